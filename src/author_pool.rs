@@ -0,0 +1,307 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::errors::TimeTravelError;
+use crate::git_context::GitIdentity;
+use crate::schedule::{json_element_lines, parse_author, CommitSchedule, ScheduleFormat};
+
+/// One identity and the dates it was used on. Open ends reach back or forward indefinitely.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorRange {
+    /// Line in the source file the row started on (1-based)
+    pub line: usize,
+    pub from: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+    pub author: GitIdentity,
+}
+
+impl AuthorRange {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.from.is_none_or(|from| from <= date) && self.until.is_none_or(|until| date <= until)
+    }
+}
+
+/// Identities by date range, e.g. an old email used until 2005, imported from CSV or JSON
+#[derive(Debug, Clone)]
+pub struct AuthorPool {
+    pub source: Option<PathBuf>,
+    pub ranges: Vec<AuthorRange>,
+}
+
+/// Raw row as it appears in a JSON author pool
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonRow {
+    #[serde(default)]
+    from: Option<String>,
+    #[serde(default)]
+    until: Option<String>,
+    author: String,
+}
+
+/// Unvalidated row fields: from, until, author
+type RawRow = (Option<String>, Option<String>, String);
+
+impl AuthorPool {
+    /// Load an author pool from a .csv or .json file
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let format = ScheduleFormat::from_path(path).map_err(|_| TimeTravelError::invalid_input(
+            "author pool file",
+            &path.display().to_string(),
+            "unsupported file type",
+            "Use a .csv or .json file"
+        ))?;
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| TimeTravelError::file_system("read", &path.display().to_string(), &e.to_string()))?;
+
+        let mut pool = Self::parse(&content, format)
+            .with_context(|| format!("Failed to import author pool from {}", path.display()))?;
+        pool.source = Some(path.to_path_buf());
+        Ok(pool)
+    }
+
+    /// Parse author pool content, reporting every invalid row with its line number
+    pub fn parse(content: &str, format: ScheduleFormat) -> Result<Self> {
+        let rows = match format {
+            ScheduleFormat::Csv => read_csv_rows(content)?,
+            ScheduleFormat::Json => read_json_rows(content)?,
+        };
+
+        let mut ranges = Vec::new();
+        let mut row_errors = Vec::new();
+        for (line, row) in rows {
+            match row.and_then(|row| validate_row(line, row)) {
+                Ok(range) => ranges.push(range),
+                Err(e) => row_errors.push(format!("line {}: {}", line, e)),
+            }
+        }
+
+        // A date must map to exactly one identity
+        ranges.sort_by_key(|range: &AuthorRange| (range.from, range.line));
+        for pair in ranges.windows(2) {
+            if pair[0].until.is_none_or(|until| pair[1].from.is_none_or(|from| from <= until)) {
+                row_errors.push(format!("line {}: overlaps the range on line {}", pair[1].line, pair[0].line));
+            }
+        }
+
+        if !row_errors.is_empty() {
+            return Err(TimeTravelError::invalid_input(
+                "author pool",
+                &format!("{} invalid row(s)", row_errors.len()),
+                &format!("\n  {}", row_errors.join("\n  ")),
+                "Fix the rows listed above and import the author pool again"
+            ).into());
+        }
+
+        if ranges.is_empty() {
+            return Err(TimeTravelError::invalid_input(
+                "author pool",
+                "",
+                "contains no rows",
+                "Add at least one row of from, until, author"
+            ).into());
+        }
+
+        Ok(Self { source: None, ranges })
+    }
+
+    /// The identity in use on `date`, if a range covers it
+    pub fn author_on(&self, date: NaiveDate) -> Option<&GitIdentity> {
+        self.ranges.iter().find(|range| range.contains(date)).map(|range| &range.author)
+    }
+
+    /// The identity for a commit made on the given day
+    pub fn author_for(&self, year: u32, month: u32, day: u32) -> Option<&GitIdentity> {
+        NaiveDate::from_ymd_opt(year as i32, month, day).and_then(|date| self.author_on(date))
+    }
+
+    /// Give schedule rows without an explicit author the identity of their date
+    pub fn apply_to_schedule(&self, schedule: &mut CommitSchedule) {
+        for entry in schedule.entries.iter_mut().filter(|entry| entry.author.is_none()) {
+            entry.author = self.author_on(entry.timestamp.date_naive()).cloned();
+        }
+    }
+}
+
+/// The author of a commit made on `date`. An `explicit` identity (given on the command line)
+/// wins, then the pool's identity for the date, then `fallback`.
+pub fn resolve_author(
+    explicit: Option<&GitIdentity>,
+    pool: Option<&AuthorPool>,
+    date: Option<NaiveDate>,
+    fallback: Option<&GitIdentity>,
+) -> Option<GitIdentity> {
+    explicit
+        .or_else(|| pool.zip(date).and_then(|(pool, date)| pool.author_on(date)))
+        .or(fallback)
+        .cloned()
+}
+
+/// Read CSV rows with their line numbers. A leading header row is skipped.
+fn read_csv_rows(content: &str) -> Result<Vec<(usize, Result<RawRow>)>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .comment(Some(b'#'))
+        .from_reader(content.as_bytes());
+
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|p| p.line() as usize).unwrap_or(0);
+                rows.push((line, Err(anyhow!("unreadable CSV row: {}", e))));
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line() as usize).unwrap_or(index + 1);
+
+        if index == 0 && record.get(0).is_some_and(|field| field.eq_ignore_ascii_case("from")) {
+            continue;
+        }
+        if record.iter().all(|field| field.is_empty()) {
+            continue;
+        }
+
+        let row = if record.len() != 3 {
+            Err(anyhow!("expected 3 columns (from, until, author), found {}", record.len()))
+        } else {
+            let date = |field: &str| Some(field.to_string()).filter(|field| !field.is_empty());
+            Ok((date(&record[0]), date(&record[1]), record[2].to_string()))
+        };
+        rows.push((line, row));
+    }
+    Ok(rows)
+}
+
+/// Read rows from a JSON array of objects with their line numbers
+fn read_json_rows(content: &str) -> Result<Vec<(usize, Result<RawRow>)>> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(content).map_err(|e| {
+        TimeTravelError::invalid_input(
+            "author pool",
+            &format!("line {}, column {}", e.line(), e.column()),
+            &e.to_string(),
+            "The JSON author pool must be an array of {\"from\", \"until\", \"author\"} objects"
+        )
+    })?;
+
+    let lines = json_element_lines(content);
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let line = lines.get(index).copied().unwrap_or(0);
+            let row = serde_json::from_value::<JsonRow>(value)
+                .map(|row| (row.from, row.until, row.author))
+                .map_err(|e| anyhow!("{}", e));
+            (line, row)
+        })
+        .collect())
+}
+
+/// Validate a raw row and turn it into a date range
+fn validate_row(line: usize, (from, until, author): RawRow) -> Result<AuthorRange> {
+    let from = from.as_deref().map(parse_date).transpose()?;
+    let until = until.as_deref().map(parse_date).transpose()?;
+    if let (Some(from), Some(until)) = (from, until) {
+        if from > until {
+            return Err(TimeTravelError::invalid_input(
+                "range",
+                &format!("{} to {}", from, until),
+                "starts after it ends",
+                "Swap the from and until dates"
+            ).into());
+        }
+    }
+
+    let author = parse_author(&author)?;
+
+    Ok(AuthorRange { line, from, until, author })
+}
+
+fn parse_date(input: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d").map_err(|_| TimeTravelError::invalid_input(
+        "date",
+        input,
+        "unrecognized date format",
+        "Use YYYY-MM-DD, or leave the field empty for an open range"
+    ).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(name: &str, email: &str) -> GitIdentity {
+        GitIdentity { name: name.to_string(), email: email.to_string() }
+    }
+
+    #[test]
+    fn test_parse_csv_pool() {
+        let content = "from,until,author\n\
+                       2006-01-01,,Jane Doe <jane@example.com>\n\
+                       ,2005-12-31,Jane Smith <jane@oldmail.net>\n\
+                       # renamed at the start of 2006\n";
+        let pool = AuthorPool::parse(content, ScheduleFormat::Csv).unwrap();
+
+        assert_eq!(pool.ranges.len(), 2);
+        assert_eq!(pool.ranges[0].line, 3);
+        assert_eq!(pool.author_for(1990, 1, 1), Some(&identity("Jane Smith", "jane@oldmail.net")));
+        assert_eq!(pool.author_for(2005, 12, 31), Some(&identity("Jane Smith", "jane@oldmail.net")));
+        assert_eq!(pool.author_for(2006, 1, 1), Some(&identity("Jane Doe", "jane@example.com")));
+    }
+
+    #[test]
+    fn test_parse_json_pool_with_gaps() {
+        let content = r#"[
+  {"from": "1995-01-01", "until": "1999-12-31", "author": "Old Me <me@isp.example>"},
+  {"from": "2010-01-01", "author": "New Me <me@example.com>"}
+]"#;
+        let pool = AuthorPool::parse(content, ScheduleFormat::Json).unwrap();
+
+        assert_eq!(pool.ranges[1].line, 3);
+        assert_eq!(pool.author_for(1997, 6, 1).unwrap().email, "me@isp.example");
+        assert_eq!(pool.author_for(2003, 1, 1), None);
+        assert_eq!(pool.author_for(2024, 1, 1).unwrap().email, "me@example.com");
+
+        let mut schedule = CommitSchedule::parse("1996-03-01,a,1\n2003-03-01,b,1\n1997-03-01,c,1,Guest <guest@example.com>\n", ScheduleFormat::Csv).unwrap();
+        pool.apply_to_schedule(&mut schedule);
+        let emails: Vec<_> = schedule.entries.iter().map(|entry| entry.author.as_ref().map(|author| author.email.as_str())).collect();
+        assert_eq!(emails, vec![Some("me@isp.example"), Some("guest@example.com"), None]);
+    }
+
+    #[test]
+    fn test_explicit_author_wins_over_pool() {
+        let pool = AuthorPool::parse(",2005-12-31,Old Me <me@old.example>\n", ScheduleFormat::Csv).unwrap();
+        let flags = identity("Flag Me", "me@flags.example");
+        let profile = identity("Profile Me", "me@profile.example");
+        let in_range = NaiveDate::from_ymd_opt(2001, 1, 1);
+        let out_of_range = NaiveDate::from_ymd_opt(2010, 1, 1);
+
+        assert_eq!(resolve_author(Some(&flags), Some(&pool), in_range, Some(&profile)), Some(flags.clone()));
+        assert_eq!(resolve_author(None, Some(&pool), in_range, Some(&profile)).unwrap().email, "me@old.example");
+        assert_eq!(resolve_author(None, Some(&pool), out_of_range, Some(&profile)), Some(profile));
+        assert_eq!(resolve_author(None, Some(&pool), out_of_range, None), None);
+        assert_eq!(resolve_author(None, None, in_range, None), None);
+    }
+
+    #[test]
+    fn test_invalid_pool_rows_report_line_numbers() {
+        let content = ",2005-12-31,Jane <jane@old.example>\n\
+                       2005-06-01,,Jane <jane@example.com>\n\
+                       2010-01-01,2009-01-01,Jane <jane@example.com>\n\
+                       yesterday,,Jane <jane@example.com>\n\
+                       2020-01-01,,nobody\n";
+        let error = AuthorPool::parse(content, ScheduleFormat::Csv).unwrap_err().to_string();
+
+        assert!(error.contains("4 invalid row(s)"));
+        assert!(error.contains("line 2: overlaps the range on line 1"));
+        assert!(error.contains("line 3: Invalid range"));
+        assert!(error.contains("line 4: Invalid date"));
+        assert!(error.contains("line 5: Invalid author"));
+    }
+}
@@ -0,0 +1,449 @@
+use anyhow::{Context, Result};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use colored::*;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::dry_run::{DryRunPlan, PlannedOperation};
+use crate::errors::TimeTravelError;
+
+/// GitHub's light-theme palette, from no contributions to the highest quartile
+pub const LEVEL_COLORS: [(u8, u8, u8); 5] = [
+    (0xeb, 0xed, 0xf0),
+    (0x9b, 0xe9, 0xa8),
+    (0x40, 0xc4, 0x63),
+    (0x30, 0xa1, 0x4e),
+    (0x21, 0x6e, 0x39),
+];
+
+/// Characters used for the intensity levels when color is unavailable
+const ASCII_LEVELS: [char; 5] = ['.', '-', '+', '*', '#'];
+
+/// Size of a calendar cell and the gap between cells in the SVG output
+const CELL_SIZE: usize = 10;
+const CELL_STEP: usize = 13;
+const LEFT_MARGIN: usize = 32;
+const TOP_MARGIN: usize = 20;
+
+const MONTH_LABELS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// Contribution counts per day, built from planned commits
+#[derive(Debug, Clone, Default)]
+pub struct ContributionCalendar {
+    pub counts: BTreeMap<NaiveDate, u32>,
+}
+
+/// File type of an exported calendar preview
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreviewFormat {
+    Svg,
+    Html,
+}
+
+impl PreviewFormat {
+    /// Detect the format from a file extension
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()).map(|ext| ext.to_ascii_lowercase()) {
+            Some(ext) if ext == "svg" => Ok(Self::Svg),
+            Some(ext) if ext == "html" || ext == "htm" => Ok(Self::Html),
+            _ => Err(TimeTravelError::invalid_input(
+                "preview file",
+                &path.display().to_string(),
+                "unsupported file type",
+                "Use a .svg or .html file"
+            ).into()),
+        }
+    }
+}
+
+/// One year laid out like GitHub's graph: columns are weeks starting on Sunday,
+/// `None` marks cells outside the year
+#[derive(Debug, Clone)]
+pub struct YearGrid {
+    pub year: i32,
+    pub weeks: Vec<[Option<NaiveDate>; 7]>,
+}
+
+impl ContributionCalendar {
+    /// Count the backdated commits of a dry run plan per day
+    pub fn from_plan(plan: &DryRunPlan) -> Self {
+        let mut calendar = Self::default();
+        for operation in &plan.operations {
+            if let PlannedOperation::CreateCommit { timestamp, .. } = operation {
+                if let Ok(datetime) = NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S") {
+                    calendar.add(datetime.date(), 1);
+                }
+            }
+        }
+        calendar
+    }
+
+    pub fn add(&mut self, date: NaiveDate, count: u32) {
+        *self.counts.entry(date).or_insert(0) += count;
+    }
+
+    pub fn count(&self, date: NaiveDate) -> u32 {
+        self.counts.get(&date).copied().unwrap_or(0)
+    }
+
+    /// Years that have at least one contribution
+    pub fn years(&self) -> Vec<i32> {
+        let mut years: Vec<i32> = self.counts.keys().map(|date| date.year()).collect();
+        years.dedup();
+        years
+    }
+
+    /// Upper bounds of intensity levels 1-3 for a year, from the quartiles of its non-zero days
+    pub fn quartiles(&self, year: i32) -> [u32; 3] {
+        let mut counts: Vec<u32> = self.counts.iter()
+            .filter(|(date, count)| date.year() == year && **count > 0)
+            .map(|(_, count)| *count)
+            .collect();
+        if counts.is_empty() {
+            return [0; 3];
+        }
+        counts.sort_unstable();
+        let at = |fraction: f64| counts[((counts.len() - 1) as f64 * fraction).round() as usize];
+        [at(0.25), at(0.5), at(0.75)]
+    }
+
+    /// Intensity level 0-4 of a day, as GitHub shades it
+    pub fn level(&self, date: NaiveDate, quartiles: &[u32; 3]) -> usize {
+        match self.count(date) {
+            0 => 0,
+            count if count <= quartiles[0] => 1,
+            count if count <= quartiles[1] => 2,
+            count if count <= quartiles[2] => 3,
+            _ => 4,
+        }
+    }
+
+    /// Render every affected year as a terminal heatmap
+    pub fn render_terminal(&self, color: bool) -> String {
+        self.years().into_iter()
+            .map(|year| self.render_year(year, color))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn render_year(&self, year: i32, color: bool) -> String {
+        let grid = YearGrid::new(year);
+        let quartiles = self.quartiles(year);
+        let total: u32 = self.counts.iter()
+            .filter(|(date, _)| date.year() == year)
+            .map(|(_, count)| count)
+            .sum();
+
+        let mut out = format!("{} ({} contribution{})\n", year, total, if total == 1 { "" } else { "s" });
+        out.push_str(&format!("    {}\n", grid.month_header()));
+        for weekday in 0..7 {
+            let label = match weekday {
+                1 => "Mon",
+                3 => "Wed",
+                5 => "Fri",
+                _ => "",
+            };
+            out.push_str(&format!("{:<4}", label));
+            for week in &grid.weeks {
+                let cell = match week[weekday] {
+                    Some(date) => {
+                        let level = self.level(date, &quartiles);
+                        if color {
+                            let (r, g, b) = LEVEL_COLORS[level];
+                            "■".truecolor(r, g, b).to_string()
+                        } else {
+                            ASCII_LEVELS[level].to_string()
+                        }
+                    }
+                    None => " ".to_string(),
+                };
+                out.push_str(&cell);
+            }
+            out.push('\n');
+        }
+
+        out.push_str("    Less ");
+        for (level, symbol) in ASCII_LEVELS.iter().enumerate() {
+            if color {
+                let (r, g, b) = LEVEL_COLORS[level];
+                out.push_str(&"■".truecolor(r, g, b).to_string());
+            } else {
+                out.push(*symbol);
+            }
+        }
+        out.push_str(" More\n");
+        out
+    }
+}
+
+impl ContributionCalendar {
+    /// Load existing contribution data from JSON.
+    ///
+    /// Accepts a `{"YYYY-MM-DD": count}` map, or any JSON (such as a saved GraphQL
+    /// response) containing objects with a `date` and a `count` or `contributionCount`.
+    pub fn from_json(content: &str) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(content)
+            .context("Failed to parse contribution data")?;
+
+        let mut calendar = Self::default();
+        if let Some(map) = value.as_object().filter(|map| map.keys().all(|key| parse_date(key).is_some())) {
+            for (key, count) in map {
+                if let (Some(date), Some(count)) = (parse_date(key), count.as_u64()) {
+                    calendar.add(date, count as u32);
+                }
+            }
+        } else {
+            collect_days(&value, &mut calendar);
+        }
+        Ok(calendar)
+    }
+
+    /// Render the affected years as an SVG calendar. Days of `existing` are added to the
+    /// planned counts; planned days are outlined so both stay distinguishable.
+    pub fn render_svg(&self, existing: Option<&ContributionCalendar>) -> String {
+        let combined = self.combined(existing);
+        let years = self.years();
+        let block_height = TOP_MARGIN + 7 * CELL_STEP + 24;
+        let width = LEFT_MARGIN + 54 * CELL_STEP + 8;
+        let height = block_height * years.len().max(1);
+
+        let mut svg = String::new();
+        let _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" font-family="-apple-system, Segoe UI, Helvetica, Arial, sans-serif" font-size="9">"#,
+            width, height, width, height);
+        let _ = writeln!(svg, r##"<rect width="100%" height="100%" fill="#ffffff"/>"##);
+
+        for (index, year) in years.iter().enumerate() {
+            let top = index * block_height;
+            let grid = YearGrid::new(*year);
+            let quartiles = combined.quartiles(*year);
+
+            let _ = writeln!(svg, r##"<g transform="translate(0 {})">"##, top);
+            let _ = writeln!(svg, r##"<text x="0" y="10" font-size="11" font-weight="600" fill="#24292f">{}</text>"##, year);
+            for (week, label) in grid.month_starts() {
+                let _ = writeln!(svg, r##"<text x="{}" y="{}" fill="#57606a">{}</text>"##,
+                    LEFT_MARGIN + week * CELL_STEP, TOP_MARGIN - 4, label);
+            }
+            for (weekday, label) in [(1, "Mon"), (3, "Wed"), (5, "Fri")] {
+                let _ = writeln!(svg, r##"<text x="0" y="{}" fill="#57606a">{}</text>"##,
+                    TOP_MARGIN + weekday * CELL_STEP + CELL_SIZE - 1, label);
+            }
+
+            for (week_index, week) in grid.weeks.iter().enumerate() {
+                for (weekday, day) in week.iter().enumerate() {
+                    let date = match day {
+                        Some(date) => *date,
+                        None => continue,
+                    };
+                    let (r, g, b) = LEVEL_COLORS[combined.level(date, &quartiles)];
+                    let planned = self.count(date);
+                    let outline = if planned > 0 && existing.is_some() {
+                        r##" stroke="#0969da" stroke-width="1.5""##
+                    } else {
+                        ""
+                    };
+                    let _ = writeln!(svg, r##"<rect x="{}" y="{}" width="{}" height="{}" rx="2" fill="#{:02x}{:02x}{:02x}"{}><title>{}</title></rect>"##,
+                        LEFT_MARGIN + week_index * CELL_STEP, TOP_MARGIN + weekday * CELL_STEP,
+                        CELL_SIZE, CELL_SIZE, r, g, b, outline,
+                        tooltip(date, planned, existing.map(|calendar| calendar.count(date))));
+                }
+            }
+
+            let legend_y = TOP_MARGIN + 7 * CELL_STEP + 6;
+            let legend_x = LEFT_MARGIN + 53 * CELL_STEP - 5 * CELL_STEP - 60;
+            let _ = writeln!(svg, r##"<text x="{}" y="{}" fill="#57606a">Less</text>"##, legend_x, legend_y + 9);
+            for (level, (r, g, b)) in LEVEL_COLORS.iter().enumerate() {
+                let _ = writeln!(svg, r##"<rect x="{}" y="{}" width="{}" height="{}" rx="2" fill="#{:02x}{:02x}{:02x}"/>"##,
+                    legend_x + 26 + level * CELL_STEP, legend_y, CELL_SIZE, CELL_SIZE, r, g, b);
+            }
+            let _ = writeln!(svg, r##"<text x="{}" y="{}" fill="#57606a">More</text>"##, legend_x + 30 + 5 * CELL_STEP, legend_y + 9);
+            svg.push_str("</g>\n");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Render the SVG calendar inside a standalone HTML page
+    pub fn render_html(&self, existing: Option<&ContributionCalendar>) -> String {
+        let total: u32 = self.counts.values().sum();
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Projected contribution calendar</title>\n\
+             <style>body {{ font-family: -apple-system, Segoe UI, Helvetica, Arial, sans-serif; color: #24292f; margin: 24px; }}\n\
+             rect:hover {{ stroke: #24292f; stroke-width: 1; }}</style>\n</head>\n<body>\n\
+             <h1>Projected contribution calendar</h1>\n<p>{} planned commit{} across {} year{}{}.</p>\n{}</body>\n</html>\n",
+            total, if total == 1 { "" } else { "s" },
+            self.years().len(), if self.years().len() == 1 { "" } else { "s" },
+            if existing.is_some() { ", outlined on top of existing contributions" } else { "" },
+            self.render_svg(existing))
+    }
+
+    /// Write the preview in the format given by the file extension
+    pub fn write_preview(&self, path: &Path, existing: Option<&ContributionCalendar>) -> Result<()> {
+        let content = match PreviewFormat::from_path(path)? {
+            PreviewFormat::Svg => self.render_svg(existing),
+            PreviewFormat::Html => self.render_html(existing),
+        };
+        std::fs::write(path, content).map_err(|e| TimeTravelError::file_system(
+            "write",
+            &path.display().to_string(),
+            &e.to_string()
+        ))?;
+        Ok(())
+    }
+
+    /// Planned counts plus existing counts
+    fn combined(&self, existing: Option<&ContributionCalendar>) -> ContributionCalendar {
+        let mut combined = self.clone();
+        if let Some(existing) = existing {
+            for (date, count) in &existing.counts {
+                combined.add(*date, *count);
+            }
+        }
+        combined
+    }
+}
+
+/// Tooltip text of one calendar cell
+fn tooltip(date: NaiveDate, planned: u32, existing: Option<u32>) -> String {
+    let day = date.format("%B %-d, %Y");
+    match existing {
+        Some(existing) => format!("{} planned, {} existing on {}", planned, existing, day),
+        None if planned == 0 => format!("No contributions on {}", day),
+        None => format!("{} contribution{} on {}", planned, if planned == 1 { "" } else { "s" }, day),
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Collect every `{date, count|contributionCount}` object in a JSON document
+fn collect_days(value: &serde_json::Value, calendar: &mut ContributionCalendar) {
+    match value {
+        serde_json::Value::Array(items) => items.iter().for_each(|item| collect_days(item, calendar)),
+        serde_json::Value::Object(map) => {
+            let date = map.get("date").and_then(|date| date.as_str()).and_then(parse_date);
+            let count = map.get("contributionCount").or_else(|| map.get("count")).and_then(|count| count.as_u64());
+            match (date, count) {
+                (Some(date), Some(count)) => calendar.add(date, count as u32),
+                _ => map.values().for_each(|value| collect_days(value, calendar)),
+            }
+        }
+        _ => {}
+    }
+}
+
+impl YearGrid {
+    /// Lay out January 1st to December 31st in Sunday-first week columns
+    pub fn new(year: i32) -> Self {
+        let first = NaiveDate::from_ymd_opt(year, 1, 1).expect("valid year");
+        let last = NaiveDate::from_ymd_opt(year, 12, 31).expect("valid year");
+
+        let mut weeks = Vec::new();
+        let mut week = [None; 7];
+        let mut date = first;
+        while date <= last {
+            let weekday = date.weekday().num_days_from_sunday() as usize;
+            week[weekday] = Some(date);
+            if weekday == 6 {
+                weeks.push(week);
+                week = [None; 7];
+            }
+            date += Duration::days(1);
+        }
+        if week.iter().any(Option::is_some) {
+            weeks.push(week);
+        }
+
+        Self { year, weeks }
+    }
+
+    /// Index of the first week column that contains each month's first day
+    pub fn month_starts(&self) -> Vec<(usize, &'static str)> {
+        let mut starts = Vec::new();
+        for (index, week) in self.weeks.iter().enumerate() {
+            if let Some(date) = week.iter().flatten().find(|date| date.day() == 1) {
+                starts.push((index, MONTH_LABELS[date.month0() as usize]));
+            }
+        }
+        starts
+    }
+
+    /// Month labels aligned to the week columns
+    fn month_header(&self) -> String {
+        let mut header = String::new();
+        for (index, label) in self.month_starts() {
+            // Skip a label that would overlap the previous one
+            if header.len() <= index {
+                header.push_str(&" ".repeat(index - header.len()));
+                header.push_str(label);
+            }
+        }
+        header
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_year_grid_layout() {
+        // 1990 starts on a Monday and has 53 week columns
+        let grid = YearGrid::new(1990);
+        assert_eq!(grid.weeks.len(), 53);
+        assert_eq!(grid.weeks[0][0], None);
+        assert_eq!(grid.weeks[0][1], Some(date(1990, 1, 1)));
+        assert_eq!(grid.month_starts().len(), 12);
+
+        // A leap year starting on Saturday needs a 54th column
+        assert_eq!(YearGrid::new(2000).weeks.len(), 54);
+    }
+
+    #[test]
+    fn test_quartile_levels_and_ascii_render() {
+        let mut calendar = ContributionCalendar::default();
+        for (day, count) in [(1, 1), (2, 2), (3, 3), (4, 4), (5, 10)] {
+            calendar.add(date(1990, 1, day), count);
+        }
+
+        let quartiles = calendar.quartiles(1990);
+        assert_eq!(quartiles, [2, 3, 4]);
+        assert_eq!(calendar.level(date(1990, 1, 1), &quartiles), 1);
+        assert_eq!(calendar.level(date(1990, 1, 5), &quartiles), 4);
+        assert_eq!(calendar.level(date(1990, 6, 1), &quartiles), 0);
+
+        let rendered = calendar.render_terminal(false);
+        assert!(rendered.starts_with("1990 (20 contributions)"));
+        assert!(rendered.contains("Mon -."));
+        assert!(rendered.contains('#'));
+        assert!(rendered.is_ascii());
+    }
+
+    #[test]
+    fn test_svg_preview_with_overlay() {
+        let mut planned = ContributionCalendar::default();
+        planned.add(date(1990, 1, 1), 2);
+
+        let existing = ContributionCalendar::from_json(
+            r#"{"data":{"weeks":[{"contributionDays":[{"date":"1990-01-02","contributionCount":5}]}]}}"#
+        ).unwrap();
+        assert_eq!(existing.count(date(1990, 1, 2)), 5);
+        assert_eq!(ContributionCalendar::from_json(r#"{"1990-03-04": 1}"#).unwrap().count(date(1990, 3, 4)), 1);
+
+        let svg = planned.render_svg(Some(&existing));
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<title>").count(), 365);
+        assert!(svg.contains("<title>2 planned, 0 existing on January 1, 1990</title>"));
+        assert!(svg.contains(">Jan</text>") && svg.contains(">Wed</text>"));
+        assert!(svg.contains("stroke=\"#0969da\""));
+
+        assert!(planned.render_html(None).contains("<title>2 contributions on January 1, 1990</title>"));
+        assert!(PreviewFormat::from_path(Path::new("calendar.png")).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::errors::TimeTravelError;
+
+lazy_static::lazy_static! {
+    /// Temporary clones that are still on disk, removed on exits that skip destructors
+    static ref TEMP_DIRS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+}
+
+/// Shared flag that lets a Ctrl-C handler stop a running time travel between steps
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    active: Arc<AtomicUsize>,
+}
+
+/// Marks an operation as running for as long as it is alive
+#[derive(Debug)]
+pub struct ActiveOperation {
+    active: Arc<AtomicUsize>,
+}
+
+impl Drop for ActiveOperation {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation; running operations stop at their next check
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Register a running operation, so an interrupt cancels it instead of exiting
+    pub fn enter(&self) -> ActiveOperation {
+        self.active.fetch_add(1, Ordering::SeqCst);
+        ActiveOperation { active: self.active.clone() }
+    }
+
+    /// Whether an operation that checks this token is currently running
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::SeqCst) > 0
+    }
+
+    /// Fail with a cancellation error before starting `operation` when cancelled
+    pub fn check(&self, operation: &str) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(TimeTravelError::cancelled(operation).into());
+        }
+        Ok(())
+    }
+}
+
+/// Whether an error (or any error in its chain) is a cancellation
+pub fn is_cancellation(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| matches!(
+        cause.downcast_ref::<TimeTravelError>(),
+        Some(TimeTravelError::Cancelled { .. })
+    ))
+}
+
+pub(crate) fn track_temp_dir(path: &Path) {
+    if let Ok(mut dirs) = TEMP_DIRS.lock() {
+        dirs.push(path.to_path_buf());
+    }
+}
+
+pub(crate) fn untrack_temp_dir(path: &Path) {
+    if let Ok(mut dirs) = TEMP_DIRS.lock() {
+        dirs.retain(|dir| dir != path);
+    }
+}
+
+/// Remove temporary clones that are still on disk.
+///
+/// `process::exit` and `panic = "abort"` skip `Drop`, so exit paths call this first.
+pub fn remove_tracked_temp_dirs() {
+    if let Ok(mut dirs) = TEMP_DIRS.lock() {
+        for dir in dirs.drain(..) {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        assert!(token.check("clone").is_ok());
+        assert!(!token.is_active());
+
+        let shared = token.clone();
+        {
+            let _operation = shared.enter();
+            assert!(token.is_active());
+            shared.cancel();
+        }
+        assert!(!token.is_active());
+
+        let error = token.check("push").unwrap_err();
+        assert!(is_cancellation(&error));
+        assert!(is_cancellation(&error.context("Year 1990 failed")));
+        assert!(!is_cancellation(&anyhow::anyhow!("other failure")));
+    }
+}
@@ -168,6 +168,60 @@ impl DateParser {
         ))
     }
 
+    /// Parse a date window such as "2010-01-01..2012-12-31" or "2010..2012".
+    ///
+    /// The start is the beginning of its first day and the end is the last
+    /// second of its final day, so both bounds are inclusive.
+    pub fn parse_window(&self, input: &str) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+        let (start_str, end_str) = input.split_once("..")
+            .ok_or_else(|| anyhow!(
+                "Invalid date window: '{}'\n\
+                Use START..END, e.g. 2010-01-01..2012-12-31 or 2010..2012",
+                input.trim()
+            ))?;
+
+        let (start, _) = self.date_bounds(&self.parse(start_str)?)?;
+        let (_, end) = self.date_bounds(&self.parse(end_str)?)?;
+
+        if end < start {
+            return Err(anyhow!(
+                "Invalid date window: '{}'\n\
+                The end of the window must not be before its start",
+                input.trim()
+            ));
+        }
+
+        let end_of_day = end.and_hms_opt(23, 59, 59)
+            .ok_or_else(|| anyhow!("Invalid end date: {}", end))?;
+        let start_of_day = start.and_hms_opt(0, 0, 0)
+            .ok_or_else(|| anyhow!("Invalid start date: {}", start))?;
+
+        Ok((Utc.from_utc_datetime(&start_of_day), Utc.from_utc_datetime(&end_of_day)))
+    }
+
+    /// First and last day covered by a single date input
+    fn date_bounds(&self, input: &DateInput) -> Result<(NaiveDate, NaiveDate)> {
+        match input {
+            DateInput::Year(year) => Ok((
+                self.create_naive_date(*year, 1, 1)?,
+                self.create_naive_date(*year, 12, 31)?,
+            )),
+            DateInput::YearMonth(year, month) => Ok((
+                self.create_naive_date(*year, *month, 1)?,
+                self.create_naive_date(*year, *month, days_in_month(*year, *month)?)?,
+            )),
+            DateInput::FullDate(date) => Ok((*date, *date)),
+            DateInput::Range(start, end) => Ok((
+                self.create_naive_date(*start, 1, 1)?,
+                self.create_naive_date(*end, 12, 31)?,
+            )),
+            DateInput::List(_) => Err(anyhow!(
+                "A list of years cannot be used as a window bound\n\
+                Use a single year or date on each side of '..'"
+            )),
+        }
+    }
+
     /// Parse a comma-separated list of years
     fn parse_list(&self, input: &str) -> Result<DateInput> {
         let mut years = Vec::new();
@@ -303,6 +357,41 @@ impl DateParser {
     }
 }
 
+/// Parse a signed time offset such as "-10y", "+3w", "90d" or "-6h".
+///
+/// Supported units are years (365 days), weeks, days, hours and minutes.
+pub fn parse_offset(input: &str) -> Result<chrono::Duration> {
+    lazy_static::lazy_static! {
+        static ref OFFSET_REGEX: Regex = Regex::new(r"^\s*([+-]?)(\d+)\s*([ywdhm])\s*$").unwrap();
+    }
+
+    let captures = OFFSET_REGEX.captures(input)
+        .ok_or_else(|| anyhow!(
+            "Invalid offset: '{}'\n\n\
+            Supported formats:\n\
+            • Years: -10y\n\
+            • Weeks: +3w\n\
+            • Days: -90d\n\
+            • Hours: 6h\n\
+            • Minutes: -30m",
+            input.trim()
+        ))?;
+
+    let amount: i64 = captures[2].parse()
+        .context("Offset amount is too large")?;
+    let amount = if &captures[1] == "-" { -amount } else { amount };
+
+    let duration = match &captures[3] {
+        "y" => chrono::Duration::try_days(amount.saturating_mul(365)),
+        "w" => chrono::Duration::try_weeks(amount),
+        "d" => chrono::Duration::try_days(amount),
+        "h" => chrono::Duration::try_hours(amount),
+        _ => chrono::Duration::try_minutes(amount),
+    };
+
+    duration.ok_or_else(|| anyhow!("Offset '{}' is out of range", input.trim()))
+}
+
 /// Generate timestamps for commits based on date input and configuration
 pub fn generate_timestamps(date_input: &DateInput, config: &TimestampConfig) -> Result<Vec<DateTime<Utc>>> {
     match date_input {
@@ -648,6 +737,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset("-10y").unwrap(), chrono::Duration::days(-3650));
+        assert_eq!(parse_offset("+3w").unwrap(), chrono::Duration::weeks(3));
+        assert_eq!(parse_offset("90d").unwrap(), chrono::Duration::days(90));
+        assert_eq!(parse_offset(" -6h ").unwrap(), chrono::Duration::hours(-6));
+
+        assert!(parse_offset("").is_err());
+        assert!(parse_offset("10").is_err());
+        assert!(parse_offset("ten days").is_err());
+    }
+
+    #[test]
+    fn test_parse_window() {
+        let parser = DateParser::new().unwrap();
+
+        let (start, end) = parser.parse_window("2010-01-01..2012-12-31").unwrap();
+        assert_eq!(start.format("%Y-%m-%d %H:%M:%S").to_string(), "2010-01-01 00:00:00");
+        assert_eq!(end.format("%Y-%m-%d %H:%M:%S").to_string(), "2012-12-31 23:59:59");
+
+        let (start, end) = parser.parse_window("2010..2011").unwrap();
+        assert_eq!(start.year(), 2010);
+        assert_eq!((end.year(), end.month(), end.day()), (2011, 12, 31));
+
+        assert!(parser.parse_window("2012..2010").is_err());
+        assert!(parser.parse_window("2010-01-01").is_err());
+        assert!(parser.parse_window("1990,1991..2000").is_err());
+    }
+
     #[test]
     fn test_leap_year() {
         assert!(is_leap_year(2000)); // Divisible by 400
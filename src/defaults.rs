@@ -151,6 +151,22 @@ pub enum SuggestionType {
     TimeOfDay(u32),
 }
 
+/// Suggestions weaker than this, after weighing past answers, are not offered
+const MIN_SUGGESTION_CONFIDENCE: f32 = 0.5;
+
+impl SuggestionType {
+    /// Stable name under which answers to this kind of suggestion are learned
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::NextYear(_) => "next_year",
+            Self::RepositoryNaming(_) => "repository_naming",
+            Self::BranchName(_) => "branch_name",
+            Self::AuthorMode(_) => "author_mode",
+            Self::TimeOfDay(_) => "time_of_day",
+        }
+    }
+}
+
 impl DefaultsEngine {
     /// Create a new defaults engine
     pub fn new() -> Result<Self> {
@@ -158,6 +174,11 @@ impl DefaultsEngine {
         Ok(Self { session_manager })
     }
 
+    /// Scope suggestions and learning to a profile, or to the shared preferences with `None`
+    pub fn set_profile(&mut self, name: Option<&str>) -> Result<()> {
+        self.session_manager.activate_profile(name)
+    }
+
     /// Generate intelligent defaults based on current context
     pub fn generate_defaults(&mut self, current_path: Option<&Path>) -> Result<IntelligentDefaults> {
         let start_time = Instant::now();
@@ -188,6 +209,8 @@ impl DefaultsEngine {
         
         // Detect patterns
         let patterns = self.detect_patterns(&git_context, &session_suggestions);
+        tracing::debug!(target: "defaults", path = %current_path.display(), git_repo = git_context.is_git_repo,
+            patterns = ?patterns, "Analyzed context");
         
         Ok(ContextAnalysis {
             git_context,
@@ -238,6 +261,9 @@ impl DefaultsEngine {
         } else {
             confidence_factors.iter().sum::<f32>() / confidence_factors.len() as f32
         };
+        tracing::debug!(target: "defaults", repository = ?defaults.repository, branch = %defaults.branch,
+            years = ?defaults.suggested_years, hour = defaults.suggested_hour, author_mode = ?defaults.author_mode,
+            confidence = defaults.confidence, reasoning = ?defaults.reasoning, "Generated defaults");
 
         Ok(defaults)
     }
@@ -344,6 +370,13 @@ impl DefaultsEngine {
             }
         }
 
+        // The active profile's author identity is used as configured
+        if let Some(ref identity) = suggestions.author_identity {
+            defaults.author_mode = AuthorMode::Manual(identity.clone());
+            defaults.reasoning.push(format!("Using author from profile '{}'", suggestions.profile.as_deref().unwrap_or_default()));
+            confidence_factors.push(0.9);
+        }
+
         // Year suggestions
         if !suggestions.suggested_years.is_empty() {
             defaults.suggested_years = suggestions.suggested_years.clone();
@@ -358,9 +391,13 @@ impl DefaultsEngine {
             confidence_factors.push(0.7);
         }
 
-        // GitHub username
+        // GitHub username (a profile's username wins over the one in the remote)
         if let Some(ref username) = suggestions.github_username {
-            if defaults.github_username.is_none() {
+            if let Some(ref profile) = suggestions.profile {
+                defaults.github_username = Some(username.clone());
+                defaults.reasoning.push(format!("Using GitHub username from profile '{}'", profile));
+                confidence_factors.push(0.9);
+            } else if defaults.github_username.is_none() {
                 defaults.github_username = Some(username.clone());
                 defaults.reasoning.push("Using GitHub username from session".to_string());
                 confidence_factors.push(0.8);
@@ -486,7 +523,9 @@ impl DefaultsEngine {
         }
 
         // Detect consistent author pattern
-        if let Some(ref author_mode) = suggestions.suggested_author_mode {
+        if let Some(ref identity) = suggestions.author_identity {
+            patterns.push(DetectedPattern::ConsistentAuthor { mode: AuthorMode::Manual(identity.clone()), confidence: 0.9 });
+        } else if let Some(ref author_mode) = suggestions.suggested_author_mode {
             let confidence = 0.7;
             let mode = match author_mode.as_str() {
                 "current_user" => {
@@ -735,6 +774,8 @@ impl DefaultsEngine {
         }
 
         defaults.generation_time_ms = start_time.elapsed().as_millis() as u64;
+        tracing::debug!(target: "defaults", repository = ?defaults.repository, years = ?defaults.suggested_years,
+            confidence = defaults.confidence, elapsed_ms = defaults.generation_time_ms, "Generated fast defaults");
         Ok(defaults)
     }
 
@@ -785,15 +826,41 @@ impl DefaultsEngine {
                         }
                     }
                 }
+                DetectedPattern::BranchPattern { pattern: BranchPatternType::CustomNaming(name), confidence } => {
+                    suggestions.push(IntelligentSuggestion {
+                        suggestion_type: SuggestionType::BranchName(name.clone()),
+                        confidence: *confidence,
+                        reasoning: format!("You are working on branch '{}'", name),
+                    });
+                }
+                DetectedPattern::TimePattern { hour, confidence, .. } if *confidence > 0.6 => {
+                    suggestions.push(IntelligentSuggestion {
+                        suggestion_type: SuggestionType::TimeOfDay(*hour),
+                        confidence: *confidence,
+                        reasoning: format!("You usually commit at {:02}:00", hour),
+                    });
+                }
                 _ => {}
             }
         }
 
+        // Suggestions that keep being accepted gain confidence. Rejections lower it, but never
+        // below the threshold, so a rejected kind is still offered and can win its confidence back
+        suggestions.retain(|suggestion| suggestion.confidence >= MIN_SUGGESTION_CONFIDENCE);
+        let feedback = &analysis.session_suggestions.suggestion_feedback;
+        for suggestion in &mut suggestions {
+            if let Some(answers) = feedback.get(suggestion.suggestion_type.kind()) {
+                suggestion.confidence = (suggestion.confidence * answers.weight()).clamp(MIN_SUGGESTION_CONFIDENCE, 1.0);
+            }
+        }
+
+        tracing::debug!(target: "defaults", suggestions = ?suggestions, "Generated suggestions");
         Ok(suggestions)
     }
 
     /// Learn from user choices to improve future defaults
     pub fn learn_from_choice(&mut self, choice_type: &str, value: &str) -> Result<()> {
+        tracing::debug!(target: "defaults", choice_type, value, "Learning from choice");
         self.session_manager.learn_from_choice(choice_type, value);
         self.session_manager.save()?;
         Ok(())
@@ -1003,14 +1070,7 @@ mod tests {
         let session_dir = temp_dir.path().join(".config").join("git-timetraveler");
         std::fs::create_dir_all(&session_dir)?;
         
-        let session_file = session_dir.join("session.bin");
-        let data = crate::session::SessionData::default();
-        
-        let session_manager = crate::session::SessionManager {
-            session_dir,
-            session_file,
-            data,
-        };
+        let session_manager = crate::session::SessionManager::open(session_dir, crate::session::SessionFormat::Binary)?;
         
         let mut engine = DefaultsEngine {
             session_manager,
@@ -1028,6 +1088,24 @@ mod tests {
             matches!(s.suggestion_type, SuggestionType::NextYear(1993))
         });
         assert!(has_next_year);
+
+        // A suggestion that keeps being rejected drops to the threshold but is still offered
+        let next_year_confidence = |suggestions: &[IntelligentSuggestion]| suggestions.iter()
+            .find(|s| matches!(s.suggestion_type, SuggestionType::NextYear(_)))
+            .map(|s| s.confidence);
+        for _ in 0..10 {
+            engine.learn_from_choice("suggestion_rejected", "next_year")?;
+        }
+        let suggestions = engine.get_intelligent_suggestions(Some(temp_dir.path()))?;
+        assert_eq!(next_year_confidence(&suggestions), Some(MIN_SUGGESTION_CONFIDENCE));
+        assert!(suggestions.iter().any(|s| matches!(s.suggestion_type, SuggestionType::TimeOfDay(18))));
+
+        // and accepting it again restores its confidence
+        for _ in 0..10 {
+            engine.learn_from_choice("suggestion_accepted", "next_year")?;
+        }
+        let suggestions = engine.get_intelligent_suggestions(Some(temp_dir.path()))?;
+        assert!(next_year_confidence(&suggestions).unwrap() > MIN_SUGGESTION_CONFIDENCE);
         
         Ok(())
     }
@@ -1,8 +1,11 @@
 use anyhow::Result;
+use chrono::Datelike;
 use colored::*;
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use std::collections::HashMap;
-use crate::{TimeTravelConfig, GitIdentity};
+use crate::calendar::ContributionCalendar;
+use crate::t;
+use crate::{TimeTravelConfig, TimeTravelCommitConfig, GitIdentity, PullRequestOptions, RepositoryLifecycle};
 
 /// Represents an operation that would be performed during time travel
 #[derive(Debug, Clone)]
@@ -11,6 +14,11 @@ pub enum PlannedOperation {
     ValidateToken {
         username: String,
     },
+    /// Check organization membership and repository creation rights
+    CheckOrganizationAccess {
+        organization: String,
+        username: String,
+    },
     /// Check if repository exists
     CheckRepository {
         repository: String,
@@ -47,6 +55,23 @@ pub enum PlannedOperation {
         branch: String,
         force: bool,
     },
+    /// Open a pull request from the pushed branch
+    OpenPullRequest {
+        repository: String,
+        head: String,
+        base: String,
+        title: String,
+        auto_merge: bool,
+    },
+    /// Update repository topics and settings
+    UpdateRepository {
+        repository: String,
+        changes: Vec<String>,
+    },
+    /// Archive the repository (makes it read-only)
+    ArchiveRepository {
+        repository: String,
+    },
     /// Clean up temporary files
     Cleanup {
         temp_path: String,
@@ -81,6 +106,8 @@ pub struct DryRunConfig {
     pub show_risks: bool,
     pub require_confirmation: bool,
     pub interactive_confirmations: bool,
+    /// Render the planned commits as a contribution graph
+    pub show_heatmap: bool,
 }
 
 impl Default for DryRunConfig {
@@ -91,6 +118,7 @@ impl Default for DryRunConfig {
             show_risks: true,
             require_confirmation: true,
             interactive_confirmations: true,
+            show_heatmap: true,
         }
     }
 }
@@ -117,6 +145,12 @@ impl DryRunExecutor {
             operations.push(PlannedOperation::ValidateToken {
                 username: first_config.username.clone(),
             });
+            if first_config.is_organization_owned() {
+                operations.push(PlannedOperation::CheckOrganizationAccess {
+                    organization: first_config.owner().to_string(),
+                    username: first_config.username.clone(),
+                });
+            }
         }
 
         // Group operations by repository
@@ -133,15 +167,14 @@ impl DryRunExecutor {
             // Check repository existence
             operations.push(PlannedOperation::CheckRepository {
                 repository: repo_name.clone(),
-                username: repo_configs_list[0].username.clone(),
+                username: repo_configs_list[0].owner().to_string(),
             });
 
             // Clone repository
             operations.push(PlannedOperation::CloneRepository {
                 repository: repo_name.clone(),
                 branch: repo_configs_list[0].branch.clone(),
-                url: format!("https://github.com/{}/{}.git", 
-                    repo_configs_list[0].username, repo_name),
+                url: repo_configs_list[0].clone_url(),
             });
 
             // Process each year for this repository
@@ -206,10 +239,141 @@ impl DryRunExecutor {
         })
     }
 
+    /// Create a dry run plan that pushes to a pull request branch instead of the target branch
+    pub fn create_pull_request_plan(&self, configs: &[TimeTravelConfig], options: &PullRequestOptions) -> Result<DryRunPlan> {
+        let mut plan = self.create_plan(configs)?;
+
+        for operation in plan.operations.iter_mut() {
+            if let PlannedOperation::PushCommit { branch, .. } = operation {
+                *branch = options.branch.clone();
+            }
+        }
+
+        if let Some(first_config) = configs.first() {
+            let cleanup_index = plan.operations.len().saturating_sub(1);
+            plan.operations.insert(cleanup_index, PlannedOperation::OpenPullRequest {
+                repository: first_config.repo_name(),
+                head: options.branch.clone(),
+                base: first_config.branch.clone(),
+                title: options.title.clone(),
+                auto_merge: options.auto_merge,
+            });
+        }
+        plan.summary.total_operations = plan.operations.len();
+
+        Ok(plan)
+    }
+
+    /// Append the repository lifecycle steps that run after the commits are pushed
+    pub fn add_lifecycle_operations(&self, plan: &mut DryRunPlan, config: &TimeTravelConfig, lifecycle: &RepositoryLifecycle) {
+        let repository = config.repo_name();
+        let mut insert_at = plan.operations.len().saturating_sub(1);
+
+        let changes = lifecycle.describe_changes();
+        if !changes.is_empty() {
+            plan.operations.insert(insert_at, PlannedOperation::UpdateRepository {
+                repository: repository.clone(),
+                changes,
+            });
+            insert_at += 1;
+        }
+        if lifecycle.archive {
+            plan.operations.insert(insert_at, PlannedOperation::ArchiveRepository {
+                repository: repository.clone(),
+            });
+            plan.risks.push(t!("dry_run.risk_archive", repository = repository));
+        }
+        if lifecycle.visibility.as_deref() == Some("public") {
+            plan.risks.push(t!("dry_run.risk_public", repository = repository));
+        }
+        plan.summary.total_operations = plan.operations.len();
+    }
+
+    /// Create a dry run plan for an imported commit schedule
+    pub fn create_schedule_plan(&self, config: &TimeTravelConfig, commits: &[TimeTravelCommitConfig], force: bool) -> Result<DryRunPlan> {
+        let repo_name = config.repo_name();
+        let mut operations = vec![
+            PlannedOperation::ValidateToken {
+                username: config.username.clone(),
+            },
+        ];
+        if config.is_organization_owned() {
+            operations.push(PlannedOperation::CheckOrganizationAccess {
+                organization: config.owner().to_string(),
+                username: config.username.clone(),
+            });
+        }
+        operations.push(PlannedOperation::CheckRepository {
+            repository: repo_name.clone(),
+            username: config.owner().to_string(),
+        });
+        operations.push(PlannedOperation::CloneRepository {
+            repository: repo_name.clone(),
+            branch: config.branch.clone(),
+            url: config.clone_url(),
+        });
+
+        let mut files_to_create = Vec::new();
+        for commit in commits {
+            let files: Vec<String> = commit.files_to_add.iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            for file in &files {
+                if !files_to_create.contains(file) {
+                    files_to_create.push(file.clone());
+                }
+            }
+
+            operations.push(PlannedOperation::CreateCommit {
+                year: commit.timestamp.year() as u32,
+                timestamp: commit.timestamp.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                author: commit.author.clone(),
+                message: commit.message.clone(),
+                files,
+            });
+        }
+
+        operations.push(PlannedOperation::PushCommit {
+            repository: repo_name.clone(),
+            branch: config.branch.clone(),
+            force,
+        });
+        operations.push(PlannedOperation::Cleanup {
+            temp_path: "/tmp/git-timetraveler-*".to_string(),
+        });
+
+        let mut years: Vec<u32> = commits.iter().map(|c| c.timestamp.year() as u32).collect();
+        years.sort_unstable();
+        years.dedup();
+
+        let summary = DryRunSummary {
+            total_operations: operations.len(),
+            years_to_process: years,
+            repositories_affected: vec![repo_name],
+            files_to_create,
+            commits_to_create: commits.len(),
+            estimated_duration: std::time::Duration::from_secs(10 + commits.len() as u64),
+        };
+
+        let risks = self.identify_risks(std::slice::from_ref(config), &operations);
+        let confirmations_needed = self.identify_confirmations_needed(std::slice::from_ref(config), &operations);
+
+        Ok(DryRunPlan {
+            operations,
+            summary,
+            risks,
+            confirmations_needed,
+        })
+    }
+
     /// Display the dry run plan to the user
     pub fn display_plan(&self, plan: &DryRunPlan) -> Result<()> {
         self.display_header();
         self.display_summary(&plan.summary);
+
+        if self.config.show_heatmap {
+            self.display_heatmap(plan);
+        }
         
         if self.config.show_risks && !plan.risks.is_empty() {
             self.display_risks(&plan.risks);
@@ -233,16 +397,16 @@ impl DryRunExecutor {
         }
 
         if !self.config.interactive_confirmations {
-            println!("\n{}", "⚠️  Confirmation required but running in non-interactive mode".yellow());
-            println!("{}", "Use --yes flag to skip confirmations or run interactively".cyan());
+            println!("\n⚠️  {}", t!("dry_run.confirmation_non_interactive").yellow());
+            println!("{}", t!("dry_run.confirmation_use_yes").cyan());
             return Ok(false);
         }
 
-        println!("\n{}", "⚠️  Confirmation Required".yellow().bold());
+        println!("\n⚠️  {}", t!("dry_run.confirmation_required").yellow().bold());
         
         // Show critical warnings
         if !plan.risks.is_empty() {
-            println!("\n{}", "🚨 Potential Risks:".red().bold());
+            println!("\n🚨 {}", t!("dry_run.risks_prompt_title").red().bold());
             for risk in &plan.risks {
                 println!("  {} {}", "•".red(), risk.yellow());
             }
@@ -250,14 +414,14 @@ impl DryRunExecutor {
 
         // Ask for confirmation
         let confirm = Confirm::with_theme(&ColorfulTheme::default())
-            .with_prompt("Do you want to proceed with these operations?")
+            .with_prompt(t!("dry_run.proceed_prompt"))
             .default(false)
             .interact()?;
 
         if confirm {
-            println!("\n{} {}", "✅".green(), "Proceeding with time travel operations...".green());
+            println!("\n{} {}", "✅".green(), t!("dry_run.proceeding").green());
         } else {
-            println!("\n{} {}", "❌".red(), "Operation cancelled by user".red());
+            println!("\n{} {}", "❌".red(), t!("common.cancelled_by_user").red());
         }
 
         Ok(confirm)
@@ -288,12 +452,12 @@ impl DryRunExecutor {
 
         // Check for force push operations
         if operations.iter().any(|op| matches!(op, PlannedOperation::PushCommit { force: true, .. })) {
-            risks.push("Force push will overwrite remote history - this cannot be undone".to_string());
+            risks.push(t!("dry_run.risk_force_push"));
         }
 
         // Check for multiple years (potential rate limiting)
         if configs.len() > 10 {
-            risks.push(format!("Processing {} years may trigger GitHub rate limits", configs.len()));
+            risks.push(t!("dry_run.risk_rate_limits", count = configs.len()));
         }
 
         // Check for very old years
@@ -302,12 +466,12 @@ impl DryRunExecutor {
             .filter(|&year| year < 1990)
             .collect();
         if !old_years.is_empty() {
-            risks.push(format!("Very old years ({:?}) may look suspicious on your profile", old_years));
+            risks.push(t!("dry_run.risk_old_years", years = format!("{:?}", old_years)));
         }
 
         // Check for repository creation
         if operations.iter().any(|op| matches!(op, PlannedOperation::CreateRepository { .. })) {
-            risks.push("New repositories will be created on your GitHub account".to_string());
+            risks.push(t!("dry_run.risk_new_repositories"));
         }
 
         risks
@@ -326,14 +490,14 @@ impl DryRunExecutor {
             .collect();
         
         if !repo_creations.is_empty() {
-            confirmations.push(format!("Create {} new repositories: {}", 
-                repo_creations.len(), 
-                repo_creations.join(", ")));
+            confirmations.push(t!("dry_run.confirm_create_repositories",
+                count = repo_creations.len(),
+                repositories = repo_creations.join(", ")));
         }
 
         // Multiple years processing
         if configs.len() > 5 {
-            confirmations.push(format!("Process {} years of commits", configs.len()));
+            confirmations.push(t!("dry_run.confirm_years", count = configs.len()));
         }
 
         // Force push operations
@@ -345,7 +509,7 @@ impl DryRunExecutor {
             .collect();
         
         if !force_pushes.is_empty() {
-            confirmations.push(format!("Force push to repositories: {}", force_pushes.join(", ")));
+            confirmations.push(t!("dry_run.confirm_force_push", repositories = force_pushes.join(", ")));
         }
 
         confirmations
@@ -353,34 +517,49 @@ impl DryRunExecutor {
 
     /// Display the dry run header
     fn display_header(&self) {
-        println!("{}", "🔍 Dry Run Mode - Preview of Operations".bright_blue().bold());
-        println!("{}", "No changes will be made to your repositories".dimmed());
+        println!("🔍 {}", t!("dry_run.header").bright_blue().bold());
+        println!("{}", t!("dry_run.no_changes").dimmed());
         println!();
     }
 
     /// Display the operation summary
     fn display_summary(&self, summary: &DryRunSummary) {
-        println!("{}", "📋 Operation Summary".bright_yellow().bold());
-        println!("  {} {}", "Total Operations:".cyan(), summary.total_operations.to_string().bright_white());
-        println!("  {} {}", "Years to Process:".cyan(), 
+        println!("📋 {}", t!("dry_run.summary_title").bright_yellow().bold());
+        println!("  {} {}", t!("dry_run.summary_operations").cyan(), summary.total_operations.to_string().bright_white());
+        println!("  {} {}", t!("dry_run.summary_years").cyan(), 
             if summary.years_to_process.len() == 1 {
                 summary.years_to_process[0].to_string()
             } else {
-                format!("{} years ({}-{})", 
-                    summary.years_to_process.len(),
-                    summary.years_to_process.iter().min().unwrap(),
-                    summary.years_to_process.iter().max().unwrap())
+                t!("dry_run.summary_year_range",
+                    count = summary.years_to_process.len(),
+                    start = summary.years_to_process.iter().min().unwrap(),
+                    end = summary.years_to_process.iter().max().unwrap())
             }.bright_magenta());
-        println!("  {} {}", "Repositories:".cyan(), summary.repositories_affected.join(", ").bright_green());
-        println!("  {} {}", "Files to Create:".cyan(), summary.files_to_create.len().to_string().bright_white());
-        println!("  {} {}", "Commits to Create:".cyan(), summary.commits_to_create.to_string().bright_white());
-        println!("  {} ~{} seconds", "Estimated Duration:".cyan(), summary.estimated_duration.as_secs().to_string().bright_white());
+        println!("  {} {}", t!("dry_run.summary_repositories").cyan(), summary.repositories_affected.join(", ").bright_green());
+        println!("  {} {}", t!("dry_run.summary_files").cyan(), summary.files_to_create.len().to_string().bright_white());
+        println!("  {} {}", t!("dry_run.summary_commits").cyan(), summary.commits_to_create.to_string().bright_white());
+        println!("  {} {}", t!("dry_run.summary_duration").cyan(),
+            t!("dry_run.summary_seconds", seconds = summary.estimated_duration.as_secs().to_string().bright_white()));
+        println!();
+    }
+
+    /// Display the planned commits as a contribution graph per affected year
+    fn display_heatmap(&self, plan: &DryRunPlan) {
+        let calendar = ContributionCalendar::from_plan(plan);
+        if calendar.counts.is_empty() {
+            return;
+        }
+        println!("🟩 {}", t!("dry_run.heatmap_title").bright_green().bold());
+        let color = colored::control::SHOULD_COLORIZE.should_colorize();
+        for line in calendar.render_terminal(color).lines() {
+            println!("  {}", line);
+        }
         println!();
     }
 
     /// Display identified risks
     fn display_risks(&self, risks: &[String]) {
-        println!("{}", "⚠️  Potential Risks".yellow().bold());
+        println!("⚠️  {}", t!("dry_run.risks_title").yellow().bold());
         for risk in risks {
             println!("  {} {}", "•".yellow(), risk.red());
         }
@@ -389,7 +568,7 @@ impl DryRunExecutor {
 
     /// Display confirmations needed
     fn display_confirmations_needed(&self, confirmations: &[String]) {
-        println!("{}", "✋ Confirmations Needed".bright_yellow().bold());
+        println!("✋ {}", t!("dry_run.confirmations_title").bright_yellow().bold());
         for confirmation in confirmations {
             println!("  {} {}", "•".yellow(), confirmation.cyan());
         }
@@ -398,33 +577,39 @@ impl DryRunExecutor {
 
     /// Display detailed operations
     fn display_operations(&self, operations: &[PlannedOperation]) {
-        println!("{}", "🔧 Detailed Operations".bright_blue().bold());
+        println!("🔧 {}", t!("dry_run.operations_title").bright_blue().bold());
         
         for (i, operation) in operations.iter().enumerate() {
             let step_num = format!("{}.", i + 1);
             match operation {
                 PlannedOperation::ValidateToken { username } => {
-                    println!("  {} {} Validate GitHub token for user '{}'", 
-                        step_num.bright_white(), "🔑".blue(), username.bright_green());
+                    println!("  {} {} {}", step_num.bright_white(), "🔑".blue(),
+                        t!("dry_run.op_validate_token", username = username.bright_green()));
+                }
+                PlannedOperation::CheckOrganizationAccess { organization, username } => {
+                    println!("  {} {} {}", step_num.bright_white(), "🏢".blue(),
+                        t!("dry_run.op_check_organization", username = username.bright_green(), organization = organization.bright_green()));
                 }
                 PlannedOperation::CheckRepository { repository, username } => {
-                    println!("  {} {} Check if repository '{}/{}' exists", 
-                        step_num.bright_white(), "🔍".blue(), username.dimmed(), repository.bright_green());
+                    println!("  {} {} {}", step_num.bright_white(), "🔍".blue(),
+                        t!("dry_run.op_check_repository", username = username.dimmed(), repository = repository.bright_green()));
                 }
                 PlannedOperation::CreateRepository { repository, description, private } => {
-                    println!("  {} {} Create {} repository '{}' with description: '{}'", 
-                        step_num.bright_white(), "📁".green(), 
-                        if *private { "private" } else { "public" },
-                        repository.bright_green(), description.dimmed());
+                    let (repository, description) = (repository.bright_green(), description.dimmed());
+                    let create = if *private {
+                        t!("dry_run.op_create_private_repository", repository = repository, description = description)
+                    } else {
+                        t!("dry_run.op_create_public_repository", repository = repository, description = description)
+                    };
+                    println!("  {} {} {}", step_num.bright_white(), "📁".green(), create);
                 }
                 PlannedOperation::CloneRepository { repository, branch, url } => {
-                    println!("  {} {} Clone repository '{}' (branch: {}) from {}", 
-                        step_num.bright_white(), "⬇️".blue(), repository.bright_green(), 
-                        branch.bright_cyan(), url.dimmed());
+                    println!("  {} {} {}", step_num.bright_white(), "⬇️".blue(),
+                        t!("dry_run.op_clone", repository = repository.bright_green(), branch = branch.bright_cyan(), url = url.dimmed()));
                 }
                 PlannedOperation::CreateFile { filename, content_preview } => {
-                    println!("  {} {} Create file '{}'", 
-                        step_num.bright_white(), "📄".green(), filename.bright_yellow());
+                    println!("  {} {} {}", step_num.bright_white(), "📄".green(),
+                        t!("dry_run.op_create_file", filename = filename.bright_yellow()));
                     if self.config.show_file_previews {
                         for line in content_preview.lines() {
                             println!("      {}", line.dimmed());
@@ -432,22 +617,40 @@ impl DryRunExecutor {
                     }
                 }
                 PlannedOperation::CreateCommit { year, timestamp, author, message, files } => {
-                    println!("  {} {} Create backdated commit for year {}", 
-                        step_num.bright_white(), "💾".green(), year.to_string().bright_yellow());
-                    println!("      {} {}", "Timestamp:".dimmed(), timestamp.bright_white());
-                    println!("      {} {} <{}>", "Author:".dimmed(), author.name.bright_cyan(), author.email.dimmed());
-                    println!("      {} {}", "Message:".dimmed(), message.bright_white());
-                    println!("      {} {}", "Files:".dimmed(), files.join(", ").bright_yellow());
+                    println!("  {} {} {}", step_num.bright_white(), "💾".green(),
+                        t!("dry_run.op_commit", year = year.to_string().bright_yellow()));
+                    println!("      {} {}", t!("dry_run.label_timestamp").dimmed(), timestamp.bright_white());
+                    println!("      {} {} <{}>", t!("dry_run.label_author").dimmed(), author.name.bright_cyan(), author.email.dimmed());
+                    println!("      {} {}", t!("dry_run.label_message").dimmed(), message.bright_white());
+                    println!("      {} {}", t!("dry_run.label_files").dimmed(), files.join(", ").bright_yellow());
                 }
                 PlannedOperation::PushCommit { repository, branch, force } => {
-                    println!("  {} {} {} push to '{}/{}' (branch: {})", 
-                        step_num.bright_white(), "⬆️".blue(),
-                        if *force { "Force".red() } else { "Push".green() },
-                        repository.bright_green(), branch.bright_cyan(), branch.bright_cyan());
+                    let push = if *force { t!("dry_run.op_force_push").red() } else { t!("dry_run.op_push").green() };
+                    println!("  {} {} {}", step_num.bright_white(), "⬆️".blue(),
+                        t!("dry_run.op_push_target", push = push, repository = repository.bright_green(), branch = branch.bright_cyan()));
+                }
+                PlannedOperation::OpenPullRequest { repository, head, base, title, auto_merge } => {
+                    println!("  {} {} {}", step_num.bright_white(), "🔀".green(),
+                        t!("dry_run.op_pull_request", repository = repository.bright_green(), head = head.bright_cyan(), base = base.bright_cyan()));
+                    println!("      {} {}", t!("dry_run.label_title").dimmed(), title.bright_white());
+                    if *auto_merge {
+                        println!("      {} {}", t!("dry_run.label_auto_merge").dimmed(), t!("dry_run.enabled").bright_yellow());
+                    }
+                }
+                PlannedOperation::UpdateRepository { repository, changes } => {
+                    println!("  {} {} {}", step_num.bright_white(), "🏷️".cyan(),
+                        t!("dry_run.op_update_repository", repository = repository.bright_green()));
+                    for change in changes {
+                        println!("      {} {}", "•".dimmed(), change.bright_white());
+                    }
+                }
+                PlannedOperation::ArchiveRepository { repository } => {
+                    println!("  {} {} {}", step_num.bright_white(), "📦".yellow(),
+                        t!("dry_run.op_archive", repository = repository.bright_green()));
                 }
                 PlannedOperation::Cleanup { temp_path } => {
-                    println!("  {} {} Clean up temporary files: {}", 
-                        step_num.bright_white(), "🧹".yellow(), temp_path.dimmed());
+                    println!("  {} {} {}", step_num.bright_white(), "🧹".yellow(),
+                        t!("dry_run.op_cleanup", path = temp_path.dimmed()));
                 }
             }
         }
@@ -463,16 +666,34 @@ pub fn create_single_config_plan(config: &TimeTravelConfig, dry_run_config: DryR
 
 /// Helper function to display and confirm a dry run for multiple configurations
 pub fn display_and_confirm_dry_run(configs: &[TimeTravelConfig], interactive: bool) -> Result<bool> {
+    display_and_confirm_dry_run_with_pr(configs, None, None, interactive)
+}
+
+/// Helper function to display and confirm a dry run, optionally delivered through a pull request
+/// and followed by repository lifecycle changes
+pub fn display_and_confirm_dry_run_with_pr(
+    configs: &[TimeTravelConfig],
+    pull_request: Option<&PullRequestOptions>,
+    lifecycle: Option<&RepositoryLifecycle>,
+    interactive: bool,
+) -> Result<bool> {
     let dry_run_config = DryRunConfig {
         show_detailed_operations: true,
         show_file_previews: false, // Keep it concise for multiple configs
         show_risks: true,
         require_confirmation: true,
         interactive_confirmations: interactive,
+        show_heatmap: true,
     };
 
     let executor = DryRunExecutor::new(dry_run_config);
-    let plan = executor.create_plan(configs)?;
+    let mut plan = match pull_request {
+        Some(options) => executor.create_pull_request_plan(configs, options)?,
+        None => executor.create_plan(configs)?,
+    };
+    if let (Some(lifecycle), Some(config)) = (lifecycle, configs.first()) {
+        executor.add_lifecycle_operations(&mut plan, config, lifecycle);
+    }
     
     executor.display_plan(&plan)?;
     executor.confirm_execution(&plan)
@@ -506,6 +727,53 @@ mod tests {
         assert_eq!(plan.summary.commits_to_create, 1);
     }
 
+    #[test]
+    fn test_create_pull_request_plan() {
+        let executor = DryRunExecutor::new(DryRunConfig::default());
+        let config = create_test_config();
+        let options = PullRequestOptions::for_years(&[config.year], true);
+
+        let plan = executor.create_pull_request_plan(&[config], &options).unwrap();
+
+        assert!(plan.operations.iter().any(|op| matches!(op,
+            PlannedOperation::PushCommit { branch, .. } if *branch == options.branch)));
+        assert!(matches!(plan.operations[plan.operations.len() - 2],
+            PlannedOperation::OpenPullRequest { auto_merge: true, .. }));
+        assert!(matches!(plan.operations.last(), Some(PlannedOperation::Cleanup { .. })));
+    }
+
+    #[test]
+    fn test_organization_owner_plan() {
+        let executor = DryRunExecutor::new(DryRunConfig::default());
+        let config = create_test_config().with_owner(Some("acme".to_string())).unwrap();
+
+        let plan = executor.create_plan(&[config]).unwrap();
+
+        assert!(matches!(&plan.operations[1],
+            PlannedOperation::CheckOrganizationAccess { organization, .. } if organization == "acme"));
+        assert!(plan.operations.iter().any(|op| matches!(op,
+            PlannedOperation::CloneRepository { url, .. } if url == "https://github.com/acme/testrepo.git")));
+    }
+
+    #[test]
+    fn test_lifecycle_operations_run_before_cleanup() {
+        let executor = DryRunExecutor::new(DryRunConfig::default());
+        let config = create_test_config();
+        let lifecycle = RepositoryLifecycle::new(
+            vec!["Retro".to_string()], None, None, Some("public".to_string()), true,
+        ).unwrap();
+
+        let mut plan = executor.create_plan(std::slice::from_ref(&config)).unwrap();
+        executor.add_lifecycle_operations(&mut plan, &config, &lifecycle);
+
+        let count = plan.operations.len();
+        assert!(matches!(&plan.operations[count - 3],
+            PlannedOperation::UpdateRepository { changes, .. } if changes[0] == "topics: retro, git-timetraveler"));
+        assert!(matches!(plan.operations[count - 2], PlannedOperation::ArchiveRepository { .. }));
+        assert!(matches!(plan.operations.last(), Some(PlannedOperation::Cleanup { .. })));
+        assert!(plan.risks.iter().any(|risk| risk.contains("archived")));
+    }
+
     #[test]
     fn test_identify_risks() {
         let config = create_test_config();
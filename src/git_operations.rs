@@ -1,15 +1,24 @@
 use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
-use git2::{Repository, Signature, Commit, PushOptions, RemoteCallbacks, Cred};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use git2::{Repository, Signature, Commit, Oid, PushOptions, RemoteCallbacks, Cred, Sort};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tempfile::TempDir;
 use crate::git_context::GitIdentity;
+use crate::cancel::{self, CancellationToken};
 use crate::errors::TimeTravelError;
+use crate::progress::{ProgressEvent, ProgressSink};
+use crate::secret::Secret;
 
 /// Enhanced Git operations for time travel functionality
 pub struct GitOperations {
     /// Optional temporary directory for cloned repositories
     temp_dir: Option<TempDir>,
+    /// Checked by the clone and push callbacks to abort transfers in flight
+    cancellation: CancellationToken,
+    /// Receives clone, commit and push events, including git transfer progress
+    progress: Option<Arc<dyn ProgressSink>>,
 }
 
 /// Configuration for creating time travel commits
@@ -35,7 +44,7 @@ pub struct RepositoryConfig {
 #[derive(Debug, Clone)]
 pub struct GitCredentials {
     pub username: String,
-    pub token: String,
+    pub token: Secret,
 }
 
 /// Result of a time travel commit operation
@@ -55,11 +64,95 @@ pub struct RepositoryResult {
     pub head_commit: Option<String>,
 }
 
+/// How commit dates are moved when shifting an existing history
+#[derive(Debug, Clone)]
+pub enum DateShift {
+    /// Move every commit by a fixed offset
+    Offset(Duration),
+    /// Map the original span of the history proportionally into a window
+    Window {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+}
+
+/// Configuration for shifting the dates of an existing history
+#[derive(Debug, Clone)]
+pub struct HistoryShiftConfig {
+    pub shift: DateShift,
+    /// Also move committer dates (author dates are always moved)
+    pub shift_committer: bool,
+}
+
+/// A single commit whose dates would be or were moved
+#[derive(Debug, Clone)]
+pub struct ShiftedCommit {
+    pub original_id: String,
+    pub summary: String,
+    pub original_author_time: DateTime<Utc>,
+    pub new_author_time: DateTime<Utc>,
+}
+
+/// Result of a history rewrite operation
+#[derive(Debug)]
+pub struct HistoryRewriteResult {
+    pub commits: Vec<ShiftedCommit>,
+    /// Mapping of original commit IDs to rewritten commit IDs
+    pub commit_map: HashMap<String, String>,
+    pub refs_updated: Vec<String>,
+    /// Local branches that were rewritten (candidates for pushing)
+    pub branches_updated: Vec<String>,
+    /// Refs that keep the pre-rewrite branch tips reachable
+    pub backup_refs: Vec<String>,
+}
+
+/// Where re-dated commits are moved to
+#[derive(Debug, Clone)]
+pub enum RedateTarget {
+    /// Move the oldest selected commit to this date, keeping the spacing of the rest
+    Date(DateTime<Utc>),
+    /// Assign one timestamp per selected commit, oldest commit first
+    Schedule(Vec<DateTime<Utc>>),
+}
+
+/// Selected commits (parents first) and their new (author, committer) seconds
+type RedatePlan = (Vec<Oid>, HashMap<Oid, (i64, i64)>);
+
+/// Configuration for re-dating selected commits of an existing repository
+#[derive(Debug, Clone)]
+pub struct RedateConfig {
+    /// Revision or range to re-date, e.g. "HEAD~3..HEAD" or a single commit
+    pub range: String,
+    pub target: RedateTarget,
+    /// Also rewrite committer dates
+    pub redate_committer: bool,
+}
+
 impl GitOperations {
     /// Create a new GitOperations instance
     pub fn new() -> Self {
         Self {
             temp_dir: None,
+            cancellation: CancellationToken::new(),
+            progress: None,
+        }
+    }
+
+    /// Abort clones and pushes in flight when the token is cancelled
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Report clone, commit and push progress to `progress`
+    pub fn with_progress(mut self, progress: Arc<dyn ProgressSink>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    fn emit(&self, event: ProgressEvent) {
+        if let Some(ref progress) = self.progress {
+            progress.emit(event);
         }
     }
 
@@ -74,36 +167,66 @@ impl GitOperations {
                     &e.to_string()
                 ))
                 .context("Failed to create temporary directory")?);
+            if let Some(ref temp_dir) = self.temp_dir {
+                cancel::track_temp_dir(temp_dir.path());
+            }
         }
 
         let temp_dir = self.temp_dir.as_ref().unwrap();
         let repo_name = self.extract_repo_name_from_url(&config.url)?;
         let local_path = temp_dir.path().join(&repo_name);
 
+        self.cancellation.check("clone")?;
+
         // Set up clone options with credentials if provided
         let mut builder = git2::build::RepoBuilder::new();
-        
+        let mut callbacks = RemoteCallbacks::new();
+
         if let Some(ref creds) = config.credentials {
-            let mut callbacks = RemoteCallbacks::new();
             let username = creds.username.clone();
             let token = creds.token.clone();
             
             callbacks.credentials(move |_url, _username_from_url, _allowed_types| {
-                Cred::userpass_plaintext(&username, &token)
+                Cred::userpass_plaintext(&username, token.expose())
             });
-            
-            let mut fetch_options = git2::FetchOptions::new();
-            fetch_options.remote_callbacks(callbacks);
-            builder.fetch_options(fetch_options);
         }
 
+        // Returning false from the progress callback aborts the transfer
+        let cancellation = self.cancellation.clone();
+        let sink = self.progress.clone();
+        let mut last_objects = None;
+        callbacks.transfer_progress(move |progress| {
+            if let Some(ref sink) = sink {
+                // git reports every chunk; only forward when the object count moves
+                if last_objects != Some(progress.received_objects()) {
+                    last_objects = Some(progress.received_objects());
+                    sink.emit(ProgressEvent::CloneProgress {
+                        objects: progress.received_objects(),
+                        total_objects: progress.total_objects(),
+                        bytes: progress.received_bytes(),
+                    });
+                }
+            }
+            !cancellation.is_cancelled()
+        });
+
+        let mut fetch_options = git2::FetchOptions::new();
+        fetch_options.remote_callbacks(callbacks);
+        builder.fetch_options(fetch_options);
+
+        self.emit(ProgressEvent::CloneStarted { url: config.url.clone() });
+        tracing::info!(target: "git", url = %config.url, branch = %config.branch, path = %local_path.display(), "Cloning repository");
+
         // Clone the repository
         let repo = builder
             .branch(&config.branch)
             .clone(&config.url, &local_path)
             .map_err(|e| {
+                tracing::warn!(target: "git", error = %e, code = ?e.code(), "Clone failed");
                 let error_msg = e.to_string();
-                if error_msg.contains("authentication") || error_msg.contains("401") {
+                if self.cancellation.is_cancelled() {
+                    TimeTravelError::cancelled("clone")
+                } else if error_msg.contains("authentication") || error_msg.contains("401") {
                     TimeTravelError::git_operation("clone", "Authentication failed - check your GitHub token")
                 } else if error_msg.contains("not found") || error_msg.contains("404") {
                     TimeTravelError::git_operation("clone", "Repository not found - check the URL and permissions")
@@ -118,6 +241,8 @@ impl GitOperations {
         // Get current branch and head commit
         let current_branch = self.get_current_branch_name(&repo)?;
         let head_commit = self.get_head_commit_id(&repo)?;
+        tracing::debug!(target: "git", branch = ?current_branch, head = ?head_commit, "Clone finished");
+        self.emit(ProgressEvent::Cloned);
 
         Ok(RepositoryResult {
             repository_path: local_path,
@@ -184,6 +309,10 @@ impl GitOperations {
             &parents,
         ).context("Failed to create commit")?;
 
+        tracing::info!(target: "git", sha = %commit_id, timestamp = %config.timestamp, author = %config.author.email,
+            files = config.files_to_add.len(), "Created commit");
+        self.emit(ProgressEvent::CommitCreated { sha: commit_id.to_string() });
+
         Ok(CommitResult {
             commit_id: commit_id.to_string(),
             timestamp: config.timestamp,
@@ -192,6 +321,76 @@ impl GitOperations {
         })
     }
 
+    /// Check that the working repository is in a state where committing on
+    /// the current branch is safe, returning the branch name
+    pub fn ensure_safe_to_commit(&self, repo: &Repository) -> Result<String> {
+        if repo.is_bare() {
+            return Err(TimeTravelError::git_operation(
+                "commit",
+                "Repository is bare - run this inside a working tree"
+            ).into());
+        }
+
+        if repo.head_detached().unwrap_or(false) {
+            return Err(TimeTravelError::git_operation(
+                "commit",
+                "HEAD is detached - check out a branch before committing"
+            ).into());
+        }
+
+        if repo.state() != git2::RepositoryState::Clean {
+            return Err(TimeTravelError::git_operation(
+                "commit",
+                &format!("Repository has an operation in progress ({:?}) - finish or abort it first", repo.state())
+            ).into());
+        }
+
+        // HEAD may point at an unborn branch in a fresh repository
+        let head_ref = repo.find_reference("HEAD").context("Failed to read HEAD")?;
+        let branch = head_ref.symbolic_target()
+            .and_then(|target| target.strip_prefix("refs/heads/"))
+            .map(str::to_string)
+            .ok_or_else(|| TimeTravelError::git_operation("commit", "HEAD does not point to a branch"))?;
+
+        Ok(branch)
+    }
+
+    /// Create a backdated commit from the files currently staged in the index
+    pub fn commit_staged_changes(
+        &self,
+        repo: &Repository,
+        config: &TimeTravelCommitConfig,
+    ) -> Result<CommitResult> {
+        self.ensure_safe_to_commit(repo)?;
+
+        let head_tree = match repo.head() {
+            Ok(head) => Some(head.peel_to_tree().context("Failed to read HEAD tree")?),
+            Err(_) => None,
+        };
+        let diff = repo.diff_tree_to_index(head_tree.as_ref(), None, None)
+            .context("Failed to compare index with HEAD")?;
+        let staged: Vec<PathBuf> = diff.deltas()
+            .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+            .map(Path::to_path_buf)
+            .collect();
+
+        if staged.is_empty() {
+            return Err(TimeTravelError::git_operation(
+                "commit",
+                "No staged changes - use 'git add' to stage the files you want to commit"
+            ).into());
+        }
+
+        // The index is committed as-is; nothing else is added
+        let index_config = TimeTravelCommitConfig {
+            files_to_add: Vec::new(),
+            ..config.clone()
+        };
+        let mut result = self.create_time_travel_commit(repo, &index_config)?;
+        result.files_added = staged;
+        Ok(result)
+    }
+
     /// Push commits to remote repository
     pub fn push_to_remote(
         &self,
@@ -200,6 +399,19 @@ impl GitOperations {
         branch: &str,
         credentials: Option<&GitCredentials>,
         force: bool,
+    ) -> Result<()> {
+        self.push_branch_to_remote(repo, remote_name, branch, branch, credentials, force)
+    }
+
+    /// Push a local branch to a (possibly differently named) remote branch
+    pub fn push_branch_to_remote(
+        &self,
+        repo: &Repository,
+        remote_name: &str,
+        local_branch: &str,
+        remote_branch: &str,
+        credentials: Option<&GitCredentials>,
+        force: bool,
     ) -> Result<()> {
         // Find the remote
         let mut remote = repo.find_remote(remote_name)
@@ -209,33 +421,58 @@ impl GitOperations {
             ))
             .with_context(|| format!("Failed to find remote: {}", remote_name))?;
 
+        self.cancellation.check("push")?;
+
         // Set up push options with credentials if provided
         let mut push_options = PushOptions::new();
-        
+        let mut callbacks = RemoteCallbacks::new();
+
         if let Some(creds) = credentials {
-            let mut callbacks = RemoteCallbacks::new();
             let username = creds.username.clone();
             let token = creds.token.clone();
             
             callbacks.credentials(move |_url, _username_from_url, _allowed_types| {
-                Cred::userpass_plaintext(&username, &token)
+                Cred::userpass_plaintext(&username, token.expose())
+            });
+        }
+
+        // Stop before the pack is sent, or while the remote reports progress
+        let cancellation = self.cancellation.clone();
+        callbacks.push_negotiation(move |_updates| {
+            if cancellation.is_cancelled() {
+                Err(git2::Error::from_str("push cancelled"))
+            } else {
+                Ok(())
+            }
+        });
+        let cancellation = self.cancellation.clone();
+        callbacks.sideband_progress(move |_data| !cancellation.is_cancelled());
+        if let Some(sink) = self.progress.clone() {
+            callbacks.push_transfer_progress(move |objects, total_objects, bytes| {
+                sink.emit(ProgressEvent::PushProgress { objects, total_objects, bytes });
             });
-            
-            push_options.remote_callbacks(callbacks);
         }
 
+        push_options.remote_callbacks(callbacks);
+
         // Create refspec for push
         let refspec = if force {
-            format!("+refs/heads/{}:refs/heads/{}", branch, branch)
+            format!("+refs/heads/{}:refs/heads/{}", local_branch, remote_branch)
         } else {
-            format!("refs/heads/{}:refs/heads/{}", branch, branch)
+            format!("refs/heads/{}:refs/heads/{}", local_branch, remote_branch)
         };
 
+        self.emit(ProgressEvent::PushStarted { branch: remote_branch.to_string() });
+        tracing::info!(target: "git", remote = %remote_name, refspec = %refspec, force, "Pushing");
+
         // Push to remote
         remote.push(&[&refspec], Some(&mut push_options))
             .map_err(|e| {
+                tracing::warn!(target: "git", error = %e, code = ?e.code(), "Push failed");
                 let error_msg = e.to_string();
-                if error_msg.contains("authentication") || error_msg.contains("401") {
+                if self.cancellation.is_cancelled() {
+                    TimeTravelError::cancelled("push")
+                } else if error_msg.contains("authentication") || error_msg.contains("401") {
                     TimeTravelError::git_operation("push", "Authentication failed - check your GitHub token permissions")
                 } else if error_msg.contains("403") || error_msg.contains("forbidden") {
                     TimeTravelError::git_operation("push", "Push forbidden - check repository permissions or branch protection")
@@ -248,10 +485,394 @@ impl GitOperations {
                 }
             })
             .context("Failed to push to remote")?;
+        tracing::debug!(target: "git", remote = %remote_name, branch = %remote_branch, "Push finished");
+        self.emit(ProgressEvent::Pushed { branch: remote_branch.to_string() });
 
         Ok(())
     }
 
+    /// Preview how a history shift would move every commit, without writing anything
+    pub fn preview_history_shift(
+        &self,
+        repo: &Repository,
+        config: &HistoryShiftConfig,
+    ) -> Result<Vec<ShiftedCommit>> {
+        let oids = self.collect_history(repo)?;
+        let mapper = self.build_time_mapper(repo, &oids, config)?;
+
+        oids.iter()
+            .map(|oid| {
+                let commit = repo.find_commit(*oid).context("Failed to find commit")?;
+                let original = commit.author().when().seconds();
+                Ok(ShiftedCommit {
+                    original_id: oid.to_string(),
+                    summary: commit.summary().unwrap_or("").to_string(),
+                    original_author_time: seconds_to_utc(original)?,
+                    new_author_time: seconds_to_utc(mapper(original))?,
+                })
+            })
+            .collect()
+    }
+
+    /// Shift the dates of every commit reachable from local branches and tags.
+    ///
+    /// Trees, messages, identities and merge structure are preserved; only the
+    /// author (and optionally committer) dates change. Branches and tags are
+    /// moved to the rewritten commits.
+    pub fn shift_history(
+        &self,
+        repo: &Repository,
+        config: &HistoryShiftConfig,
+    ) -> Result<HistoryRewriteResult> {
+        let oids = self.collect_history(repo)?;
+        let mapper = self.build_time_mapper(repo, &oids, config)?;
+        let shift_committer = config.shift_committer;
+
+        self.rewrite_history(repo, &oids, |commit| {
+            let author = commit.author().when().seconds();
+            let committer = commit.committer().when().seconds();
+            let new_committer = if shift_committer { mapper(committer) } else { committer };
+            Some((mapper(author), new_committer))
+        })
+    }
+
+    /// Preview the new dates of the commits selected for re-dating
+    pub fn preview_redate(&self, repo: &Repository, config: &RedateConfig) -> Result<Vec<ShiftedCommit>> {
+        let (selected, new_times) = self.plan_redate(repo, config)?;
+
+        selected.iter()
+            .map(|oid| {
+                let commit = repo.find_commit(*oid).context("Failed to find commit")?;
+                let original = commit.author().when().seconds();
+                Ok(ShiftedCommit {
+                    original_id: oid.to_string(),
+                    summary: commit.summary().unwrap_or("").to_string(),
+                    original_author_time: seconds_to_utc(original)?,
+                    new_author_time: seconds_to_utc(new_times[oid].0)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Rewrite the dates of the selected commits and rebase their descendants.
+    ///
+    /// Tree contents are unchanged. Every local branch tip is first saved under
+    /// `refs/git-timetraveler/backup/` so the old history can be restored.
+    pub fn redate_commits(&self, repo: &Repository, config: &RedateConfig) -> Result<HistoryRewriteResult> {
+        if repo.state() != git2::RepositoryState::Clean {
+            return Err(TimeTravelError::git_operation(
+                "rewrite",
+                &format!("Repository has an operation in progress ({:?}) - finish or abort it first", repo.state())
+            ).into());
+        }
+
+        let (_, new_times) = self.plan_redate(repo, config)?;
+        let backup_refs = self.write_backup_refs(repo)?;
+        let oids = self.collect_history(repo)?;
+
+        let mut result = self.rewrite_history(repo, &oids, |commit| new_times.get(&commit.id()).copied())?;
+        result.backup_refs = backup_refs;
+        Ok(result)
+    }
+
+    /// Resolve the selected commits (parents first) and their new (author, committer) seconds
+    fn plan_redate(&self, repo: &Repository, config: &RedateConfig) -> Result<RedatePlan> {
+        let selected = self.resolve_revisions(repo, &config.range)?;
+
+        let commits = selected.iter()
+            .map(|oid| repo.find_commit(*oid))
+            .collect::<std::result::Result<Vec<Commit>, git2::Error>>()
+            .context("Failed to find selected commit")?;
+
+        let new_author_times: Vec<i64> = match &config.target {
+            RedateTarget::Date(date) => {
+                let oldest = commits.iter().map(|c| c.author().when().seconds()).min().unwrap_or(0);
+                let delta = date.timestamp() - oldest;
+                commits.iter().map(|c| c.author().when().seconds() + delta).collect()
+            }
+            RedateTarget::Schedule(times) => {
+                if times.len() != commits.len() {
+                    return Err(TimeTravelError::invalid_input(
+                        "schedule",
+                        &format!("{} timestamps", times.len()),
+                        &format!("the range selects {} commits", commits.len()),
+                        "Provide exactly one schedule entry per selected commit"
+                    ).into());
+                }
+                let mut sorted = times.clone();
+                sorted.sort();
+                sorted.iter().map(DateTime::timestamp).collect()
+            }
+        };
+
+        let new_times = commits.iter().zip(new_author_times)
+            .map(|(commit, author_secs)| {
+                let committer_secs = if config.redate_committer {
+                    author_secs
+                } else {
+                    commit.committer().when().seconds()
+                };
+                (commit.id(), (author_secs, committer_secs))
+            })
+            .collect();
+
+        Ok((selected, new_times))
+    }
+
+    /// Resolve a single revision or an "A..B" range into commits, parents first
+    fn resolve_revisions(&self, repo: &Repository, range: &str) -> Result<Vec<Oid>> {
+        let invalid = |reason: &str| TimeTravelError::invalid_input(
+            "revision range",
+            range,
+            reason,
+            "Use a commit (e.g. HEAD~2) or a range (e.g. HEAD~3..HEAD)"
+        );
+
+        let mut revwalk = repo.revwalk().context("Failed to create revision walker")?;
+        revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)
+            .context("Failed to configure revision walker")?;
+
+        if range.contains("..") {
+            revwalk.push_range(range).map_err(|e| invalid(e.message()))?;
+            let oids = revwalk
+                .collect::<std::result::Result<Vec<Oid>, git2::Error>>()
+                .context("Failed to walk revision range")?;
+            if oids.is_empty() {
+                return Err(invalid("selects no commits").into());
+            }
+            Ok(oids)
+        } else {
+            let commit = repo.revparse_single(range)
+                .and_then(|object| object.peel_to_commit())
+                .map_err(|e| invalid(e.message()))?;
+            Ok(vec![commit.id()])
+        }
+    }
+
+    /// Save every local branch tip under a timestamped backup namespace
+    fn write_backup_refs(&self, repo: &Repository) -> Result<Vec<String>> {
+        let stamp = Utc::now().format("%Y%m%d%H%M%S");
+        let mut backups = Vec::new();
+
+        for branch in repo.branches(Some(git2::BranchType::Local)).context("Failed to list branches")? {
+            let (branch, _) = branch.context("Failed to read branch")?;
+            let (Some(name), Some(target)) = (branch.name().ok().flatten(), branch.get().target()) else { continue };
+            let backup = format!("refs/git-timetraveler/backup/{}/{}", stamp, name);
+            repo.reference(&backup, target, true, "git-timetraveler: backup before redate")
+                .with_context(|| format!("Failed to write backup ref {}", backup))?;
+            backups.push(backup);
+        }
+
+        Ok(backups)
+    }
+
+    /// Collect all commits reachable from local branches and tags, parents first
+    fn collect_history(&self, repo: &Repository) -> Result<Vec<Oid>> {
+        let mut revwalk = repo.revwalk().context("Failed to create revision walker")?;
+        revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)
+            .context("Failed to configure revision walker")?;
+        revwalk.push_glob("refs/heads/*").context("Failed to walk local branches")?;
+        revwalk.push_glob("refs/tags/*").context("Failed to walk tags")?;
+
+        let oids = revwalk
+            .collect::<std::result::Result<Vec<Oid>, git2::Error>>()
+            .context("Failed to walk repository history")?;
+
+        if oids.is_empty() {
+            return Err(TimeTravelError::git_operation(
+                "rewrite",
+                "Repository has no commits to rewrite"
+            ).into());
+        }
+
+        Ok(oids)
+    }
+
+    /// Build the function that maps an original timestamp (seconds) to its shifted value
+    fn build_time_mapper(
+        &self,
+        repo: &Repository,
+        oids: &[Oid],
+        config: &HistoryShiftConfig,
+    ) -> Result<Box<dyn Fn(i64) -> i64>> {
+        match &config.shift {
+            DateShift::Offset(offset) => {
+                let delta = offset.num_seconds();
+                Ok(Box::new(move |seconds| seconds + delta))
+            }
+            DateShift::Window { start, end } => {
+                if end < start {
+                    return Err(TimeTravelError::invalid_input(
+                        "window",
+                        &format!("{}..{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d")),
+                        "end of the window is before its start",
+                        "Use a window like 2010-01-01..2012-12-31"
+                    ).into());
+                }
+
+                // The original span covers every timestamp that is going to move
+                let mut min = i64::MAX;
+                let mut max = i64::MIN;
+                for oid in oids {
+                    let commit = repo.find_commit(*oid).context("Failed to find commit")?;
+                    let mut times = vec![commit.author().when().seconds()];
+                    if config.shift_committer {
+                        times.push(commit.committer().when().seconds());
+                    }
+                    for t in times {
+                        min = min.min(t);
+                        max = max.max(t);
+                    }
+                }
+
+                let target_start = start.timestamp();
+                let target_span = (end.timestamp() - target_start) as i128;
+                let source_span = (max - min) as i128;
+
+                Ok(Box::new(move |seconds| {
+                    if source_span == 0 {
+                        return target_start;
+                    }
+                    let scaled = (seconds - min) as i128 * target_span / source_span;
+                    target_start + scaled as i64
+                }))
+            }
+        }
+    }
+
+    /// Rewrite the given commits (parents first) and move local refs onto the result.
+    ///
+    /// `new_times` returns the new (author, committer) seconds for a commit, or
+    /// `None` to keep its dates; commits are re-created whenever their dates or
+    /// any of their parents change.
+    fn rewrite_history<F>(
+        &self,
+        repo: &Repository,
+        oids: &[Oid],
+        new_times: F,
+    ) -> Result<HistoryRewriteResult>
+    where
+        F: Fn(&Commit) -> Option<(i64, i64)>,
+    {
+        tracing::info!(target: "git", commits = oids.len(), "Rewriting history");
+        let mut mapping: HashMap<Oid, Oid> = HashMap::new();
+        let mut commits = Vec::new();
+
+        for oid in oids {
+            let commit = repo.find_commit(*oid).context("Failed to find commit")?;
+            let parents = commit.parents()
+                .map(|p| mapping.get(&p.id()).copied().unwrap_or_else(|| p.id()))
+                .collect::<Vec<Oid>>();
+            let parents_changed = parents.iter().zip(commit.parent_ids()).any(|(new, old)| *new != old);
+
+            let author = commit.author();
+            let committer = commit.committer();
+            let (author_secs, committer_secs) = new_times(&commit)
+                .unwrap_or((author.when().seconds(), committer.when().seconds()));
+            let dates_changed = author_secs != author.when().seconds()
+                || committer_secs != committer.when().seconds();
+
+            if !parents_changed && !dates_changed {
+                continue;
+            }
+
+            let new_author = resign(&author, author_secs)?;
+            let new_committer = resign(&committer, committer_secs)?;
+            let parent_commits = parents.iter()
+                .map(|id| repo.find_commit(*id))
+                .collect::<std::result::Result<Vec<Commit>, git2::Error>>()
+                .context("Failed to find parent commit")?;
+            let parent_refs: Vec<&Commit> = parent_commits.iter().collect();
+            let tree = commit.tree().context("Failed to read commit tree")?;
+
+            let new_oid = write_rewritten_commit(repo, &commit, &new_author, &new_committer, &tree, &parent_refs)
+                .map_err(|e| TimeTravelError::git_operation("commit", &e.to_string()))
+                .context("Failed to rewrite commit")?;
+            mapping.insert(*oid, new_oid);
+
+            if dates_changed {
+                commits.push(ShiftedCommit {
+                    original_id: oid.to_string(),
+                    summary: commit.summary().unwrap_or("").to_string(),
+                    original_author_time: seconds_to_utc(author.when().seconds())?,
+                    new_author_time: seconds_to_utc(author_secs)?,
+                });
+            }
+        }
+
+        let (refs_updated, branches_updated) = self.update_rewritten_refs(repo, &mapping)?;
+
+        Ok(HistoryRewriteResult {
+            backup_refs: Vec::new(),
+            commits,
+            commit_map: mapping.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            refs_updated,
+            branches_updated,
+        })
+    }
+
+    /// Move local branches, tags and a detached HEAD onto rewritten commits
+    fn update_rewritten_refs(
+        &self,
+        repo: &Repository,
+        mapping: &HashMap<Oid, Oid>,
+    ) -> Result<(Vec<String>, Vec<String>)> {
+        let mut refs_updated = Vec::new();
+        let mut branches_updated = Vec::new();
+
+        let references = repo.references().context("Failed to list references")?;
+        let mut updates = Vec::new();
+        for reference in references {
+            let reference = reference.context("Failed to read reference")?;
+            let name = match reference.name() {
+                Some(name) if name.starts_with("refs/heads/") || name.starts_with("refs/tags/") => name.to_string(),
+                _ => continue,
+            };
+            if let Some(target) = reference.target() {
+                updates.push((name, target));
+            }
+        }
+
+        for (name, target) in updates {
+            if let Some(new_target) = mapping.get(&target) {
+                repo.reference(&name, *new_target, true, "git-timetraveler: rewrite dates")
+                    .with_context(|| format!("Failed to update {}", name))?;
+            } else if let Ok(tag) = repo.find_tag(target) {
+                // Annotated tags are re-created pointing at the rewritten commit
+                let Some(new_target) = mapping.get(&tag.target_id()) else { continue };
+                let object = repo.find_object(*new_target, None).context("Failed to find rewritten commit")?;
+                let tag_name = tag.name().unwrap_or_else(|| name.trim_start_matches("refs/tags/"));
+                match tag.tagger() {
+                    Some(tagger) => {
+                        repo.tag(tag_name, &object, &tagger, tag.message().unwrap_or(""), true)
+                            .with_context(|| format!("Failed to re-create tag {}", tag_name))?;
+                    }
+                    None => {
+                        repo.reference(&name, *new_target, true, "git-timetraveler: rewrite dates")
+                            .with_context(|| format!("Failed to update {}", name))?;
+                    }
+                }
+            } else {
+                continue;
+            }
+
+            if let Some(branch) = name.strip_prefix("refs/heads/") {
+                branches_updated.push(branch.to_string());
+            }
+            refs_updated.push(name);
+        }
+
+        // A detached HEAD does not follow branch updates
+        if repo.head_detached().unwrap_or(false) {
+            if let Some(new_head) = repo.head().ok().and_then(|h| h.target()).and_then(|t| mapping.get(&t)) {
+                repo.set_head_detached(*new_head).context("Failed to move detached HEAD")?;
+                refs_updated.push("HEAD".to_string());
+            }
+        }
+
+        Ok((refs_updated, branches_updated))
+    }
+
     /// Create a new file with content in the repository
     pub fn create_file_with_content(
         &self,
@@ -342,8 +963,49 @@ impl GitOperations {
 
     /// Clean up temporary directory
     pub fn cleanup(&mut self) {
-        self.temp_dir = None;
+        if let Some(temp_dir) = self.temp_dir.take() {
+            cancel::untrack_temp_dir(temp_dir.path());
+        }
+    }
+}
+
+/// Write a copy of `original` with new signatures and parents. The message bytes and
+/// `encoding` header are kept as they are, which `Repository::commit` cannot do for non-UTF-8 messages.
+fn write_rewritten_commit(
+    repo: &Repository,
+    original: &Commit,
+    author: &Signature,
+    committer: &Signature,
+    tree: &git2::Tree,
+    parents: &[&Commit],
+) -> std::result::Result<Oid, git2::Error> {
+    let buffer = repo.commit_create_buffer(author, committer, "", tree, parents)?;
+    // Headers end at the blank line before the (here empty) message
+    let headers_end = buffer.windows(2).position(|pair| pair == b"\n\n").map_or(buffer.len(), |index| index + 1);
+    let mut object = buffer[..headers_end].to_vec();
+    if let Some(encoding) = original.message_encoding() {
+        object.extend_from_slice(format!("encoding {}\n", encoding).as_bytes());
     }
+    object.push(b'\n');
+    object.extend_from_slice(original.message_raw_bytes());
+    repo.odb()?.write(git2::ObjectType::Commit, &object)
+}
+
+/// Re-create a signature with the same identity and offset at a new time
+fn resign(signature: &Signature, seconds: i64) -> Result<Signature<'static>> {
+    let offset = signature.when().offset_minutes();
+    Signature::new(
+        &String::from_utf8_lossy(signature.name_bytes()),
+        &String::from_utf8_lossy(signature.email_bytes()),
+        &git2::Time::new(seconds, offset),
+    ).context("Failed to create rewritten signature")
+}
+
+/// Convert git seconds since the epoch to a UTC timestamp
+fn seconds_to_utc(seconds: i64) -> Result<DateTime<Utc>> {
+    Utc.timestamp_opt(seconds, 0)
+        .single()
+        .context("Commit timestamp is out of range")
 }
 
 impl Drop for GitOperations {
@@ -444,6 +1106,199 @@ mod tests {
         Ok(())
     }
 
+    fn commit_at(repo: &Repository, file: &str, seconds: i64, parents: &[&Commit]) -> Result<Oid> {
+        let workdir = repo.workdir().unwrap();
+        fs::write(workdir.join(file), file)?;
+        let mut index = repo.index()?;
+        index.add_path(Path::new(file))?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let sig = Signature::new("Test User", "test@example.com", &git2::Time::new(seconds, 60))?;
+        Ok(repo.commit(Some("HEAD"), &sig, &sig, file, &tree, parents)?)
+    }
+
+    #[test]
+    fn test_shift_history_by_offset() -> Result<()> {
+        let (_temp_dir, repo) = create_test_repo()?;
+        let git_ops = GitOperations::new();
+
+        let first = commit_at(&repo, "a.txt", 1_600_000_000, &[])?;
+        let first_commit = repo.find_commit(first)?;
+        let second = commit_at(&repo, "b.txt", 1_600_086_400, &[&first_commit])?;
+        let original_tree = repo.find_commit(second)?.tree_id();
+
+        let config = HistoryShiftConfig {
+            shift: DateShift::Offset(Duration::days(-365)),
+            shift_committer: true,
+        };
+        let result = git_ops.shift_history(&repo, &config)?;
+
+        assert_eq!(result.commits.len(), 2);
+        assert_eq!(result.branches_updated.len(), 1);
+
+        let head = repo.head()?.peel_to_commit()?;
+        assert_ne!(head.id(), second);
+        assert_eq!(head.tree_id(), original_tree);
+        assert_eq!(head.author().when().seconds(), 1_600_086_400 - 365 * 86_400);
+        assert_eq!(head.committer().when().seconds(), 1_600_086_400 - 365 * 86_400);
+        assert_eq!(head.author().when().offset_minutes(), 60);
+        assert_eq!(head.parent(0)?.author().when().seconds(), 1_600_000_000 - 365 * 86_400);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shift_history_keeps_message_bytes_and_encoding() -> Result<()> {
+        let (_temp_dir, repo) = create_test_repo()?;
+        let git_ops = GitOperations::new();
+
+        let first = commit_at(&repo, "a.txt", 1_600_000_000, &[])?;
+        let first_commit = repo.find_commit(first)?;
+        let object = format!(
+            "tree {}\nparent {}\nauthor Test User <test@example.com> 1600086400 +0100\n\
+             committer Test User <test@example.com> 1600086400 +0100\nencoding ISO-8859-1\n\n",
+            first_commit.tree_id(), first,
+        );
+        let latin1 = [object.as_bytes(), b"Caf\xe9 cr\xe8me\n"].concat();
+        let second = repo.odb()?.write(git2::ObjectType::Commit, &latin1)?;
+        let head_name = repo.head()?.name().unwrap().to_string();
+        repo.reference(&head_name, second, true, "test")?;
+
+        let config = HistoryShiftConfig {
+            shift: DateShift::Offset(Duration::days(-365)),
+            shift_committer: true,
+        };
+        git_ops.shift_history(&repo, &config)?;
+
+        let head = repo.head()?.peel_to_commit()?;
+        assert_ne!(head.id(), second);
+        assert_eq!(head.message_raw_bytes(), b"Caf\xe9 cr\xe8me\n");
+        assert_eq!(head.message_encoding(), Some("ISO-8859-1"));
+        assert_eq!(head.author().when().seconds(), 1_600_086_400 - 365 * 86_400);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shift_history_into_window_keeps_spacing_and_merges() -> Result<()> {
+        let (_temp_dir, repo) = create_test_repo()?;
+        let git_ops = GitOperations::new();
+
+        let root = commit_at(&repo, "a.txt", 1_600_000_000, &[])?;
+        let root_commit = repo.find_commit(root)?;
+        let side = commit_at(&repo, "b.txt", 1_600_000_100, &[&root_commit])?;
+        let side_commit = repo.find_commit(side)?;
+        repo.set_head_detached(root)?;
+        let main = commit_at(&repo, "c.txt", 1_600_000_300, &[&root_commit])?;
+        let main_commit = repo.find_commit(main)?;
+        let merge = commit_at(&repo, "d.txt", 1_600_000_400, &[&main_commit, &side_commit])?;
+        repo.branch("topic", &repo.find_commit(merge)?, true)?;
+
+        let start = Utc.timestamp_opt(946_684_800, 0).unwrap();
+        let end = Utc.timestamp_opt(946_684_800 + 4_000, 0).unwrap();
+        let config = HistoryShiftConfig {
+            shift: DateShift::Window { start, end },
+            shift_committer: false,
+        };
+
+        let preview = git_ops.preview_history_shift(&repo, &config)?;
+        assert_eq!(preview.len(), 4);
+
+        let result = git_ops.shift_history(&repo, &config)?;
+        assert!(result.refs_updated.contains(&"refs/heads/topic".to_string()));
+        assert!(result.refs_updated.contains(&"HEAD".to_string()));
+
+        let new_merge = repo.find_branch("topic", git2::BranchType::Local)?.get().peel_to_commit()?;
+        assert_eq!(new_merge.parent_count(), 2);
+        assert_eq!(new_merge.author().when().seconds(), 946_684_800 + 4_000);
+        assert_eq!(new_merge.committer().when().seconds(), 1_600_000_400);
+        assert_eq!(new_merge.parent(1)?.author().when().seconds(), 946_684_800 + 1_000);
+        assert_eq!(new_merge.parent(0)?.author().when().seconds(), 946_684_800 + 3_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_staged_changes_refuses_unsafe_states() -> Result<()> {
+        let (_temp_dir, repo) = create_test_repo()?;
+        let git_ops = GitOperations::new();
+        let repo_path = repo.workdir().unwrap().to_path_buf();
+        let identity = GitIdentity {
+            name: "Test User".to_string(),
+            email: "test@example.com".to_string(),
+        };
+        let config = TimeTravelCommitConfig {
+            timestamp: Utc.with_ymd_and_hms(1999, 12, 31, 18, 0, 0).unwrap(),
+            author: identity.clone(),
+            committer: identity,
+            message: "Staged work".to_string(),
+            files_to_add: Vec::new(),
+        };
+
+        // Nothing staged yet
+        assert!(git_ops.commit_staged_changes(&repo, &config).is_err());
+
+        git_ops.create_file_with_content(&repo_path, Path::new("staged.txt"), "staged")?;
+        git_ops.create_file_with_content(&repo_path, Path::new("unstaged.txt"), "unstaged")?;
+        let mut index = repo.index()?;
+        index.add_path(Path::new("staged.txt"))?;
+        index.write()?;
+
+        let result = git_ops.commit_staged_changes(&repo, &config)?;
+        assert_eq!(result.files_added, vec![PathBuf::from("staged.txt")]);
+        let commit = repo.head()?.peel_to_commit()?;
+        assert_eq!(commit.author().when().seconds(), config.timestamp.timestamp());
+        assert!(commit.tree()?.get_name("unstaged.txt").is_none());
+
+        // Detached HEAD is refused
+        repo.set_head_detached(commit.id())?;
+        assert!(git_ops.ensure_safe_to_commit(&repo).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_redate_range_rebases_descendants() -> Result<()> {
+        let (_temp_dir, repo) = create_test_repo()?;
+        let git_ops = GitOperations::new();
+
+        let first = commit_at(&repo, "a.txt", 1_000_000_000, &[])?;
+        let second = commit_at(&repo, "b.txt", 1_000_100_000, &[&repo.find_commit(first)?])?;
+        let third = commit_at(&repo, "c.txt", 1_000_200_000, &[&repo.find_commit(second)?])?;
+        let fourth = commit_at(&repo, "d.txt", 1_000_300_000, &[&repo.find_commit(third)?])?;
+        repo.reference("refs/heads/main", fourth, true, "test")?;
+        repo.set_head("refs/heads/main")?;
+        let original_tree = repo.find_commit(fourth)?.tree_id();
+
+        let target = Utc.with_ymd_and_hms(1995, 5, 5, 12, 0, 0).unwrap();
+        let config = RedateConfig {
+            range: format!("{}..{}", first, third),
+            target: RedateTarget::Date(target),
+            redate_committer: false,
+        };
+
+        let preview = git_ops.preview_redate(&repo, &config)?;
+        assert_eq!(preview.len(), 2);
+        assert_eq!(preview[0].new_author_time, target);
+
+        let result = git_ops.redate_commits(&repo, &config)?;
+        assert_eq!(result.commits.len(), 2);
+        let main_backup = result.backup_refs.iter().find(|r| r.ends_with("/main")).unwrap();
+        assert_eq!(repo.refname_to_id(main_backup)?, fourth);
+
+        let head = repo.head()?.peel_to_commit()?;
+        assert_eq!(head.tree_id(), original_tree);
+        assert_eq!(head.author().when().seconds(), 1_000_300_000);
+        let redated_third = head.parent(0)?;
+        let redated_second = redated_third.parent(0)?;
+        assert_eq!(redated_second.author().when().seconds(), target.timestamp());
+        assert_eq!(redated_third.author().when().seconds(), target.timestamp() + 100_000);
+        assert_eq!(redated_second.committer().when().seconds(), 1_000_100_000);
+        assert_eq!(redated_second.parent_id(0)?, first);
+
+        Ok(())
+    }
+
     #[test]
     fn test_extract_repo_name_from_url() -> Result<()> {
         let git_ops = GitOperations::new();
@@ -479,4 +1334,28 @@ mod tests {
         
         Ok(())
     }
+
+    #[test]
+    fn test_cancelled_clone_removes_temp_dir() -> Result<()> {
+        let (source_dir, _repo) = create_test_repo()?;
+        let token = CancellationToken::new();
+        let mut git_ops = GitOperations::new().with_cancellation(token.clone());
+        token.cancel();
+
+        let config = RepositoryConfig {
+            url: format!("file://{}", source_dir.path().display()),
+            branch: "main".to_string(),
+            local_path: None,
+            credentials: None,
+        };
+        let error = git_ops.clone_repository(&config).unwrap_err();
+        assert!(cancel::is_cancellation(&error));
+
+        let temp_path = git_ops.temp_dir.as_ref().unwrap().path().to_path_buf();
+        assert!(temp_path.exists());
+        drop(git_ops);
+        assert!(!temp_path.exists());
+
+        Ok(())
+    }
 }
\ No newline at end of file
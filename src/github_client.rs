@@ -1,16 +1,83 @@
 use anyhow::{Context, Result};
-use reqwest::{Client, header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT}};
+use reqwest::{Client, RequestBuilder, Response, header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT}};
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use crate::errors::{TimeTravelError, AuthError, RepoError, NetworkError};
+use crate::secret::Secret;
+
+/// Rate-limit and tracing headers recorded for every API response
+const LOGGED_HEADERS: [&str; 6] = [
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+    "x-ratelimit-used",
+    "x-ratelimit-reset",
+    "x-ratelimit-resource",
+    "x-github-request-id",
+];
+
+/// Sends a request with its method, URL, status and rate-limit headers logged
+trait LoggedSend {
+    async fn send_logged(self) -> reqwest::Result<Response>;
+}
+
+impl LoggedSend for RequestBuilder {
+    async fn send_logged(self) -> reqwest::Result<Response> {
+        let (client, request) = self.build_split();
+        let request = request?;
+        let method = request.method().to_string();
+        let url = request.url().to_string();
+        tracing::debug!(target: "github", method = %method, url = %url, "Sending request");
+
+        let started = Instant::now();
+        let result = client.execute(request).await;
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        match &result {
+            Ok(response) => {
+                let headers: Vec<String> = LOGGED_HEADERS.iter()
+                    .filter_map(|name| {
+                        let value = response.headers().get(*name)?.to_str().ok()?;
+                        Some(format!("{}={}", name, value))
+                    })
+                    .collect();
+                tracing::debug!(target: "github", method = %method, url = %url, status = response.status().as_u16(),
+                    elapsed_ms, headers = %headers.join(" "), "Received response");
+            }
+            Err(e) => {
+                tracing::warn!(target: "github", method = %method, url = %url, elapsed_ms, error = %e, "Request failed");
+            }
+        }
+        result
+    }
+}
+
+/// Classify a failed request as a network error so callers can retry or branch on it
+fn transport_error(e: reqwest::Error) -> TimeTravelError {
+    if e.is_timeout() {
+        TimeTravelError::network("GitHub API", NetworkError::Timeout, true)
+    } else if e.is_connect() {
+        TimeTravelError::network("GitHub API", NetworkError::ConnectionFailed, true)
+    } else {
+        TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false)
+    }
+}
 
 /// GitHub API client for repository management
 pub struct GitHubClient {
     client: Client,
-    token: String,
+    token: Secret,
     username: String,
+    /// Account that owns the repositories; the authenticated user when unset
+    owner: Option<String>,
+    /// REST and GraphQL API root, without a trailing slash
+    api_base: String,
 }
 
+/// Default GitHub API root
+pub const DEFAULT_API_BASE: &str = "https://api.github.com";
+
+/// Environment variable that points the client at another API root, e.g. a local stand-in
+pub const API_BASE_ENV: &str = "GIT_TIMETRAVELER_API_URL";
+
 /// Configuration for creating a new repository
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateRepositoryRequest {
@@ -35,6 +102,12 @@ pub struct Repository {
     pub default_branch: String,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub fork: bool,
 }
 
 /// GitHub user information
@@ -73,22 +146,297 @@ pub struct TokenInfo {
     pub rate_limit_remaining: Option<u32>,
 }
 
+/// Repository settings that can be changed after creation.
+/// Fields left as `None` are not sent and keep their current value.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateRepositoryRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+/// Request body for replacing repository topics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryTopics {
+    pub names: Vec<String>,
+}
+
+/// Request body for opening a pull request
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatePullRequestRequest {
+    pub title: String,
+    pub head: String,
+    pub base: String,
+    pub body: String,
+}
+
+/// GitHub pull request information
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequest {
+    pub number: u64,
+    pub node_id: String,
+    pub html_url: String,
+    pub state: String,
+    pub title: String,
+}
+
+/// The authenticated user's membership in an organization
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganizationMembership {
+    /// "active" or "pending"
+    pub state: String,
+    /// "admin" or "member"
+    pub role: String,
+}
+
+/// GitHub organization information
+#[derive(Debug, Clone, Deserialize)]
+pub struct Organization {
+    pub login: String,
+    /// Only visible to members of the organization
+    pub members_can_create_repositories: Option<bool>,
+}
+
+/// One day of a user's contribution calendar
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionDay {
+    /// Calendar date as YYYY-MM-DD
+    pub date: String,
+    pub contribution_count: u32,
+}
+
+/// Email address linked to the authenticated account
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserEmail {
+    pub email: String,
+    pub verified: bool,
+}
+
 /// GitHub API error response
 #[derive(Debug, Clone, Deserialize)]
 pub struct GitHubError {
     pub message: String,
     pub documentation_url: Option<String>,
+    /// Validation failures of a 422 response
+    #[serde(default)]
+    pub errors: Vec<GitHubErrorDetail>,
+}
+
+impl GitHubError {
+    /// The message followed by the messages or codes of its validation failures
+    pub fn full_message(&self) -> String {
+        let details: Vec<String> = self.errors.iter()
+            .filter_map(|detail| detail.message.clone().or_else(|| {
+                let code = detail.code.as_deref()?;
+                Some(match detail.field.as_deref() {
+                    Some(field) => format!("{} {}", field, code.replace('_', " ")),
+                    None => code.replace('_', " "),
+                })
+            }))
+            .collect();
+        if details.is_empty() {
+            self.message.clone()
+        } else {
+            format!("{} ({})", self.message, details.join("; "))
+        }
+    }
+}
+
+/// One entry of the `errors` array of a 422 response.
+/// A few endpoints send plain strings, which end up in `message`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "RawErrorDetail")]
+pub struct GitHubErrorDetail {
+    pub resource: Option<String>,
+    pub field: Option<String>,
+    /// e.g. `already_exists`, `invalid`, `missing_field` or `custom`
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawErrorDetail {
+    Text(String),
+    Detail {
+        resource: Option<String>,
+        field: Option<String>,
+        code: Option<String>,
+        message: Option<String>,
+    },
+}
+
+impl From<RawErrorDetail> for GitHubErrorDetail {
+    fn from(raw: RawErrorDetail) -> Self {
+        match raw {
+            RawErrorDetail::Text(message) => Self { message: Some(message), ..Self::default() },
+            RawErrorDetail::Detail { resource, field, code, message } => Self { resource, field, code, message },
+        }
+    }
+}
+
+/// A failed API response, parsed once so every endpoint classifies it the same way
+#[derive(Debug)]
+struct ApiFailure {
+    status: u16,
+    error: Option<GitHubError>,
+    /// Authorization URL from `X-GitHub-SSO: required; url=...`, empty when GitHub sent none
+    sso_url: Option<String>,
+    /// Delay from `Retry-After`, or until `X-RateLimit-Reset` when the limit is used up
+    retry_after: Option<Duration>,
+    rate_limit_exhausted: bool,
+}
+
+impl ApiFailure {
+    async fn read(response: Response) -> Self {
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.text().await.unwrap_or_default();
+        Self::from_parts(status, &headers, &body)
+    }
+
+    fn from_parts(status: u16, headers: &HeaderMap, body: &str) -> Self {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let rate_limit_exhausted = header("x-ratelimit-remaining") == Some("0");
+        let retry_after = header("retry-after")
+            .and_then(|value| value.trim().parse().ok())
+            .or_else(|| {
+                let reset: i64 = header("x-ratelimit-reset")?.trim().parse().ok()?;
+                rate_limit_exhausted.then(|| (reset - chrono::Utc::now().timestamp()).max(0) as u64)
+            })
+            .map(Duration::from_secs);
+        let sso_url = header("x-github-sso")
+            .filter(|value| value.trim_start().starts_with("required"))
+            .map(|value| value.split_once("url=").map(|(_, url)| url.trim().to_string()).unwrap_or_default());
+
+        Self {
+            status,
+            error: serde_json::from_str(body).ok(),
+            sso_url,
+            retry_after,
+            rate_limit_exhausted,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.error.as_ref()
+            .map(GitHubError::full_message)
+            .unwrap_or_else(|| format!("HTTP {}", self.status))
+    }
+
+    /// Turn the failure into the most precise error.
+    ///
+    /// Conditions GitHub reports the same way on every endpoint (SSO, rate limits,
+    /// disabled or archived repositories, 422 validation codes) are recognised first.
+    /// `specific` then handles statuses whose meaning depends on the endpoint, and
+    /// anything left falls back to a per-status default. `subject` names the
+    /// repository or resource in messages.
+    fn into_error(self, subject: &str, specific: impl FnOnce(u16, &str) -> Option<TimeTravelError>) -> TimeTravelError {
+        let message = self.message();
+        let lower = message.to_lowercase();
+        let details = self.error.as_ref().map(|error| error.errors.as_slice()).unwrap_or_default();
+        let has_code = |code: &str, field: Option<&str>| details.iter().any(|detail| {
+            detail.code.as_deref() == Some(code) && field.is_none_or(|field| detail.field.as_deref() == Some(field))
+        });
+
+        let error = if let Some(ref url) = self.sso_url {
+            let error = TimeTravelError::authentication(
+                AuthError::SsoRequired,
+                &format!("The token must be authorized for SAML single sign-on: {}", message)
+            );
+            // The authorization link beats the generic documentation
+            let url = if url.is_empty() { self.documentation_url() } else { Some(url.clone()) };
+            return match url {
+                Some(url) => error.with_help_url(&url),
+                None => error,
+            };
+        } else if lower.contains("secondary rate limit") || lower.contains("abuse") {
+            let error = TimeTravelError::network("GitHub API", NetworkError::SecondaryRateLimited, true);
+            match self.retry_after {
+                Some(delay) => error.with_retry_after(delay),
+                None => error,
+            }
+        } else if self.status == 429 || (self.status == 403 && self.rate_limit_exhausted) {
+            let error = TimeTravelError::network("GitHub API", NetworkError::RateLimited, true);
+            match self.retry_after {
+                Some(delay) => error.with_retry_after(delay),
+                None => error,
+            }
+        } else if self.status == 451 || lower.contains("access blocked") || lower.contains("has been disabled") {
+            TimeTravelError::repository(
+                RepoError::Disabled,
+                subject,
+                &format!("Repository '{}' is disabled: {}", subject, message)
+            )
+        } else if self.status == 403 && lower.contains("archived") {
+            TimeTravelError::repository(
+                RepoError::Archived,
+                subject,
+                &format!("Repository '{}' is archived and read-only", subject)
+            )
+        } else if self.status == 422 && (has_code("already_exists", None) || lower.contains("already exists")) {
+            TimeTravelError::repository(RepoError::AlreadyExists, subject, &message)
+        } else if self.status == 422 && has_code("invalid", Some("name")) {
+            TimeTravelError::repository(
+                RepoError::InvalidName,
+                subject,
+                &format!("Invalid name '{}': {}", subject, message)
+            )
+        } else if let Some(error) = specific(self.status, &message) {
+            error
+        } else {
+            match self.status {
+                401 => TimeTravelError::authentication(
+                    AuthError::InvalidToken,
+                    &format!("Authentication failed - check your GitHub token ({})", message)
+                ),
+                403 => TimeTravelError::repository(
+                    RepoError::AccessDenied,
+                    subject,
+                    &format!("Access denied to '{}': {}", subject, message)
+                ),
+                404 => TimeTravelError::repository(
+                    RepoError::NotFound,
+                    subject,
+                    &format!("'{}' not found: {}", subject, message)
+                ),
+                422 => TimeTravelError::invalid_input(
+                    details.iter().find_map(|detail| detail.field.as_deref()).unwrap_or("request"),
+                    subject,
+                    &message,
+                    "Check the values sent to GitHub"
+                ),
+                500..=599 => TimeTravelError::network("GitHub API", NetworkError::ServiceUnavailable, true),
+                _ => TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false),
+            }
+        };
+
+        match self.documentation_url() {
+            Some(url) => error.with_help_url(&url),
+            None => error,
+        }
+    }
+
+    fn documentation_url(&self) -> Option<String> {
+        self.error.as_ref()?.documentation_url.clone()
+    }
 }
 
 impl GitHubClient {
     /// Create a new GitHub client
-    pub fn new(username: String, token: String) -> Result<Self> {
+    pub fn new(username: String, token: impl Into<Secret>) -> Result<Self> {
+        let token = token.into();
+        let mut authorization = HeaderValue::from_str(&format!("token {}", token.expose()))
+            .context("Failed to create authorization header")?;
+        authorization.set_sensitive(true);
         let mut headers = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("token {}", token))
-                .context("Failed to create authorization header")?,
-        );
+        headers.insert(AUTHORIZATION, authorization);
         headers.insert(
             USER_AGENT,
             HeaderValue::from_static("git-timetraveler/0.1.0"),
@@ -104,16 +452,43 @@ impl GitHubClient {
             client,
             token,
             username,
+            owner: None,
+            api_base: std::env::var(API_BASE_ENV)
+                .map(|url| url.trim_end_matches('/').to_string())
+                .unwrap_or_else(|_| DEFAULT_API_BASE.to_string()),
         })
     }
 
+    /// Send requests to another API root (GitHub Enterprise or a local stand-in)
+    pub fn with_api_base(mut self, api_base: &str) -> Self {
+        self.api_base = api_base.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Target repositories owned by an organization instead of the authenticated user
+    pub fn with_owner(mut self, owner: Option<String>) -> Self {
+        self.owner = owner.filter(|owner| !owner.eq_ignore_ascii_case(&self.username));
+        self
+    }
+
+    /// Account that owns the repositories this client works with
+    pub fn owner(&self) -> &str {
+        self.owner.as_deref().unwrap_or(&self.username)
+    }
+
+    /// Whether repositories are owned by an organization
+    pub fn is_organization_owner(&self) -> bool {
+        self.owner.is_some()
+    }
+
     /// Validate the GitHub token and get user information
     pub async fn validate_token(&self) -> Result<TokenInfo> {
         let response = self
             .client
-            .get("https://api.github.com/user")
-            .send()
+            .get(format!("{}/user", self.api_base))
+            .send_logged()
             .await
+            .map_err(transport_error)
             .context("Failed to send token validation request")?;
 
         // Get rate limit info from headers
@@ -143,24 +518,27 @@ impl GitHubClient {
                 user: Some(user),
                 rate_limit_remaining,
             })
-        } else {
+        } else if response.status().as_u16() == 401 {
             Ok(TokenInfo {
                 valid: false,
                 scopes,
                 user: None,
                 rate_limit_remaining,
             })
+        } else {
+            // Rate limits and outages say nothing about the token itself
+            Err(ApiFailure::read(response).await.into_error(&self.username, |_, _| None).into())
         }
     }
 
     /// Check if a repository exists
     pub async fn repository_exists(&self, repo_name: &str) -> Result<bool> {
-        let url = format!("https://api.github.com/repos/{}/{}", self.username, repo_name);
+        let url = format!("{}/repos/{}/{}", self.api_base, self.owner(), repo_name);
         
         let response = self
             .client
             .get(&url)
-            .send()
+            .send_logged()
             .await
             .map_err(|e| {
                 if e.is_timeout() {
@@ -176,30 +554,34 @@ impl GitHubClient {
         match response.status().as_u16() {
             200 => Ok(true),
             404 => Ok(false),
-            401 => Err(TimeTravelError::authentication(
-                AuthError::InvalidToken,
-                "Authentication failed while checking repository"
-            ).into()),
-            403 => Err(TimeTravelError::repository(
-                RepoError::AccessDenied,
-                repo_name,
-                "Access denied to repository"
-            ).into()),
-            429 => Err(TimeTravelError::network("GitHub API", NetworkError::RateLimited, true).into()),
-            500..=599 => Err(TimeTravelError::network("GitHub API", NetworkError::ServiceUnavailable, true).into()),
+            401 | 403 | 429 | 451 | 500..=599 => {
+                Err(ApiFailure::read(response).await.into_error(repo_name, |status, _| match status {
+                    401 => Some(TimeTravelError::authentication(
+                        AuthError::InvalidToken,
+                        "Authentication failed while checking repository"
+                    )),
+                    403 => Some(TimeTravelError::repository(
+                        RepoError::AccessDenied,
+                        repo_name,
+                        "Access denied to repository"
+                    )),
+                    _ => None,
+                }).into())
+            }
             _ => Ok(false), // Treat other errors as "not found"
         }
     }
 
     /// Get repository information
     pub async fn get_repository(&self, repo_name: &str) -> Result<Repository> {
-        let url = format!("https://api.github.com/repos/{}/{}", self.username, repo_name);
+        let url = format!("{}/repos/{}/{}", self.api_base, self.owner(), repo_name);
         
         let response = self
             .client
             .get(&url)
-            .send()
+            .send_logged()
             .await
+            .map_err(transport_error)
             .context("Failed to get repository information")?;
 
         if response.status().is_success() {
@@ -209,23 +591,29 @@ impl GitHubClient {
                 .context("Failed to parse repository response")?;
             Ok(repo)
         } else {
-            let error: GitHubError = response
-                .json()
-                .await
-                .context("Failed to parse error response")?;
-            anyhow::bail!("GitHub API error: {}", error.message);
+            Err(ApiFailure::read(response).await.into_error(repo_name, |status, _| match status {
+                404 => Some(TimeTravelError::repository(
+                    RepoError::NotFound,
+                    repo_name,
+                    &format!("Repository '{}/{}' not found", self.owner(), repo_name)
+                )),
+                _ => None,
+            }).into())
         }
     }
 
     /// Create a new repository
     pub async fn create_repository(&self, request: &CreateRepositoryRequest) -> Result<Repository> {
-        let url = "https://api.github.com/user/repos";
+        let url = match self.owner {
+            Some(ref org) => format!("{}/orgs/{}/repos", self.api_base, org),
+            None => format!("{}/user/repos", self.api_base),
+        };
         
         let response = self
             .client
-            .post(url)
+            .post(&url)
             .json(request)
-            .send()
+            .send_logged()
             .await
             .map_err(|e| {
                 if e.is_timeout() {
@@ -246,58 +634,36 @@ impl GitHubClient {
                 .context("Failed to parse repository creation response")?;
             Ok(repo)
         } else {
-            let status = response.status();
-            
-            match status.as_u16() {
-                422 => {
-                    Err(TimeTravelError::repository(
-                        RepoError::AlreadyExists,
-                        &request.name,
-                        &format!("Repository '{}' already exists or name is invalid", request.name)
-                    ).into())
-                }
-                401 => {
-                    Err(TimeTravelError::authentication(
-                        AuthError::InvalidToken,
-                        "Authentication failed - check your GitHub token"
-                    ).into())
-                }
-                403 => {
-                    Err(TimeTravelError::authentication(
-                        AuthError::InsufficientPermissions,
-                        "Insufficient permissions to create repository"
-                    ).into())
-                }
-                429 => {
-                    Err(TimeTravelError::network("GitHub API", NetworkError::RateLimited, true).into())
-                }
-                500..=599 => {
-                    Err(TimeTravelError::network("GitHub API", NetworkError::ServiceUnavailable, true).into())
-                }
-                _ => {
-                    let error_text = response
-                        .text()
-                        .await
-                        .unwrap_or_else(|_| "Unknown error".to_string());
-                    Err(TimeTravelError::repository(
-                        RepoError::InvalidName,
-                        &request.name,
-                        &format!("Failed to create repository: {} - {}", status, error_text)
-                    ).into())
-                }
-            }
+            Err(ApiFailure::read(response).await.into_error(&request.name, |status, message| match status {
+                422 => Some(TimeTravelError::repository(
+                    RepoError::InvalidName,
+                    &request.name,
+                    &format!("Repository '{}' could not be created: {}", request.name, message)
+                )),
+                403 => Some(TimeTravelError::authentication(
+                    AuthError::InsufficientPermissions,
+                    "Insufficient permissions to create repository"
+                )),
+                401 | 429 | 500..=599 => None,
+                _ => Some(TimeTravelError::repository(
+                    RepoError::InvalidName,
+                    &request.name,
+                    &format!("Failed to create repository: {} - {}", status, message)
+                )),
+            }).into())
         }
     }
 
     /// List branches for a repository
     pub async fn list_branches(&self, repo_name: &str) -> Result<Vec<Branch>> {
-        let url = format!("https://api.github.com/repos/{}/{}/branches", self.username, repo_name);
+        let url = format!("{}/repos/{}/{}/branches", self.api_base, self.owner(), repo_name);
         
         let response = self
             .client
             .get(&url)
-            .send()
+            .send_logged()
             .await
+            .map_err(transport_error)
             .context("Failed to list branches")?;
 
         if response.status().is_success() {
@@ -307,33 +673,394 @@ impl GitHubClient {
                 .context("Failed to parse branches response")?;
             Ok(branches)
         } else {
-            let error: GitHubError = response
+            Err(ApiFailure::read(response).await.into_error(repo_name, |_, _| None).into())
+        }
+    }
+
+    /// Update repository settings such as description, homepage, visibility or archived state
+    pub async fn update_repository(&self, repo_name: &str, request: &UpdateRepositoryRequest) -> Result<Repository> {
+        let url = format!("{}/repos/{}/{}", self.api_base, self.owner(), repo_name);
+
+        let response = self
+            .client
+            .patch(&url)
+            .json(request)
+            .send_logged()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    TimeTravelError::network("GitHub API", NetworkError::Timeout, true)
+                } else if e.is_connect() {
+                    TimeTravelError::network("GitHub API", NetworkError::ConnectionFailed, true)
+                } else {
+                    TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false)
+                }
+            })
+            .context("Failed to send repository update request")?;
+
+        if response.status().is_success() {
+            let repo: Repository = response
                 .json()
                 .await
-                .context("Failed to parse error response")?;
-            anyhow::bail!("GitHub API error: {}", error.message);
+                .map_err(|_| TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+                .context("Failed to parse repository update response")?;
+            return Ok(repo);
+        }
+
+        Err(ApiFailure::read(response).await.into_error(repo_name, |status, _| match status {
+            403 => Some(TimeTravelError::repository(
+                RepoError::AccessDenied,
+                repo_name,
+                "Admin access is required to change repository settings"
+            )),
+            404 => Some(TimeTravelError::repository(
+                RepoError::NotFound,
+                repo_name,
+                &format!("Repository '{}' not found", repo_name)
+            )),
+            _ => None,
+        }).into())
+    }
+
+    /// Replace all topics of a repository
+    pub async fn replace_topics(&self, repo_name: &str, topics: &[String]) -> Result<Vec<String>> {
+        let url = format!("{}/repos/{}/{}/topics", self.api_base, self.owner(), repo_name);
+        let request = RepositoryTopics { names: topics.to_vec() };
+
+        let response = self
+            .client
+            .put(&url)
+            .json(&request)
+            .send_logged()
+            .await
+            .map_err(transport_error)
+            .context("Failed to send topics update request")?;
+
+        match response.status().as_u16() {
+            200 => {
+                let updated: RepositoryTopics = response
+                    .json()
+                    .await
+                    .context("Failed to parse topics response")?;
+                Ok(updated.names)
+            }
+            _ => Err(ApiFailure::read(response).await.into_error(repo_name, |status, message| match status {
+                403 => Some(TimeTravelError::repository(
+                    RepoError::AccessDenied,
+                    repo_name,
+                    "Admin access is required to change repository topics"
+                )),
+                404 => Some(TimeTravelError::repository(
+                    RepoError::NotFound,
+                    repo_name,
+                    &format!("Repository '{}' not found", repo_name)
+                )),
+                422 => Some(TimeTravelError::invalid_input(
+                    "topics",
+                    &topics.join(","),
+                    &format!("rejected by GitHub: {}", message),
+                    "Topics must be lowercase letters, numbers and hyphens (max 50 characters, 20 topics)"
+                )),
+                _ => None,
+            }).into()),
+        }
+    }
+
+    /// Check if a branch exists in a repository
+    pub async fn branch_exists(&self, repo_name: &str, branch: &str) -> Result<bool> {
+        let url = format!("{}/repos/{}/{}/branches/{}", self.api_base, self.owner(), repo_name, branch);
+
+        let response = self
+            .client
+            .get(&url)
+            .send_logged()
+            .await
+            .map_err(transport_error)
+            .context("Failed to check branch existence")?;
+
+        match response.status().as_u16() {
+            200 => Ok(true),
+            404 => Ok(false),
+            401 | 403 | 429 | 451 | 500..=599 => {
+                Err(ApiFailure::read(response).await.into_error(repo_name, |status, _| match status {
+                    401 => Some(TimeTravelError::authentication(
+                        AuthError::InvalidToken,
+                        "Authentication failed while checking branch"
+                    )),
+                    _ => None,
+                }).into())
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Open a pull request
+    pub async fn create_pull_request(&self, repo_name: &str, request: &CreatePullRequestRequest) -> Result<PullRequest> {
+        let url = format!("{}/repos/{}/{}/pulls", self.api_base, self.owner(), repo_name);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(request)
+            .send_logged()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    TimeTravelError::network("GitHub API", NetworkError::Timeout, true)
+                } else if e.is_connect() {
+                    TimeTravelError::network("GitHub API", NetworkError::ConnectionFailed, true)
+                } else {
+                    TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false)
+                }
+            })
+            .context("Failed to send pull request creation request")?;
+
+        if response.status().is_success() {
+            let pull_request: PullRequest = response
+                .json()
+                .await
+                .map_err(|_| TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+                .context("Failed to parse pull request response")?;
+            return Ok(pull_request);
+        }
+
+        Err(ApiFailure::read(response).await.into_error(repo_name, |status, message| match status {
+            403 => Some(TimeTravelError::authentication(
+                AuthError::InsufficientPermissions,
+                "Insufficient permissions to open a pull request"
+            )),
+            404 => Some(TimeTravelError::repository(
+                RepoError::NotFound,
+                repo_name,
+                &format!("Repository '{}' or branch '{}' not found", repo_name, request.base)
+            )),
+            422 => Some(TimeTravelError::repository(
+                RepoError::AlreadyExists,
+                repo_name,
+                &format!("Pull request from '{}' could not be opened (it may already exist or have no changes): {}",
+                    request.head, message)
+            )),
+            _ => None,
+        }).into())
+    }
+
+    /// Enable auto-merge on a pull request.
+    ///
+    /// GitHub only exposes auto-merge through GraphQL, so this uses the
+    /// pull request's node id. The repository must allow auto-merge.
+    pub async fn enable_auto_merge(&self, pull_request: &PullRequest, merge_method: &str) -> Result<()> {
+        let query = serde_json::json!({
+            "query": "mutation($id: ID!, $method: PullRequestMergeMethod!) { \
+                enablePullRequestAutoMerge(input: {pullRequestId: $id, mergeMethod: $method}) { \
+                    pullRequest { number } } }",
+            "variables": {
+                "id": pull_request.node_id,
+                "method": merge_method.to_uppercase(),
+            },
+        });
+
+        let response = self
+            .client
+            .post(format!("{}/graphql", self.api_base))
+            .json(&query)
+            .send_logged()
+            .await
+            .map_err(transport_error)
+            .context("Failed to send auto-merge request")?;
+
+        if !response.status().is_success() {
+            return Err(ApiFailure::read(response).await.into_error(&pull_request.html_url, |_, _| None).into());
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|_| TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+            .context("Failed to parse auto-merge response")?;
+
+        if let Some(error) = graphql_error(&body, &pull_request.html_url) {
+            return Err(error.into());
+        }
+        let errors = body.get("errors").and_then(|e| e.as_array());
+        if errors.is_some_and(|e| !e.is_empty()) {
+            let message = errors
+                .and_then(|e| e.first())
+                .and_then(|e| e.get("message"))
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error");
+            return Err(TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+                .context(format!("Failed to enable auto-merge on pull request #{}: {}", pull_request.number, message));
+        }
+
+        Ok(())
+    }
+
+    /// List all repositories of the owner, following the Link header across pages
+    pub async fn list_repositories(&self) -> Result<Vec<Repository>> {
+        let mut next = Some(match self.owner {
+            Some(ref org) => format!("{}/orgs/{}/repos?per_page=100", self.api_base, org),
+            None => format!("{}/user/repos?affiliation=owner&per_page=100", self.api_base),
+        });
+
+        let mut repositories = Vec::new();
+        while let Some(url) = next.take() {
+            let response = self
+                .client
+                .get(&url)
+                .send_logged()
+                .await
+                .map_err(transport_error)
+                .context("Failed to list repositories")?;
+
+            if !response.status().is_success() {
+                return Err(ApiFailure::read(response).await.into_error(self.owner(), |status, _| match status {
+                    403 => Some(TimeTravelError::authentication(
+                        AuthError::InsufficientPermissions,
+                        "Insufficient permissions to list repositories"
+                    )),
+                    404 => Some(TimeTravelError::repository(
+                        RepoError::NotFound,
+                        self.owner(),
+                        &format!("Owner '{}' not found", self.owner())
+                    )),
+                    _ => None,
+                }).into());
+            }
+
+            next = response.headers()
+                .get("link")
+                .and_then(|value| value.to_str().ok())
+                .and_then(next_page_url);
+
+            let page: Vec<Repository> = response
+                .json()
+                .await
+                .map_err(|_| TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+                .context("Failed to parse repository list")?;
+            repositories.extend(page);
+        }
+
+        Ok(repositories)
+    }
+
+    /// Fetch a user's contribution calendar between two dates.
+    ///
+    /// GitHub limits `contributionsCollection` to ranges of at most one year.
+    pub async fn contribution_calendar(&self, login: &str, from: &str, to: &str) -> Result<Vec<ContributionDay>> {
+        let query = serde_json::json!({
+            "query": "query($login: String!, $from: DateTime!, $to: DateTime!) { \
+                user(login: $login) { contributionsCollection(from: $from, to: $to) { \
+                    contributionCalendar { weeks { contributionDays { date contributionCount } } } } } }",
+            "variables": {
+                "login": login,
+                "from": from,
+                "to": to,
+            },
+        });
+
+        let response = self
+            .client
+            .post(format!("{}/graphql", self.api_base))
+            .json(&query)
+            .send_logged()
+            .await
+            .map_err(transport_error)
+            .context("Failed to query contribution calendar")?;
+
+        if !response.status().is_success() {
+            return Err(ApiFailure::read(response).await.into_error(login, |_, _| None).into());
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|_| TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+            .context("Failed to parse contribution calendar response")?;
+
+        if let Some(error) = graphql_error(&body, login) {
+            return Err(error.into());
+        }
+        let errors = body.get("errors").and_then(|e| e.as_array());
+        if errors.is_some_and(|e| !e.is_empty()) {
+            let message = errors
+                .and_then(|e| e.first())
+                .and_then(|e| e.get("message"))
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error");
+            return Err(TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+                .context(format!("Failed to query contribution calendar for '{}': {}", login, message));
+        }
+
+        let weeks = body.pointer("/data/user/contributionsCollection/contributionCalendar/weeks")
+            .cloned()
+            .ok_or_else(|| TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+            .context("Contribution calendar missing from response")?;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Week {
+            contribution_days: Vec<ContributionDay>,
+        }
+        let weeks: Vec<Week> = serde_json::from_value(weeks)
+            .context("Failed to parse contribution calendar")?;
+
+        Ok(weeks.into_iter().flat_map(|week| week.contribution_days).collect())
+    }
+
+    /// List the verified email addresses of the authenticated user (needs the `user:email` scope)
+    pub async fn verified_emails(&self) -> Result<Vec<String>> {
+        let url = format!("{}/user/emails", self.api_base);
+
+        let response = self
+            .client
+            .get(&url)
+            .send_logged()
+            .await
+            .map_err(transport_error)
+            .context("Failed to list user emails")?;
+
+        if response.status().is_success() {
+            let emails: Vec<UserEmail> = response
+                .json()
+                .await
+                .context("Failed to parse user emails")?;
+            Ok(emails.into_iter().filter(|e| e.verified).map(|e| e.email).collect())
+        } else {
+            Err(ApiFailure::read(response).await.into_error(&self.username, |status, _| match status {
+                403 | 404 => Some(TimeTravelError::authentication(
+                    AuthError::InsufficientPermissions,
+                    "Cannot list email addresses - the token needs the 'user:email' scope"
+                )),
+                _ => None,
+            }).into())
         }
     }
 
     /// Delete a repository (use with caution)
     pub async fn delete_repository(&self, repo_name: &str) -> Result<()> {
-        let url = format!("https://api.github.com/repos/{}/{}", self.username, repo_name);
+        let url = format!("{}/repos/{}/{}", self.api_base, self.owner(), repo_name);
         
         let response = self
             .client
             .delete(&url)
-            .send()
+            .send_logged()
             .await
+            .map_err(transport_error)
             .context("Failed to delete repository")?;
 
         if response.status().is_success() {
             Ok(())
         } else {
-            let error: GitHubError = response
-                .json()
-                .await
-                .context("Failed to parse error response")?;
-            anyhow::bail!("GitHub API error: {}", error.message);
+            Err(ApiFailure::read(response).await.into_error(repo_name, |status, _| match status {
+                403 => Some(TimeTravelError::authentication(
+                    AuthError::InsufficientPermissions,
+                    &format!("Cannot delete '{}/{}' - the token needs the 'delete_repo' scope", self.owner(), repo_name)
+                )),
+                404 => Some(TimeTravelError::repository(
+                    RepoError::NotFound,
+                    repo_name,
+                    &format!("Repository '{}/{}' not found", self.owner(), repo_name)
+                )),
+                _ => None,
+            }).into())
         }
     }
 
@@ -365,6 +1092,101 @@ impl GitHubClient {
         Ok(token_info.scopes)
     }
 
+    /// Check that the authenticated user is an active member of the owning organization
+    /// and, when `create_repository` is set, is allowed to create repositories there
+    pub async fn check_organization_access(&self, create_repository: bool) -> Result<()> {
+        let org = match self.owner {
+            Some(ref org) => org,
+            None => return Ok(()),
+        };
+
+        let url = format!("{}/user/memberships/orgs/{}", self.api_base, org);
+        let response = self
+            .client
+            .get(&url)
+            .send_logged()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    TimeTravelError::network("GitHub API", NetworkError::Timeout, true)
+                } else if e.is_connect() {
+                    TimeTravelError::network("GitHub API", NetworkError::ConnectionFailed, true)
+                } else {
+                    TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false)
+                }
+            })
+            .context("Failed to check organization membership")?;
+
+        let membership: OrganizationMembership = if response.status().is_success() {
+            response
+                .json()
+                .await
+                .map_err(|_| TimeTravelError::network("GitHub API", NetworkError::InvalidResponse, false))
+                .context("Failed to parse organization membership")?
+        } else {
+            return Err(ApiFailure::read(response).await.into_error(org, |status, _| match status {
+                403 => Some(TimeTravelError::authentication(
+                    AuthError::InsufficientPermissions,
+                    &format!("Cannot read membership of organization '{}' - the token needs the 'read:org' scope", org)
+                )),
+                404 => Some(TimeTravelError::authentication(
+                    AuthError::InsufficientPermissions,
+                    &format!("User '{}' is not a member of organization '{}'", self.username, org)
+                )),
+                _ => None,
+            }).into());
+        };
+
+        if membership.state != "active" {
+            return Err(TimeTravelError::authentication(
+                AuthError::InsufficientPermissions,
+                &format!("Membership of organization '{}' is {} - accept the invitation first", org, membership.state)
+            ).into());
+        }
+
+        if create_repository && membership.role != "admin" {
+            let organization = self.get_organization(org).await?;
+            if organization.members_can_create_repositories == Some(false) {
+                return Err(TimeTravelError::authentication(
+                    AuthError::InsufficientPermissions,
+                    &format!("Members of organization '{}' cannot create repositories - ask an owner to create it", organization.login)
+                ).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get organization information
+    pub async fn get_organization(&self, org: &str) -> Result<Organization> {
+        let url = format!("{}/orgs/{}", self.api_base, org);
+
+        let response = self
+            .client
+            .get(&url)
+            .send_logged()
+            .await
+            .map_err(transport_error)
+            .context("Failed to get organization information")?;
+
+        if response.status().is_success() {
+            let organization: Organization = response
+                .json()
+                .await
+                .context("Failed to parse organization information")?;
+            Ok(organization)
+        } else {
+            Err(ApiFailure::read(response).await.into_error(org, |status, _| match status {
+                404 => Some(TimeTravelError::repository(
+                    RepoError::NotFound,
+                    org,
+                    &format!("Organization '{}' not found", org)
+                )),
+                _ => None,
+            }).into())
+        }
+    }
+
     /// Get the authenticated user's information
     pub async fn get_user(&self) -> Result<User> {
         let token_info = self.validate_token().await?;
@@ -372,7 +1194,10 @@ impl GitHubClient {
         if let Some(user) = token_info.user {
             Ok(user)
         } else {
-            anyhow::bail!("Failed to get user information - invalid token");
+            Err(TimeTravelError::authentication(
+                AuthError::InvalidToken,
+                "Failed to get user information - invalid token"
+            ).into())
         }
     }
 
@@ -395,6 +1220,29 @@ impl GitHubClient {
     }
 }
 
+/// Classify the first error of a GraphQL response by its `type`.
+/// Errors without a type this client understands are left to the caller.
+fn graphql_error(body: &serde_json::Value, subject: &str) -> Option<TimeTravelError> {
+    let error = body.get("errors")?.as_array()?.first()?;
+    let message = error.get("message").and_then(|m| m.as_str()).unwrap_or("unknown error");
+    match error.get("type")?.as_str()? {
+        "RATE_LIMITED" => Some(TimeTravelError::network("GitHub API", NetworkError::RateLimited, true)),
+        "FORBIDDEN" => Some(TimeTravelError::authentication(AuthError::InsufficientPermissions, message)),
+        "NOT_FOUND" => Some(TimeTravelError::repository(RepoError::NotFound, subject, message)),
+        _ => None,
+    }
+}
+
+/// Extract the `rel="next"` URL from a GitHub Link header
+pub fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params.split(';')
+            .any(|param| param.trim() == "rel=\"next\"")
+            .then(|| url.trim().trim_start_matches('<').trim_end_matches('>').to_string())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -447,4 +1295,68 @@ mod tests {
         assert!(request.auto_init);
         assert_eq!(request.default_branch, Some("main".to_string()));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_next_page_url() {
+        let link = "<https://api.github.com/user/repos?page=2>; rel=\"next\", <https://api.github.com/user/repos?page=5>; rel=\"last\"";
+        assert_eq!(next_page_url(link).as_deref(), Some("https://api.github.com/user/repos?page=2"));
+
+        let last_page = "<https://api.github.com/user/repos?page=4>; rel=\"prev\", <https://api.github.com/user/repos?page=1>; rel=\"first\"";
+        assert_eq!(next_page_url(last_page), None);
+    }
+
+    #[test]
+    fn test_owner_defaults_to_username() -> Result<()> {
+        let client = GitHubClient::new("octocat".to_string(), "ghp_test".to_string())?;
+        assert_eq!(client.owner(), "octocat");
+        assert!(!client.is_organization_owner());
+
+        let client = client.with_owner(Some("OctoCat".to_string()));
+        assert!(!client.is_organization_owner());
+
+        let client = client.with_owner(Some("acme".to_string()));
+        assert_eq!(client.owner(), "acme");
+        assert!(client.is_organization_owner());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_api_failures_are_classified() {
+        let headers = |pairs: &[(&'static str, &str)]| {
+            let mut map = HeaderMap::new();
+            for (name, value) in pairs {
+                map.insert(*name, HeaderValue::from_str(value).unwrap());
+            }
+            map
+        };
+        let classify = |status, headers: &HeaderMap, body: &str| {
+            ApiFailure::from_parts(status, headers, body).into_error("1990", |_, _| None)
+        };
+
+        let body = r#"{"message":"Repository creation failed.","errors":[{"resource":"Repository","code":"custom","field":"name","message":"name already exists on this account"}],"documentation_url":"https://docs.github.com/rest/repos/repos#create"}"#;
+        let error = classify(422, &HeaderMap::new(), body);
+        assert_eq!(error.exit_code(), crate::errors::exit_codes::REPO_ALREADY_EXISTS);
+        assert!(error.user_message().contains("name already exists on this account"));
+        assert_eq!(error.help_url(), Some("https://docs.github.com/rest/repos/repos#create"));
+
+        let sso = headers(&[("x-github-sso", "required; url=https://github.com/orgs/acme/sso?authorization_request=abc")]);
+        let error = classify(403, &sso, r#"{"message":"Resource protected by organization SAML enforcement."}"#);
+        assert_eq!(error.kind().as_deref(), Some("sso_required"));
+        assert_eq!(error.help_url(), Some("https://github.com/orgs/acme/sso?authorization_request=abc"));
+
+        let error = classify(403, &headers(&[("retry-after", "120")]),
+            r#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes before you try again."}"#);
+        assert_eq!(error.kind().as_deref(), Some("secondary_rate_limited"));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(120)));
+
+        let error = classify(403, &headers(&[("x-ratelimit-remaining", "0")]), r#"{"message":"API rate limit exceeded"}"#);
+        assert_eq!(error.kind().as_deref(), Some("rate_limited"));
+
+        let error = classify(451, &HeaderMap::new(), r#"{"message":"Repository access blocked"}"#);
+        assert_eq!(error.kind().as_deref(), Some("disabled"));
+
+        let error = classify(502, &HeaderMap::new(), "<html>Bad gateway</html>");
+        assert_eq!(error.kind().as_deref(), Some("service_unavailable"));
+    }
+}
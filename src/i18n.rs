@@ -0,0 +1,915 @@
+use anyhow::Result;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::errors::TimeTravelError;
+
+/// Language of user-facing text: prompts, banners, dry-run output, warnings and recovery steps.
+///
+/// Log records, error categories and CLI flags stay in English so scripts can rely on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    Es,
+}
+
+/// Locale used by [`translate`], set once at startup from `--lang` or the environment
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Es];
+
+    /// Language code accepted by `--lang`
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Es => "es",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        Self::from_tag(name).ok_or_else(|| TimeTravelError::invalid_input(
+            "language",
+            name,
+            "Unsupported language",
+            &format!("Use one of: {}", Self::ALL.map(Self::code).join(", ")),
+        ).into())
+    }
+
+    /// Parse a POSIX locale or language tag such as `es`, `es-MX` or `es_ES.UTF-8`
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Self::ALL.into_iter().find(|locale| locale.code() == language)
+    }
+
+    /// `--lang` if given, otherwise the first of `LC_ALL`, `LC_MESSAGES` and `LANG` that is set.
+    ///
+    /// An unsupported environment locale falls back to English; an unsupported `--lang` is an error.
+    pub fn detect(requested: Option<&str>) -> Result<Self> {
+        if let Some(name) = requested {
+            return Self::from_name(name);
+        }
+        let environment = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|variable| std::env::var(variable).ok())
+            .find(|value| !value.is_empty());
+        Ok(environment.as_deref().and_then(Self::from_tag).unwrap_or(Locale::En))
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::En => EN,
+            Self::Es => ES,
+        }
+    }
+
+    /// The message for `key` in this locale, falling back to English
+    pub fn message(self, key: &str) -> Option<&'static str> {
+        lookup(self.catalog(), key).or_else(|| lookup(EN, key))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Make `locale` the language of all further messages
+pub fn set_locale(locale: Locale) {
+    CURRENT.store(locale as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Locale::Es,
+        _ => Locale::En,
+    }
+}
+
+fn lookup(catalog: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    catalog.iter().find(|(candidate, _)| *candidate == key).map(|(_, message)| *message)
+}
+
+/// Render `key` in `locale`, replacing `{name}` placeholders with `args`.
+///
+/// An unknown key renders as the key itself, so a missing message is visible rather than fatal.
+pub fn translate_in(locale: Locale, key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut message = locale.message(key).unwrap_or(key).to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    message
+}
+
+/// Render `key` in the current locale, see [`translate_in`]
+pub fn translate(key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    translate_in(locale(), key, args)
+}
+
+/// Look up a message in the current locale: `t!("key")` or `t!("key", name = value, ...)`
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate($key, &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+])
+    };
+}
+
+const EN: &[(&str, &str)] = &[
+    // Shared
+    ("common.cancelled_by_user", "Operation cancelled by user"),
+    ("common.press_enter", "Press Enter to continue..."),
+    ("common.yes", "Yes"),
+    ("common.no", "No"),
+
+    // Welcome screen and menu
+    ("banner.created_by", "created by CHX"),
+    ("banner.tagline", "Create backdated commits to enhance your GitHub contribution graph"),
+    ("banner.features_title", "What this tool does:"),
+    ("banner.feature_timestamps", "Create commits with custom timestamps"),
+    ("banner.feature_push", "Push to GitHub repositories safely"),
+    ("banner.feature_ranges", "Support single years or year ranges"),
+    ("banner.feature_preferences", "Remember your preferences for future use"),
+    ("banner.requires_tty", "This CLI requires an interactive terminal (TTY) for interactive mode.\nIf you are using npx or a non-interactive shell, try running with the --no-menu flag and provide all required arguments."),
+    ("banner.interrupted", "Interrupted - stopping after the current step (press Ctrl+C again to quit now)"),
+    ("banner.goodbye", "Exiting. Goodbye!"),
+    ("menu.create", "Create backdated commit(s)"),
+    ("menu.examples", "View usage examples"),
+    ("menu.tokens", "Learn about GitHub tokens"),
+    ("menu.configuration", "Configuration options"),
+    ("menu.exit", "Exit"),
+    ("menu.prompt", "What would you like to do?"),
+    ("menu.thanks", "Thanks for using Git Time Traveler!"),
+
+    // Run banners
+    ("banner.summary_title", "Operation Summary"),
+    ("banner.repository", "Repository:"),
+    ("banner.github_user", "GitHub User:"),
+    ("banner.years", "Years:"),
+    ("banner.year_range", "{count} years ({start}-{end})"),
+    ("banner.additional_confirmation", "Additional Confirmation Required"),
+    ("banner.force_push_warning", "Force push will overwrite remote history"),
+    ("banner.many_years_warning", "Processing {count} years may take significant time"),
+    ("banner.final_confirm", "Are you absolutely sure you want to proceed?"),
+    ("banner.starting", "Starting time travel operation..."),
+    ("banner.processing_year", "Processing year:"),
+    ("banner.year_complete", "Year {year} complete"),
+    ("banner.year_created", "Commit for year {year} created successfully!"),
+    ("banner.complete", "Time Travel Complete!"),
+    ("banner.commits_created", "{commits} commits created across {years} years"),
+    ("banner.view_at", "View at:"),
+    ("banner.check_profile", "Check your GitHub profile to see the backdated commits!"),
+
+    // Usage examples screen
+    ("examples.title", "Usage Examples"),
+    ("examples.intro", "Here are common ways to use Git Time Traveler:"),
+    ("examples.interactive", "Interactive Mode (Recommended):"),
+    ("examples.interactive_note", "Guided prompts with smart defaults"),
+    ("examples.non_interactive", "Non-Interactive Mode:"),
+    ("examples.non_interactive_note", "Perfect for scripts and automation"),
+    ("examples.options", "Common Options:"),
+    ("examples.option_year", "Create commits for a single year"),
+    ("examples.option_years", "Create commits across a range"),
+    ("examples.option_repo", "Specify custom repository name"),
+    ("examples.option_hour", "Set commit time (0-23)"),
+    ("examples.option_force", "Force push (use with caution)"),
+    ("examples.option_lang", "Show prompts and messages in another language"),
+    ("examples.option_profile", "Use a saved profile (see 'git-timetraveler profile add')"),
+    ("examples.tips", "Pro Tips:"),
+    ("examples.tip_preferences", "The tool remembers your preferences between sessions"),
+    ("examples.tip_ranges", "Use ranges like 1990-1995 for multiple years at once"),
+    ("examples.tip_hour", "Commits are created at 6 PM by default for realistic timing"),
+    ("examples.tip_token", "Your GitHub token needs 'repo' permissions"),
+
+    // GitHub token screen
+    ("tokens.title", "GitHub Personal Access Token Setup"),
+    ("tokens.intro", "You need a GitHub token to create and push to repositories."),
+    ("tokens.steps", "Step-by-step instructions:"),
+    ("tokens.step_open", "Go to: {url}"),
+    ("tokens.step_generate", "Click: {button}"),
+    ("tokens.step_expiration", "Set expiration: {hint}"),
+    ("tokens.step_expiration_hint", "Choose your preferred duration"),
+    ("tokens.step_scopes", "Select scopes: {hint}"),
+    ("tokens.step_scopes_hint", "Check 'repo' (Full control of private repositories)"),
+    ("tokens.step_confirm", "Click: {button}"),
+    ("tokens.step_copy", "Copy the token: {hint}"),
+    ("tokens.step_copy_hint", "Save it securely - you won't see it again!"),
+    ("tokens.permissions", "Required Permissions:"),
+    ("tokens.permission_repo", "Create, read, and write repositories"),
+    ("tokens.permission_public_repo", "Access public repositories (included in repo)"),
+    ("tokens.security", "Security Notes:"),
+    ("tokens.security_share", "Never share your token with others"),
+    ("tokens.security_store", "Store it securely (password manager recommended)"),
+    ("tokens.security_revoke", "You can revoke it anytime from GitHub settings"),
+    ("tokens.security_local", "This tool stores it locally and never transmits it elsewhere"),
+
+    // Configuration screen
+    ("configuration.title", "Configuration Options"),
+    ("configuration.intro", "Git Time Traveler learns from your usage patterns."),
+    ("configuration.preferences", "Stored Preferences:"),
+    ("configuration.preference_repositories", "Recent repository names"),
+    ("configuration.preference_author", "Preferred author modes"),
+    ("configuration.preference_years", "Common year patterns"),
+    ("configuration.preference_username", "GitHub username"),
+    ("configuration.preference_timing", "Default commit timing"),
+    ("configuration.location", "Configuration Location:"),
+    ("configuration.reset", "Reset Options:"),
+    ("configuration.reset_delete", "Delete the configuration directory to start fresh"),
+    ("configuration.reset_expiry", "Individual preferences reset automatically after 30 days of inactivity"),
+    ("configuration.reset_tokens", "GitHub tokens are stored securely using your system keychain"),
+    ("configuration.override", "Command Line Override:"),
+    ("configuration.override_no_menu", "Use {flag} to bypass all interactive prompts"),
+    ("configuration.override_arguments", "All preferences can be overridden with command line arguments"),
+
+    // Interactive prompts
+    ("interactive.tagline", "Travel back in time on your GitHub profile with ease!"),
+    ("interactive.profile_prompt", "Which profile do you want to use?"),
+    ("interactive.profile_none", "No profile (shared preferences)"),
+    ("interactive.smart_suggestions", "Smart suggestions based on your context:"),
+    ("interactive.suggested", "{prompt} (suggested: {suggestion})"),
+    ("interactive.repository", "Repository name"),
+    ("interactive.branch", "Branch name"),
+    ("interactive.author_prompt", "How should commits be authored?"),
+    ("interactive.author_git_identity", "Use your Git identity: {name} <{email}>"),
+    ("interactive.author_git_identity_if_available", "Use your Git identity (if available)"),
+    ("interactive.author_custom_identity", "Use custom identity: {name} <{email}>"),
+    ("interactive.author_time_traveler", "Use generic time traveler identity"),
+    ("interactive.author_specify_custom", "Specify custom author details"),
+    ("interactive.author_ask_each_time", "Ask me each time"),
+    ("interactive.custom_author_title", "Custom Author Details"),
+    ("interactive.custom_author_intro", "Specify the name and email for commit authorship:"),
+    ("interactive.author_name", "Author name"),
+    ("interactive.author_email", "Author email"),
+    ("interactive.year_authors_title", "Authors per year:"),
+    ("interactive.author_for_year", "Author for {year}"),
+    ("interactive.author_from_pool", "{year}: {name} <{email}> (from the author pool)"),
+    ("interactive.year_options", "Year selection options:"),
+    ("interactive.year_single", "Single year: {example}"),
+    ("interactive.year_range", "Year range: {example}"),
+    ("interactive.year_multiple", "Multiple years: {example}"),
+    ("interactive.years", "Years for time travel"),
+    ("interactive.hour", "Hour for commits (0-23, suggested: {suggestion})"),
+    ("interactive.hour_title", "Hour for commits"),
+    ("interactive.suggestion_confidence", "{dots} {percent}% confident"),
+    ("interactive.suggestion_repository", "Start the name with '{prefix}', like your other repositories"),
+    ("interactive.suggestion_other_repository", "Choose the name myself"),
+    ("interactive.suggestion_branch", "Use branch '{branch}'"),
+    ("interactive.suggestion_other_branch", "Enter another branch"),
+    ("interactive.suggestion_year", "Travel to {year}"),
+    ("interactive.suggestion_other_years", "Enter other years"),
+    ("interactive.suggestion_hour", "Commit at {hour}"),
+    ("interactive.suggestion_other_hour", "Enter another hour"),
+    ("interactive.github_username", "GitHub username"),
+    ("interactive.token_title", "GitHub Personal Access Token:"),
+    ("interactive.token_create_at", "Create at: {url}"),
+    ("interactive.token_permissions", "Required permissions: {scope}"),
+    ("interactive.token_scope", "repo (full control)"),
+    ("interactive.token_prompt", "GitHub token (input hidden)"),
+    ("interactive.token_from_profile", "🔑 Using the token from ${variable} (profile '{profile}')"),
+    ("interactive.token_variable_unset", "⚠️  ${variable} from profile '{profile}' is not set"),
+    ("interactive.force_push_title", "Force Push Warning:"),
+    ("interactive.force_push_warning", "Force push will overwrite remote history. Use with caution!"),
+    ("interactive.force_push_prompt", "Enable force push?"),
+    ("interactive.summary_title", "Summary:"),
+    ("interactive.summary_profile", "Profile: {value}"),
+    ("interactive.summary_repository", "Repository: {value}"),
+    ("interactive.summary_branch", "Branch: {value}"),
+    ("interactive.summary_author", "Author: {value}"),
+    ("interactive.summary_author_yours", "Your identity: {name} <{email}>"),
+    ("interactive.summary_author_time_traveler", "Time Traveler"),
+    ("interactive.summary_author_custom", "Custom identity: {name} <{email}>"),
+    ("interactive.summary_author_ask", "Ask each time"),
+    ("interactive.summary_year_author", "{year}: {value}"),
+    ("interactive.summary_years", "Years: {value}"),
+    ("interactive.summary_year_range", "{count} years ({start}-{end})"),
+    ("interactive.summary_time", "Time: {value}"),
+    ("interactive.summary_username", "Username: {value}"),
+    ("interactive.summary_force_push", "Force push: {value}"),
+    ("interactive.proceed_prompt", "Proceed with time travel?"),
+
+    // Input validation
+    ("validation.repository_empty", "Repository name cannot be empty"),
+    ("validation.repository_too_long", "Repository name too long (max 100 characters)"),
+    ("validation.repository_characters", "Repository name can only contain letters, numbers, hyphens, underscores, and dots"),
+    ("validation.branch_empty", "Branch name cannot be empty"),
+    ("validation.branch_hyphen", "Branch name cannot start or end with a hyphen"),
+    ("validation.branch_characters", "Branch name cannot contain spaces or consecutive dots"),
+    ("validation.years_empty", "At least one year must be specified"),
+    ("validation.years_too_many", "Too many years specified (max 50)"),
+    ("validation.hour_range", "Hour must be between 0 and 23"),
+    ("validation.not_a_number", "Please enter a valid number"),
+    ("validation.username_empty", "GitHub username cannot be empty"),
+    ("validation.username_too_long", "GitHub username too long (max 39 characters)"),
+    ("validation.username_characters", "GitHub username can only contain letters, numbers, and hyphens"),
+    ("validation.username_hyphen", "GitHub username cannot start or end with a hyphen"),
+    ("validation.token_empty", "GitHub token cannot be empty"),
+    ("validation.token_too_short", "GitHub token seems too short"),
+    ("validation.author_name_empty", "Author name cannot be empty"),
+    ("validation.author_name_too_long", "Author name too long (max 100 characters)"),
+    ("validation.email_empty", "Email cannot be empty"),
+    ("validation.email_missing_at", "Email must contain @ symbol"),
+    ("validation.email_multiple_at", "Email must have exactly one @ symbol"),
+    ("validation.email_parts", "Email must have content before and after @"),
+    ("validation.email_domain", "Email domain must contain a dot"),
+    ("validation.email_too_long", "Email too long (max 254 characters)"),
+    ("validation.invalid_start_year", "Invalid start year in range"),
+    ("validation.invalid_end_year", "Invalid end year in range"),
+    ("validation.year_range_order", "Start year must be less than or equal to end year"),
+    ("validation.year_range_too_large", "Year range too large (max 50 years)"),
+    ("validation.years_out_of_range", "Years must be between 1970 and 2030"),
+    ("validation.invalid_year_in_list", "Invalid year in list"),
+    ("validation.year_out_of_range", "Year {year} must be between 1970 and 2030"),
+    ("validation.invalid_year", "Invalid year format"),
+    ("validation.single_year_out_of_range", "Year must be between 1970 and 2030"),
+
+    // Dry run
+    ("dry_run.header", "Dry Run Mode - Preview of Operations"),
+    ("dry_run.no_changes", "No changes will be made to your repositories"),
+    ("dry_run.summary_title", "Operation Summary"),
+    ("dry_run.summary_operations", "Total Operations:"),
+    ("dry_run.summary_years", "Years to Process:"),
+    ("dry_run.summary_year_range", "{count} years ({start}-{end})"),
+    ("dry_run.summary_repositories", "Repositories:"),
+    ("dry_run.summary_files", "Files to Create:"),
+    ("dry_run.summary_commits", "Commits to Create:"),
+    ("dry_run.summary_duration", "Estimated Duration:"),
+    ("dry_run.summary_seconds", "~{seconds} seconds"),
+    ("dry_run.heatmap_title", "Projected Contribution Graph"),
+    ("dry_run.risks_title", "Potential Risks"),
+    ("dry_run.confirmations_title", "Confirmations Needed"),
+    ("dry_run.operations_title", "Detailed Operations"),
+    ("dry_run.op_validate_token", "Validate GitHub token for user '{username}'"),
+    ("dry_run.op_check_organization", "Check that '{username}' is a member of organization '{organization}'"),
+    ("dry_run.op_check_repository", "Check if repository '{username}/{repository}' exists"),
+    ("dry_run.op_create_private_repository", "Create private repository '{repository}' with description: '{description}'"),
+    ("dry_run.op_create_public_repository", "Create public repository '{repository}' with description: '{description}'"),
+    ("dry_run.op_clone", "Clone repository '{repository}' (branch: {branch}) from {url}"),
+    ("dry_run.op_create_file", "Create file '{filename}'"),
+    ("dry_run.op_commit", "Create backdated commit for year {year}"),
+    ("dry_run.op_push", "Push"),
+    ("dry_run.op_force_push", "Force push"),
+    ("dry_run.op_push_target", "{push} to '{repository}' (branch: {branch})"),
+    ("dry_run.op_pull_request", "Open pull request on '{repository}' from {head} into {base}"),
+    ("dry_run.op_update_repository", "Update repository settings for '{repository}'"),
+    ("dry_run.op_archive", "Archive repository '{repository}'"),
+    ("dry_run.op_cleanup", "Clean up temporary files: {path}"),
+    ("dry_run.label_timestamp", "Timestamp:"),
+    ("dry_run.label_author", "Author:"),
+    ("dry_run.label_message", "Message:"),
+    ("dry_run.label_files", "Files:"),
+    ("dry_run.label_title", "Title:"),
+    ("dry_run.label_auto_merge", "Auto-merge:"),
+    ("dry_run.enabled", "enabled"),
+    ("dry_run.risk_force_push", "Force push will overwrite remote history - this cannot be undone"),
+    ("dry_run.risk_rate_limits", "Processing {count} years may trigger GitHub rate limits"),
+    ("dry_run.risk_old_years", "Very old years ({years}) may look suspicious on your profile"),
+    ("dry_run.risk_new_repositories", "New repositories will be created on your GitHub account"),
+    ("dry_run.risk_archive", "Repository '{repository}' will be archived and become read-only"),
+    ("dry_run.risk_public", "Repository '{repository}' will be made public"),
+    ("dry_run.confirm_create_repositories", "Create {count} new repositories: {repositories}"),
+    ("dry_run.confirm_years", "Process {count} years of commits"),
+    ("dry_run.confirm_force_push", "Force push to repositories: {repositories}"),
+    ("dry_run.confirmation_non_interactive", "Confirmation required but running in non-interactive mode"),
+    ("dry_run.confirmation_use_yes", "Use --yes flag to skip confirmations or run interactively"),
+    ("dry_run.confirmation_required", "Confirmation Required"),
+    ("dry_run.risks_prompt_title", "Potential Risks:"),
+    ("dry_run.proceed_prompt", "Do you want to proceed with these operations?"),
+    ("dry_run.proceeding", "Proceeding with time travel operations..."),
+
+    // Session storage
+    ("session.unreadable_backed_up", "Warning: session file {path} could not be read ({error}); it was moved to {backup} and preferences start fresh"),
+    ("session.unreadable", "Warning: session file {path} could not be read ({error}); preferences start fresh"),
+    ("session.newer_version", "Warning: session file {path} was written by a newer release (version {version}, this one supports {supported}); it is left untouched and preferences from this run are not saved"),
+    ("session.newer_version_read_only", "it was written by a newer release; upgrade git-timetraveler to change profiles"),
+
+    // Non-interactive runs
+    ("run.validation_errors", "Validation errors:"),
+    ("run.issues_found", "The following issues were found:"),
+    ("run.example_usage", "Example usage:"),
+    ("run.more_help", "For more help, run: git-timetraveler --help"),
+    ("run.no_years", "No valid years specified"),
+    ("run.too_many_years", "Too many years specified (maximum {max})"),
+    ("run.verbose_and_quiet", "Cannot use both --verbose and --quiet flags"),
+    ("run.dry_run_completed", "Dry run completed - use --yes to proceed without confirmation"),
+    ("run.skip_confirmation", "Use --yes flag to skip this confirmation or run interactively for more control"),
+    ("run.confirmation_cancelled", "Operation cancelled - confirmation required"),
+    ("run.pull_request_opened", "Opened pull request:"),
+    ("run.auto_merge", "Auto-merge:"),
+    ("run.auto_merge_enabled", "enabled"),
+    ("run.pushed_to_branch", "Commits were pushed to branch"),
+    ("run.settings_failed", "All commits were pushed; only the repository settings update failed"),
+    ("run.archived", "Repository archived"),
+    ("run.all_years_processed", "All {count} years processed successfully!"),
+    ("run.pushed_years", "pushed years"),
+    ("run.none", "none"),
+    ("run.repository", "Repository"),
+    ("run.not_attempted", "Not attempted:"),
+    ("run.debug_info", "Debug info:"),
+    ("run.interrupted", "Time travel interrupted - temporary clones were removed"),
+    ("run.pushed", "Pushed:"),
+    ("run.not_pushed", "Not pushed:"),
+    ("run.preview_written", "Calendar preview written to"),
+
+    // Contribution check after pushing
+    ("verify.waiting", "Checking contributions (waiting up to {seconds}s for GitHub to process the push)..."),
+    ("verify.failed", "Could not verify contributions:"),
+    ("verify.title", "Contribution Check"),
+    ("verify.contributions", "{count} contribution(s) on this day"),
+    ("verify.no_contributions", "no contributions"),
+    ("verify.likely_reason", "Likely reason:"),
+    ("verify.result", "Result:"),
+    ("verify.days_with_contributions", "{count} of {total} days have contributions"),
+    ("verify.counts_include_all", "Counts include activity from all repositories on those days"),
+
+    // Profile command
+    ("profile.none", "No profiles yet - create one with 'git-timetraveler profile add <NAME> --username <USER>'"),
+    ("profile.title", "👤 Profiles"),
+    ("profile.username", "Username:"),
+    ("profile.author", "Author:"),
+    ("profile.token", "Token:"),
+    ("profile.token_prompt", "asked for each run"),
+    ("profile.owner", "Owner:"),
+    ("profile.hours", "Hours:"),
+    ("profile.token_not_stored", "Tokens are not stored in profiles - export it and pass the variable name with --token-env"),
+    ("profile.created", "Created profile"),
+    ("profile.updated", "Updated profile"),
+    ("profile.use_with", "Use it with"),
+    ("profile.removed", "Removed profile"),
+
+    // Error output
+    ("errors.how_to_fix", "How to fix this:"),
+    ("errors.more_info", "More info:"),
+    ("errors.retryable", "This operation can be retried"),
+    ("errors.retry_after", "Wait {seconds} seconds before retrying"),
+    ("errors.details", "Error details:"),
+
+    // Recovery steps, one per line
+    ("recovery.auth_invalid_token", "1. Go to https://github.com/settings/tokens\n2. Click 'Generate new token (classic)'\n3. Select 'repo' scope for full repository access\n4. Copy the generated token and try again"),
+    ("recovery.auth_insufficient_permissions", "Your token needs 'repo' permissions\n1. Go to your token settings\n2. Edit the token or create a new one\n3. Ensure 'repo' scope is selected"),
+    ("recovery.auth_token_expired", "Your token has expired\n1. Go to https://github.com/settings/tokens\n2. Generate a new token with 'repo' scope\n3. Update your configuration with the new token"),
+    ("recovery.auth_rate_limited", "GitHub API rate limit exceeded\n1. Wait for the rate limit to reset (usually 1 hour)\n2. Consider using a personal access token for higher limits\n3. Try again later"),
+    ("recovery.auth_sso_required", "The organization requires SAML single sign-on\n1. Open the authorization link below\n2. Sign in with the organization's identity provider and authorize the token\n3. Run the command again"),
+    ("recovery.repo_not_found", "Repository '{repository}' was not found\n1. Check the repository name for typos\n2. Ensure you have access to the repository\n3. Use --create-repo flag to create it automatically"),
+    ("recovery.repo_already_exists", "Repository '{repository}' already exists\n1. Choose a different repository name\n2. Use --force flag to overwrite (use with caution)\n3. Delete the existing repository first"),
+    ("recovery.repo_access_denied", "Access denied to repository '{repository}'\n1. Check your GitHub token permissions\n2. Ensure you own the repository or have write access\n3. Verify the repository name is correct"),
+    ("recovery.repo_invalid_name", "Invalid repository name: '{repository}'\n1. Repository names must be 1-100 characters\n2. Use only letters, numbers, hyphens, and underscores\n3. Cannot start or end with hyphens"),
+    ("recovery.repo_too_large", "Repository operation would exceed size limits\n1. Consider using fewer years or smaller date ranges\n2. Split the operation into multiple repositories\n3. Clean up existing repository content first"),
+    ("recovery.repo_disabled", "Repository '{repository}' has been disabled by GitHub\n1. Check your notifications and email from GitHub for the reason\n2. Contact GitHub Support to restore access\n3. Use a different repository in the meantime"),
+    ("recovery.repo_archived", "Repository '{repository}' is archived and read-only\n1. Unarchive it in the repository settings\n2. Or choose a different repository"),
+    ("recovery.git_clone", "Failed to clone repository\n1. Check your internet connection\n2. Verify the repository URL is correct\n3. Ensure your GitHub token has access\n4. Try again in a few moments"),
+    ("recovery.git_commit", "Failed to create commit\n1. Check if files were properly staged\n2. Verify Git configuration is correct\n3. Ensure the repository is not corrupted"),
+    ("recovery.git_push", "Failed to push to remote repository\n1. Check your internet connection\n2. Verify your GitHub token permissions\n3. Try using --force flag if safe to do so\n4. Check if the branch is protected"),
+    ("recovery.git_other", "Git operation '{operation}' failed\n1. Check your Git configuration\n2. Verify repository permissions\n3. Try the operation again"),
+    ("recovery.network_connection_failed", "Failed to connect to {service}\n1. Check your internet connection\n2. Verify the service is accessible\n3. Try again in a few moments"),
+    ("recovery.network_timeout", "Request to {service} timed out\n1. Check your internet connection speed\n2. Try again with a more stable connection\n3. The service might be experiencing high load"),
+    ("recovery.network_rate_limited", "{service} rate limit exceeded\n1. Wait for the rate limit to reset\n2. Consider using authentication for higher limits\n3. Reduce the frequency of requests"),
+    ("recovery.network_service_unavailable", "{service} is temporarily unavailable\n1. The service is experiencing issues\n2. Check the service status page\n3. Try again in a few minutes"),
+    ("recovery.network_invalid_response", "Received invalid response from {service}\n1. The service might be experiencing issues\n2. Try again in a few moments\n3. Check if you're using the latest version"),
+    ("recovery.network_secondary_rate_limited", "{service} throttled a burst of requests\n1. Wait before retrying\n2. Use fewer parallel jobs (--jobs)\n3. Spread the work over a longer time"),
+    ("recovery.fs_read", "Failed to read file: {path}\n1. Check if the file exists\n2. Verify you have read permissions\n3. Ensure the file is not locked by another process"),
+    ("recovery.fs_write", "Failed to write file: {path}\n1. Check if you have write permissions\n2. Ensure the directory exists\n3. Verify there's enough disk space"),
+    ("recovery.fs_create", "Failed to create file: {path}\n1. Check if the parent directory exists\n2. Verify you have write permissions\n3. Ensure the filename is valid"),
+    ("recovery.fs_other", "File system operation '{operation}' failed for: {path}\n1. Check file permissions\n2. Verify the path is correct\n3. Ensure sufficient disk space"),
+    ("recovery.configuration", "Configuration issue with {setting}: {issue}\n1. Check your configuration file syntax\n2. Verify all required settings are present\n3. Use --help to see valid options\n4. Reset to defaults if needed"),
+];
+
+const ES: &[(&str, &str)] = &[
+    // Shared
+    ("common.cancelled_by_user", "Operación cancelada por el usuario"),
+    ("common.press_enter", "Pulsa Intro para continuar..."),
+    ("common.yes", "Sí"),
+    ("common.no", "No"),
+
+    // Welcome screen and menu
+    ("banner.created_by", "creado por CHX"),
+    ("banner.tagline", "Crea commits con fecha pasada para mejorar tu gráfico de contribuciones de GitHub"),
+    ("banner.features_title", "Qué hace esta herramienta:"),
+    ("banner.feature_timestamps", "Crea commits con fechas personalizadas"),
+    ("banner.feature_push", "Sube a repositorios de GitHub de forma segura"),
+    ("banner.feature_ranges", "Admite años sueltos o rangos de años"),
+    ("banner.feature_preferences", "Recuerda tus preferencias para la próxima vez"),
+    ("banner.requires_tty", "Esta CLI necesita un terminal interactivo (TTY) para el modo interactivo.\nSi usas npx o una shell no interactiva, prueba con la opción --no-menu e indica todos los argumentos necesarios."),
+    ("banner.interrupted", "Interrumpido: se detendrá tras el paso actual (pulsa Ctrl+C otra vez para salir ya)"),
+    ("banner.goodbye", "Saliendo. ¡Hasta luego!"),
+    ("menu.create", "Crear commit(s) con fecha pasada"),
+    ("menu.examples", "Ver ejemplos de uso"),
+    ("menu.tokens", "Información sobre los tokens de GitHub"),
+    ("menu.configuration", "Opciones de configuración"),
+    ("menu.exit", "Salir"),
+    ("menu.prompt", "¿Qué quieres hacer?"),
+    ("menu.thanks", "¡Gracias por usar Git Time Traveler!"),
+
+    // Run banners
+    ("banner.summary_title", "Resumen de la operación"),
+    ("banner.repository", "Repositorio:"),
+    ("banner.github_user", "Usuario de GitHub:"),
+    ("banner.years", "Años:"),
+    ("banner.year_range", "{count} años ({start}-{end})"),
+    ("banner.additional_confirmation", "Se necesita una confirmación adicional"),
+    ("banner.force_push_warning", "El push forzado sobrescribirá el historial remoto"),
+    ("banner.many_years_warning", "Procesar {count} años puede llevar bastante tiempo"),
+    ("banner.final_confirm", "¿Seguro que quieres continuar?"),
+    ("banner.starting", "Iniciando el viaje en el tiempo..."),
+    ("banner.processing_year", "Procesando el año:"),
+    ("banner.year_complete", "Año {year} completado"),
+    ("banner.year_created", "¡Commit del año {year} creado correctamente!"),
+    ("banner.complete", "¡Viaje en el tiempo completado!"),
+    ("banner.commits_created", "{commits} commits creados en {years} años"),
+    ("banner.view_at", "Ver en:"),
+    ("banner.check_profile", "¡Revisa tu perfil de GitHub para ver los commits con fecha pasada!"),
+
+    // Usage examples screen
+    ("examples.title", "Ejemplos de uso"),
+    ("examples.intro", "Estas son las formas habituales de usar Git Time Traveler:"),
+    ("examples.interactive", "Modo interactivo (recomendado):"),
+    ("examples.interactive_note", "Preguntas guiadas con valores sugeridos"),
+    ("examples.non_interactive", "Modo no interactivo:"),
+    ("examples.non_interactive_note", "Ideal para scripts y automatización"),
+    ("examples.options", "Opciones habituales:"),
+    ("examples.option_year", "Crea commits para un solo año"),
+    ("examples.option_years", "Crea commits para un rango de años"),
+    ("examples.option_repo", "Indica un nombre de repositorio"),
+    ("examples.option_hour", "Hora de los commits (0-23)"),
+    ("examples.option_force", "Push forzado (úsalo con cuidado)"),
+    ("examples.option_lang", "Muestra las preguntas y los mensajes en otro idioma"),
+    ("examples.option_profile", "Usa un perfil guardado (ver 'git-timetraveler profile add')"),
+    ("examples.tips", "Consejos:"),
+    ("examples.tip_preferences", "La herramienta recuerda tus preferencias entre sesiones"),
+    ("examples.tip_ranges", "Usa rangos como 1990-1995 para varios años a la vez"),
+    ("examples.tip_hour", "Por defecto los commits se crean a las 18:00 para que parezcan realistas"),
+    ("examples.tip_token", "Tu token de GitHub necesita el permiso 'repo'"),
+
+    // GitHub token screen
+    ("tokens.title", "Configuración del token de acceso personal de GitHub"),
+    ("tokens.intro", "Necesitas un token de GitHub para crear repositorios y subir cambios."),
+    ("tokens.steps", "Instrucciones paso a paso:"),
+    ("tokens.step_open", "Ve a: {url}"),
+    ("tokens.step_generate", "Haz clic en: {button}"),
+    ("tokens.step_expiration", "Elige la caducidad: {hint}"),
+    ("tokens.step_expiration_hint", "La duración que prefieras"),
+    ("tokens.step_scopes", "Selecciona los permisos: {hint}"),
+    ("tokens.step_scopes_hint", "Marca 'repo' (control total de los repositorios privados)"),
+    ("tokens.step_confirm", "Haz clic en: {button}"),
+    ("tokens.step_copy", "Copia el token: {hint}"),
+    ("tokens.step_copy_hint", "Guárdalo en un lugar seguro, ¡no podrás volver a verlo!"),
+    ("tokens.permissions", "Permisos necesarios:"),
+    ("tokens.permission_repo", "Crear, leer y escribir repositorios"),
+    ("tokens.permission_public_repo", "Acceso a repositorios públicos (incluido en repo)"),
+    ("tokens.security", "Notas de seguridad:"),
+    ("tokens.security_share", "Nunca compartas tu token con nadie"),
+    ("tokens.security_store", "Guárdalo de forma segura (se recomienda un gestor de contraseñas)"),
+    ("tokens.security_revoke", "Puedes revocarlo cuando quieras desde la configuración de GitHub"),
+    ("tokens.security_local", "Esta herramienta lo guarda localmente y nunca lo envía a otro sitio"),
+
+    // Configuration screen
+    ("configuration.title", "Opciones de configuración"),
+    ("configuration.intro", "Git Time Traveler aprende de tu forma de usarlo."),
+    ("configuration.preferences", "Preferencias guardadas:"),
+    ("configuration.preference_repositories", "Nombres de repositorios recientes"),
+    ("configuration.preference_author", "Modos de autoría preferidos"),
+    ("configuration.preference_years", "Patrones de años habituales"),
+    ("configuration.preference_username", "Usuario de GitHub"),
+    ("configuration.preference_timing", "Hora predeterminada de los commits"),
+    ("configuration.location", "Ubicación de la configuración:"),
+    ("configuration.reset", "Opciones de restablecimiento:"),
+    ("configuration.reset_delete", "Borra el directorio de configuración para empezar de cero"),
+    ("configuration.reset_expiry", "Cada preferencia se restablece sola tras 30 días sin uso"),
+    ("configuration.reset_tokens", "Los tokens de GitHub se guardan de forma segura en el llavero del sistema"),
+    ("configuration.override", "Opciones de línea de comandos:"),
+    ("configuration.override_no_menu", "Usa {flag} para omitir todas las preguntas interactivas"),
+    ("configuration.override_arguments", "Todas las preferencias se pueden sustituir con argumentos de línea de comandos"),
+
+    // Interactive prompts
+    ("interactive.tagline", "¡Viaja atrás en el tiempo en tu perfil de GitHub con facilidad!"),
+    ("interactive.profile_prompt", "¿Qué perfil quieres usar?"),
+    ("interactive.profile_none", "Ningún perfil (preferencias compartidas)"),
+    ("interactive.smart_suggestions", "Sugerencias según tu contexto:"),
+    ("interactive.suggested", "{prompt} (sugerencia: {suggestion})"),
+    ("interactive.repository", "Nombre del repositorio"),
+    ("interactive.branch", "Nombre de la rama"),
+    ("interactive.author_prompt", "¿Quién debe figurar como autor de los commits?"),
+    ("interactive.author_git_identity", "Usar tu identidad de Git: {name} <{email}>"),
+    ("interactive.author_git_identity_if_available", "Usar tu identidad de Git (si existe)"),
+    ("interactive.author_custom_identity", "Usar la identidad personalizada: {name} <{email}>"),
+    ("interactive.author_time_traveler", "Usar la identidad genérica de viajero en el tiempo"),
+    ("interactive.author_specify_custom", "Indicar un autor personalizado"),
+    ("interactive.author_ask_each_time", "Preguntarme cada vez"),
+    ("interactive.custom_author_title", "Autor personalizado"),
+    ("interactive.custom_author_intro", "Indica el nombre y el correo del autor de los commits:"),
+    ("interactive.author_name", "Nombre del autor"),
+    ("interactive.author_email", "Correo del autor"),
+    ("interactive.year_authors_title", "Autores por año:"),
+    ("interactive.author_for_year", "Autor para {year}"),
+    ("interactive.author_from_pool", "{year}: {name} <{email}> (del archivo de autores)"),
+    ("interactive.year_options", "Formas de elegir los años:"),
+    ("interactive.year_single", "Un año: {example}"),
+    ("interactive.year_range", "Rango de años: {example}"),
+    ("interactive.year_multiple", "Varios años: {example}"),
+    ("interactive.years", "Años del viaje en el tiempo"),
+    ("interactive.hour", "Hora de los commits (0-23, sugerencia: {suggestion})"),
+    ("interactive.hour_title", "Hora de los commits"),
+    ("interactive.suggestion_confidence", "{dots} {percent}% de confianza"),
+    ("interactive.suggestion_repository", "Empezar el nombre con '{prefix}', como tus otros repositorios"),
+    ("interactive.suggestion_other_repository", "Elegir el nombre yo mismo"),
+    ("interactive.suggestion_branch", "Usar la rama '{branch}'"),
+    ("interactive.suggestion_other_branch", "Introducir otra rama"),
+    ("interactive.suggestion_year", "Viajar a {year}"),
+    ("interactive.suggestion_other_years", "Introducir otros años"),
+    ("interactive.suggestion_hour", "Hacer commits a las {hour}"),
+    ("interactive.suggestion_other_hour", "Introducir otra hora"),
+    ("interactive.github_username", "Usuario de GitHub"),
+    ("interactive.token_title", "Token de acceso personal de GitHub:"),
+    ("interactive.token_create_at", "Créalo en: {url}"),
+    ("interactive.token_permissions", "Permisos necesarios: {scope}"),
+    ("interactive.token_scope", "repo (control total)"),
+    ("interactive.token_prompt", "Token de GitHub (no se muestra)"),
+    ("interactive.token_from_profile", "🔑 Usando el token de ${variable} (perfil '{profile}')"),
+    ("interactive.token_variable_unset", "⚠️  ${variable} del perfil '{profile}' no está definida"),
+    ("interactive.force_push_title", "Aviso sobre el push forzado:"),
+    ("interactive.force_push_warning", "El push forzado sobrescribirá el historial remoto. ¡Úsalo con cuidado!"),
+    ("interactive.force_push_prompt", "¿Activar el push forzado?"),
+    ("interactive.summary_title", "Resumen:"),
+    ("interactive.summary_profile", "Perfil: {value}"),
+    ("interactive.summary_repository", "Repositorio: {value}"),
+    ("interactive.summary_branch", "Rama: {value}"),
+    ("interactive.summary_author", "Autor: {value}"),
+    ("interactive.summary_author_yours", "Tu identidad: {name} <{email}>"),
+    ("interactive.summary_author_time_traveler", "Viajero en el tiempo"),
+    ("interactive.summary_author_custom", "Identidad personalizada: {name} <{email}>"),
+    ("interactive.summary_author_ask", "Preguntar cada vez"),
+    ("interactive.summary_year_author", "{year}: {value}"),
+    ("interactive.summary_years", "Años: {value}"),
+    ("interactive.summary_year_range", "{count} años ({start}-{end})"),
+    ("interactive.summary_time", "Hora: {value}"),
+    ("interactive.summary_username", "Usuario: {value}"),
+    ("interactive.summary_force_push", "Push forzado: {value}"),
+    ("interactive.proceed_prompt", "¿Empezar el viaje en el tiempo?"),
+
+    // Input validation
+    ("validation.repository_empty", "El nombre del repositorio no puede estar vacío"),
+    ("validation.repository_too_long", "El nombre del repositorio es demasiado largo (máximo 100 caracteres)"),
+    ("validation.repository_characters", "El nombre del repositorio solo puede contener letras, números, guiones, guiones bajos y puntos"),
+    ("validation.branch_empty", "El nombre de la rama no puede estar vacío"),
+    ("validation.branch_hyphen", "El nombre de la rama no puede empezar ni terminar con un guion"),
+    ("validation.branch_characters", "El nombre de la rama no puede contener espacios ni puntos seguidos"),
+    ("validation.years_empty", "Indica al menos un año"),
+    ("validation.years_too_many", "Demasiados años (máximo 50)"),
+    ("validation.hour_range", "La hora debe estar entre 0 y 23"),
+    ("validation.not_a_number", "Introduce un número válido"),
+    ("validation.username_empty", "El usuario de GitHub no puede estar vacío"),
+    ("validation.username_too_long", "El usuario de GitHub es demasiado largo (máximo 39 caracteres)"),
+    ("validation.username_characters", "El usuario de GitHub solo puede contener letras, números y guiones"),
+    ("validation.username_hyphen", "El usuario de GitHub no puede empezar ni terminar con un guion"),
+    ("validation.token_empty", "El token de GitHub no puede estar vacío"),
+    ("validation.token_too_short", "El token de GitHub parece demasiado corto"),
+    ("validation.author_name_empty", "El nombre del autor no puede estar vacío"),
+    ("validation.author_name_too_long", "El nombre del autor es demasiado largo (máximo 100 caracteres)"),
+    ("validation.email_empty", "El correo no puede estar vacío"),
+    ("validation.email_missing_at", "El correo debe contener el símbolo @"),
+    ("validation.email_multiple_at", "El correo debe contener exactamente un símbolo @"),
+    ("validation.email_parts", "El correo debe tener texto antes y después de la @"),
+    ("validation.email_domain", "El dominio del correo debe contener un punto"),
+    ("validation.email_too_long", "El correo es demasiado largo (máximo 254 caracteres)"),
+    ("validation.invalid_start_year", "El año inicial del rango no es válido"),
+    ("validation.invalid_end_year", "El año final del rango no es válido"),
+    ("validation.year_range_order", "El año inicial debe ser menor o igual que el año final"),
+    ("validation.year_range_too_large", "El rango de años es demasiado grande (máximo 50 años)"),
+    ("validation.years_out_of_range", "Los años deben estar entre 1970 y 2030"),
+    ("validation.invalid_year_in_list", "Hay un año no válido en la lista"),
+    ("validation.year_out_of_range", "El año {year} debe estar entre 1970 y 2030"),
+    ("validation.invalid_year", "Formato de año no válido"),
+    ("validation.single_year_out_of_range", "El año debe estar entre 1970 y 2030"),
+
+    // Dry run
+    ("dry_run.header", "Modo de prueba: vista previa de las operaciones"),
+    ("dry_run.no_changes", "No se hará ningún cambio en tus repositorios"),
+    ("dry_run.summary_title", "Resumen de la operación"),
+    ("dry_run.summary_operations", "Operaciones totales:"),
+    ("dry_run.summary_years", "Años a procesar:"),
+    ("dry_run.summary_year_range", "{count} años ({start}-{end})"),
+    ("dry_run.summary_repositories", "Repositorios:"),
+    ("dry_run.summary_files", "Archivos a crear:"),
+    ("dry_run.summary_commits", "Commits a crear:"),
+    ("dry_run.summary_duration", "Duración estimada:"),
+    ("dry_run.summary_seconds", "~{seconds} segundos"),
+    ("dry_run.heatmap_title", "Gráfico de contribuciones previsto"),
+    ("dry_run.risks_title", "Riesgos posibles"),
+    ("dry_run.confirmations_title", "Confirmaciones necesarias"),
+    ("dry_run.operations_title", "Operaciones detalladas"),
+    ("dry_run.op_validate_token", "Validar el token de GitHub del usuario '{username}'"),
+    ("dry_run.op_check_organization", "Comprobar que '{username}' es miembro de la organización '{organization}'"),
+    ("dry_run.op_check_repository", "Comprobar si existe el repositorio '{username}/{repository}'"),
+    ("dry_run.op_create_private_repository", "Crear el repositorio privado '{repository}' con la descripción: '{description}'"),
+    ("dry_run.op_create_public_repository", "Crear el repositorio público '{repository}' con la descripción: '{description}'"),
+    ("dry_run.op_clone", "Clonar el repositorio '{repository}' (rama: {branch}) desde {url}"),
+    ("dry_run.op_create_file", "Crear el archivo '{filename}'"),
+    ("dry_run.op_commit", "Crear un commit con fecha pasada para el año {year}"),
+    ("dry_run.op_push", "Push"),
+    ("dry_run.op_force_push", "Push forzado"),
+    ("dry_run.op_push_target", "{push} a '{repository}' (rama: {branch})"),
+    ("dry_run.op_pull_request", "Abrir un pull request en '{repository}' desde {head} hacia {base}"),
+    ("dry_run.op_update_repository", "Actualizar la configuración del repositorio '{repository}'"),
+    ("dry_run.op_archive", "Archivar el repositorio '{repository}'"),
+    ("dry_run.op_cleanup", "Eliminar los archivos temporales: {path}"),
+    ("dry_run.label_timestamp", "Fecha:"),
+    ("dry_run.label_author", "Autor:"),
+    ("dry_run.label_message", "Mensaje:"),
+    ("dry_run.label_files", "Archivos:"),
+    ("dry_run.label_title", "Título:"),
+    ("dry_run.label_auto_merge", "Fusión automática:"),
+    ("dry_run.enabled", "activada"),
+    ("dry_run.risk_force_push", "El push forzado sobrescribirá el historial remoto y no se puede deshacer"),
+    ("dry_run.risk_rate_limits", "Procesar {count} años puede superar los límites de uso de GitHub"),
+    ("dry_run.risk_old_years", "Los años muy antiguos ({years}) pueden parecer sospechosos en tu perfil"),
+    ("dry_run.risk_new_repositories", "Se crearán repositorios nuevos en tu cuenta de GitHub"),
+    ("dry_run.risk_archive", "El repositorio '{repository}' se archivará y pasará a ser de solo lectura"),
+    ("dry_run.risk_public", "El repositorio '{repository}' pasará a ser público"),
+    ("dry_run.confirm_create_repositories", "Crear {count} repositorios nuevos: {repositories}"),
+    ("dry_run.confirm_years", "Procesar {count} años de commits"),
+    ("dry_run.confirm_force_push", "Push forzado a los repositorios: {repositories}"),
+    ("dry_run.confirmation_non_interactive", "Se necesita confirmación, pero se está ejecutando en modo no interactivo"),
+    ("dry_run.confirmation_use_yes", "Usa la opción --yes para omitir las confirmaciones o ejecútalo de forma interactiva"),
+    ("dry_run.confirmation_required", "Se necesita confirmación"),
+    ("dry_run.risks_prompt_title", "Riesgos posibles:"),
+    ("dry_run.proceed_prompt", "¿Quieres continuar con estas operaciones?"),
+    ("dry_run.proceeding", "Continuando con el viaje en el tiempo..."),
+
+    // Session storage
+    ("session.unreadable_backed_up", "Aviso: no se ha podido leer el archivo de sesión {path} ({error}); se ha movido a {backup} y las preferencias empiezan de cero"),
+    ("session.unreadable", "Aviso: no se ha podido leer el archivo de sesión {path} ({error}); las preferencias empiezan de cero"),
+    ("session.newer_version", "Aviso: el archivo de sesión {path} lo escribió una versión más reciente (versión {version}, esta admite {supported}); no se modifica y las preferencias de esta ejecución no se guardan"),
+    ("session.newer_version_read_only", "lo escribió una versión más reciente; actualiza git-timetraveler para cambiar los perfiles"),
+
+    // Non-interactive runs
+    ("run.validation_errors", "Errores de validación:"),
+    ("run.issues_found", "Se han encontrado los siguientes problemas:"),
+    ("run.example_usage", "Ejemplo de uso:"),
+    ("run.more_help", "Para más ayuda, ejecuta: git-timetraveler --help"),
+    ("run.no_years", "No se ha indicado ningún año válido"),
+    ("run.too_many_years", "Demasiados años indicados (máximo {max})"),
+    ("run.verbose_and_quiet", "No se pueden usar a la vez --verbose y --quiet"),
+    ("run.dry_run_completed", "Simulación completada - usa --yes para continuar sin confirmación"),
+    ("run.skip_confirmation", "Usa --yes para omitir esta confirmación o ejecuta en modo interactivo para tener más control"),
+    ("run.confirmation_cancelled", "Operación cancelada - se necesita confirmación"),
+    ("run.pull_request_opened", "Pull request abierta:"),
+    ("run.auto_merge", "Fusión automática:"),
+    ("run.auto_merge_enabled", "activada"),
+    ("run.pushed_to_branch", "Los commits se subieron a la rama"),
+    ("run.settings_failed", "Se subieron todos los commits; solo falló la actualización de la configuración del repositorio"),
+    ("run.archived", "Repositorio archivado"),
+    ("run.all_years_processed", "¡Los {count} años se procesaron correctamente!"),
+    ("run.pushed_years", "años subidos"),
+    ("run.none", "ninguno"),
+    ("run.repository", "Repositorio"),
+    ("run.not_attempted", "Sin intentar:"),
+    ("run.debug_info", "Información de depuración:"),
+    ("run.interrupted", "Viaje en el tiempo interrumpido - se eliminaron los clones temporales"),
+    ("run.pushed", "Subidos:"),
+    ("run.not_pushed", "Sin subir:"),
+    ("run.preview_written", "Vista previa del calendario guardada en"),
+
+    // Contribution check after pushing
+    ("verify.waiting", "Comprobando las contribuciones (esperando hasta {seconds} s a que GitHub procese la subida)..."),
+    ("verify.failed", "No se han podido verificar las contribuciones:"),
+    ("verify.title", "Comprobación de contribuciones"),
+    ("verify.contributions", "{count} contribución(es) ese día"),
+    ("verify.no_contributions", "sin contribuciones"),
+    ("verify.likely_reason", "Motivo probable:"),
+    ("verify.result", "Resultado:"),
+    ("verify.days_with_contributions", "{count} de {total} días tienen contribuciones"),
+    ("verify.counts_include_all", "Los recuentos incluyen la actividad de todos los repositorios en esos días"),
+
+    // Profile command
+    ("profile.none", "Todavía no hay perfiles - crea uno con 'git-timetraveler profile add <NOMBRE> --username <USUARIO>'"),
+    ("profile.title", "👤 Perfiles"),
+    ("profile.username", "Usuario:"),
+    ("profile.author", "Autor:"),
+    ("profile.token", "Token:"),
+    ("profile.token_prompt", "se pide en cada ejecución"),
+    ("profile.owner", "Propietario:"),
+    ("profile.hours", "Horas:"),
+    ("profile.token_not_stored", "Los tokens no se guardan en los perfiles - expórtalo y pasa el nombre de la variable con --token-env"),
+    ("profile.created", "Perfil creado"),
+    ("profile.updated", "Perfil actualizado"),
+    ("profile.use_with", "Úsalo con"),
+    ("profile.removed", "Perfil eliminado"),
+
+    // Error output
+    ("errors.how_to_fix", "Cómo solucionarlo:"),
+    ("errors.more_info", "Más información:"),
+    ("errors.retryable", "Esta operación se puede reintentar"),
+    ("errors.retry_after", "Espera {seconds} segundos antes de reintentar"),
+    ("errors.details", "Detalles del error:"),
+
+    // Recovery steps, one per line
+    ("recovery.auth_invalid_token", "1. Ve a https://github.com/settings/tokens\n2. Haz clic en 'Generate new token (classic)'\n3. Selecciona el permiso 'repo' para tener acceso completo a los repositorios\n4. Copia el token generado y vuelve a intentarlo"),
+    ("recovery.auth_insufficient_permissions", "Tu token necesita el permiso 'repo'\n1. Abre la configuración de tu token\n2. Edita el token o crea uno nuevo\n3. Asegúrate de que el permiso 'repo' está seleccionado"),
+    ("recovery.auth_token_expired", "Tu token ha caducado\n1. Ve a https://github.com/settings/tokens\n2. Genera un token nuevo con el permiso 'repo'\n3. Actualiza tu configuración con el token nuevo"),
+    ("recovery.auth_rate_limited", "Se ha superado el límite de uso de la API de GitHub\n1. Espera a que se restablezca el límite (normalmente 1 hora)\n2. Usa un token de acceso personal para tener límites más altos\n3. Vuelve a intentarlo más tarde"),
+    ("recovery.auth_sso_required", "La organización exige inicio de sesión único SAML\n1. Abre el enlace de autorización de abajo\n2. Inicia sesión con el proveedor de identidad de la organización y autoriza el token\n3. Vuelve a ejecutar el comando"),
+    ("recovery.repo_not_found", "No se ha encontrado el repositorio '{repository}'\n1. Revisa que el nombre del repositorio esté bien escrito\n2. Asegúrate de que tienes acceso al repositorio\n3. Usa la opción --create-repo para crearlo automáticamente"),
+    ("recovery.repo_already_exists", "El repositorio '{repository}' ya existe\n1. Elige otro nombre de repositorio\n2. Usa la opción --force para sobrescribirlo (con cuidado)\n3. Borra primero el repositorio existente"),
+    ("recovery.repo_access_denied", "Acceso denegado al repositorio '{repository}'\n1. Revisa los permisos de tu token de GitHub\n2. Asegúrate de que el repositorio es tuyo o tienes permiso de escritura\n3. Comprueba que el nombre del repositorio es correcto"),
+    ("recovery.repo_invalid_name", "Nombre de repositorio no válido: '{repository}'\n1. Los nombres de repositorio deben tener entre 1 y 100 caracteres\n2. Usa solo letras, números, guiones y guiones bajos\n3. No pueden empezar ni terminar con guion"),
+    ("recovery.repo_too_large", "La operación superaría los límites de tamaño del repositorio\n1. Usa menos años o rangos de fechas más cortos\n2. Reparte la operación entre varios repositorios\n3. Limpia primero el contenido existente del repositorio"),
+    ("recovery.repo_disabled", "GitHub ha desactivado el repositorio '{repository}'\n1. Revisa tus notificaciones y el correo de GitHub para conocer el motivo\n2. Contacta con el soporte de GitHub para recuperar el acceso\n3. Mientras tanto, usa otro repositorio"),
+    ("recovery.repo_archived", "El repositorio '{repository}' está archivado y es de solo lectura\n1. Desarchívalo en la configuración del repositorio\n2. O elige otro repositorio"),
+    ("recovery.git_clone", "No se ha podido clonar el repositorio\n1. Comprueba tu conexión a internet\n2. Verifica que la URL del repositorio es correcta\n3. Asegúrate de que tu token de GitHub tiene acceso\n4. Vuelve a intentarlo en unos momentos"),
+    ("recovery.git_commit", "No se ha podido crear el commit\n1. Comprueba que los archivos se añadieron correctamente\n2. Verifica que la configuración de Git es correcta\n3. Asegúrate de que el repositorio no está dañado"),
+    ("recovery.git_push", "No se ha podido subir al repositorio remoto\n1. Comprueba tu conexión a internet\n2. Verifica los permisos de tu token de GitHub\n3. Prueba con la opción --force si es seguro hacerlo\n4. Comprueba si la rama está protegida"),
+    ("recovery.git_other", "Ha fallado la operación de Git '{operation}'\n1. Revisa tu configuración de Git\n2. Verifica los permisos del repositorio\n3. Vuelve a intentar la operación"),
+    ("recovery.network_connection_failed", "No se ha podido conectar con {service}\n1. Comprueba tu conexión a internet\n2. Verifica que el servicio está accesible\n3. Vuelve a intentarlo en unos momentos"),
+    ("recovery.network_timeout", "La petición a {service} ha excedido el tiempo de espera\n1. Comprueba la velocidad de tu conexión a internet\n2. Vuelve a intentarlo con una conexión más estable\n3. Puede que el servicio tenga mucha carga"),
+    ("recovery.network_rate_limited", "Se ha superado el límite de uso de {service}\n1. Espera a que se restablezca el límite\n2. Usa autenticación para tener límites más altos\n3. Reduce la frecuencia de las peticiones"),
+    ("recovery.network_service_unavailable", "{service} no está disponible temporalmente\n1. El servicio tiene problemas\n2. Consulta la página de estado del servicio\n3. Vuelve a intentarlo en unos minutos"),
+    ("recovery.network_invalid_response", "Se ha recibido una respuesta no válida de {service}\n1. Puede que el servicio tenga problemas\n2. Vuelve a intentarlo en unos momentos\n3. Comprueba que usas la última versión"),
+    ("recovery.network_secondary_rate_limited", "{service} ha limitado una ráfaga de peticiones\n1. Espera antes de reintentar\n2. Usa menos tareas en paralelo (--jobs)\n3. Reparte el trabajo en más tiempo"),
+    ("recovery.fs_read", "No se ha podido leer el archivo: {path}\n1. Comprueba que el archivo existe\n2. Verifica que tienes permiso de lectura\n3. Asegúrate de que ningún otro proceso tiene bloqueado el archivo"),
+    ("recovery.fs_write", "No se ha podido escribir el archivo: {path}\n1. Comprueba que tienes permiso de escritura\n2. Asegúrate de que el directorio existe\n3. Verifica que hay suficiente espacio en disco"),
+    ("recovery.fs_create", "No se ha podido crear el archivo: {path}\n1. Comprueba que el directorio padre existe\n2. Verifica que tienes permiso de escritura\n3. Asegúrate de que el nombre del archivo es válido"),
+    ("recovery.fs_other", "Ha fallado la operación de archivos '{operation}' en: {path}\n1. Revisa los permisos del archivo\n2. Verifica que la ruta es correcta\n3. Asegúrate de que hay suficiente espacio en disco"),
+    ("recovery.configuration", "Problema de configuración en {setting}: {issue}\n1. Revisa la sintaxis de tu archivo de configuración\n2. Verifica que están todos los ajustes necesarios\n3. Usa --help para ver las opciones válidas\n4. Restablece los valores predeterminados si hace falta"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::BTreeSet;
+
+    /// Sources whose `t!` calls must all resolve in every locale
+    const SOURCES: [&str; 5] = [
+        include_str!("interactive.rs"),
+        include_str!("session.rs"),
+        include_str!("dry_run.rs"),
+        include_str!("errors.rs"),
+        include_str!("main.rs"),
+    ];
+
+    fn keys(locale: Locale) -> BTreeSet<&'static str> {
+        locale.catalog().iter().map(|(key, _)| *key).collect()
+    }
+
+    fn placeholders(message: &str) -> BTreeSet<String> {
+        Regex::new(r"\{([a-z_]+)\}").unwrap()
+            .captures_iter(message)
+            .map(|captures| captures[1].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_every_locale_has_every_key() {
+        for locale in Locale::ALL {
+            assert_eq!(keys(locale).len(), locale.catalog().len(), "duplicate key in {}", locale);
+        }
+        let reference = keys(Locale::En);
+        for locale in Locale::ALL {
+            let catalog = keys(locale);
+            let missing: Vec<_> = reference.difference(&catalog).collect();
+            let unknown: Vec<_> = catalog.difference(&reference).collect();
+            assert!(missing.is_empty(), "{} is missing {:?}", locale, missing);
+            assert!(unknown.is_empty(), "{} has keys not in en: {:?}", locale, unknown);
+
+            for (key, message) in locale.catalog() {
+                let english = lookup(EN, key).unwrap();
+                assert_eq!(placeholders(message), placeholders(english), "placeholders of {} in {}", key, locale);
+            }
+        }
+    }
+
+    #[test]
+    fn test_used_keys_exist() {
+        let call = Regex::new(r#"\bt!\(\s*"([^"]+)""#).unwrap();
+        let mut used = 0;
+        for source in SOURCES {
+            for captures in call.captures_iter(source) {
+                used += 1;
+                for locale in Locale::ALL {
+                    assert!(lookup(locale.catalog(), &captures[1]).is_some(), "{} is missing {}", locale, &captures[1]);
+                }
+            }
+        }
+        assert!(used > 0);
+    }
+
+    #[test]
+    fn test_locale_detection_and_translation() {
+        assert_eq!(Locale::from_tag("es_ES.UTF-8"), Some(Locale::Es));
+        assert_eq!(Locale::from_tag("es-MX"), Some(Locale::Es));
+        assert_eq!(Locale::from_tag("en_US"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("C"), None);
+        assert_eq!(Locale::detect(Some("es")).unwrap(), Locale::Es);
+        assert!(Locale::detect(Some("xx")).is_err());
+
+        let message = translate_in(Locale::Es, "validation.year_out_of_range", &[("year", &1969)]);
+        assert!(message.contains("1969") && !message.contains("{year}"));
+        assert_eq!(translate_in(Locale::En, "no.such.key", &[]), "no.such.key");
+    }
+}
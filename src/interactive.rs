@@ -1,16 +1,22 @@
 use anyhow::{Context, Result};
 use colored::*;
 use dialoguer::{theme::ColorfulTheme, Select, Input, Password, Confirm};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
-use crate::defaults::{DefaultsEngine, IntelligentDefaults, AuthorMode};
+use crate::author_pool::AuthorPool;
+use crate::defaults::{DefaultsEngine, IntelligentDefaults, IntelligentSuggestion, SuggestionType, AuthorMode};
 
-use crate::session::SessionManager;
+use crate::git_context::GitIdentity;
+use crate::session::{SessionManager, TokenSource};
+use crate::secret::Secret;
+use crate::t;
 
 /// Interactive prompts with smart defaults integration
 pub struct InteractivePrompts {
     defaults_engine: DefaultsEngine,
     session_manager: SessionManager,
+    author_pool: Option<AuthorPool>,
     theme: ColorfulTheme,
 }
 
@@ -20,11 +26,17 @@ pub struct UserChoices {
     pub repository: String,
     pub branch: String,
     pub author_mode: AuthorMode,
+    /// Authors chosen per year, overriding `author_mode` for those years
+    pub year_authors: BTreeMap<u32, AuthorMode>,
     pub years: Vec<u32>,
     pub hour: u32,
     pub github_username: String,
-    pub github_token: String,
+    pub github_token: Secret,
     pub force_push: bool,
+    /// Profile the choices were made with
+    pub profile: Option<String>,
+    /// Organization that owns the repository, from the profile
+    pub owner: Option<String>,
 }
 
 /// Validation result for user input
@@ -34,6 +46,27 @@ pub enum ValidationResult {
     Invalid(String),
 }
 
+/// Entries of the author mode menu
+#[derive(Debug, Clone, Copy)]
+enum AuthorOption {
+    /// The identity from the defaults (current user or custom)
+    Suggested,
+    TimeTraveler,
+    GitIdentity,
+    Custom,
+    AskEachTime,
+}
+
+impl UserChoices {
+    /// The identity commits for `year` are authored with (`None` for the Time Traveler)
+    pub fn author_for_year(&self, year: u32) -> Option<GitIdentity> {
+        match self.year_authors.get(&year).unwrap_or(&self.author_mode) {
+            AuthorMode::CurrentUser(identity) | AuthorMode::Manual(identity) => Some(identity.clone()),
+            AuthorMode::TimeTraveler | AuthorMode::AskEachTime => None,
+        }
+    }
+}
+
 impl InteractivePrompts {
     /// Create a new interactive prompts instance
     pub fn new() -> Result<Self> {
@@ -45,42 +78,81 @@ impl InteractivePrompts {
         Ok(Self {
             defaults_engine,
             session_manager,
+            author_pool: None,
             theme: ColorfulTheme::default(),
         })
     }
 
+    /// Attribute the years an author pool covers to its identities
+    pub fn with_author_pool(mut self, pool: AuthorPool) -> Self {
+        self.author_pool = Some(pool);
+        self
+    }
+
+    /// Use a profile instead of asking for one (`None` keeps the shared preferences)
+    pub fn with_profile(mut self, name: Option<&str>) -> Result<Self> {
+        self.activate_profile(name)?;
+        Ok(self)
+    }
+
+    fn activate_profile(&mut self, name: Option<&str>) -> Result<()> {
+        self.session_manager.activate_profile(name)?;
+        self.defaults_engine.set_profile(name)
+    }
+
     /// Run the complete interactive workflow with smart defaults
     pub fn run_interactive_workflow(&mut self, current_path: Option<&Path>) -> Result<UserChoices> {
+        // Pick a profile first, since it decides which preferences the defaults come from
+        if self.session_manager.active_profile.is_none() && !self.session_manager.get_data().profiles.is_empty() {
+            let profile = self.prompt_profile()?;
+            self.activate_profile(profile.as_deref())?;
+        }
+
         // Generate intelligent defaults based on current context
         let defaults = self.defaults_engine.generate_defaults(current_path)
             .context("Failed to generate intelligent defaults")?;
 
+        // Pattern-based suggestions are offered as choices in the matching prompts
+        let suggestions = self.defaults_engine.get_intelligent_suggestions(current_path)
+            .unwrap_or_else(|e| {
+                tracing::warn!(target: "defaults", error = %format!("{:#}", e), "Failed to generate suggestions");
+                Vec::new()
+            });
+
         // Display welcome and context information
         self.display_welcome(&defaults)?;
 
         // Collect user choices with smart defaults
-        let repository = self.prompt_repository(&defaults)?;
-        let branch = self.prompt_branch(&defaults)?;
+        let repository = self.prompt_repository(&defaults, &suggestions)?;
+        let branch = self.prompt_branch(&defaults, &suggestions)?;
         let author_mode = self.prompt_author_mode(&defaults)?;
-        let years = self.prompt_years(&defaults)?;
-        let hour = self.prompt_hour(&defaults)?;
+        let years = self.prompt_years(&defaults, &suggestions)?;
+        let year_authors = self.prompt_year_authors(&defaults, &author_mode, &years)?;
+        let hour = self.prompt_hour(&defaults, &suggestions)?;
         let github_username = self.prompt_github_username(&defaults)?;
         let github_token = self.prompt_github_token()?;
         let force_push = self.prompt_force_push()?;
+        let (profile, owner) = match self.session_manager.active_profile() {
+            Some((name, profile)) => (Some(name.to_string()), profile.default_owner.clone()),
+            None => (None, None),
+        };
 
         let choices = UserChoices {
             repository,
             branch,
             author_mode,
+            year_authors,
             years,
             hour,
             github_username,
-            github_token,
+            github_token: Secret::new(github_token),
             force_push,
+            profile,
+            owner,
         };
 
         // Learn from user choices for future sessions
-        self.learn_from_choices(&choices)?;
+        self.learn_from_choices(&choices, &suggestions)?;
 
         // Display summary and confirm
         self.display_summary_and_confirm(&choices)?;
@@ -91,11 +163,11 @@ impl InteractivePrompts {
     /// Display welcome message with context information
     fn display_welcome(&self, defaults: &IntelligentDefaults) -> Result<()> {
         println!("{}", "🕰️  Git Time Traveler".bright_blue().bold());
-        println!("{}", "Travel back in time on your GitHub profile with ease!\n".cyan());
+        println!("{}\n", t!("interactive.tagline").cyan());
 
         // Show intelligent context if available
         if defaults.confidence > 0.6 {
-            println!("{}", "Smart suggestions based on your context:".green());
+            println!("{}", t!("interactive.smart_suggestions").green());
             for reason in &defaults.reasoning {
                 println!("  • {}", reason.dimmed());
             }
@@ -105,14 +177,71 @@ impl InteractivePrompts {
         Ok(())
     }
 
+    /// Prompt for the profile to use, `None` meaning the shared preferences
+    fn prompt_profile(&self) -> Result<Option<String>> {
+        let names = self.session_manager.profile_names();
+        let mut labels: Vec<String> = names.iter().map(|name| {
+            match self.session_manager.profile(name).and_then(|profile| profile.github_username.as_ref()) {
+                Some(username) => format!("{} ({})", name, username.bright_cyan()),
+                None => name.to_string(),
+            }
+        }).collect();
+        labels.push(t!("interactive.profile_none"));
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(t!("interactive.profile_prompt"))
+            .items(&labels)
+            .default(0)
+            .interact()
+            .context("Failed to get profile selection")?;
+
+        Ok(names.get(selection).map(|name| name.to_string()))
+    }
+
+    /// Offer an engine suggestion as the first choice of a prompt, returning whether it was taken
+    fn offer_suggestion(&self, prompt: String, suggestion: &IntelligentSuggestion, accept: String, decline: String) -> Result<bool> {
+        println!("\n💡 {} {}", suggestion.reasoning.dimmed(), confidence_indicator(suggestion.confidence));
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(prompt)
+            .items(&[accept, decline])
+            .default(0)
+            .interact()
+            .context("Failed to get suggestion selection")?;
+
+        Ok(selection == 0)
+    }
+
     /// Prompt for repository name with smart defaults
-    fn prompt_repository(&self, defaults: &IntelligentDefaults) -> Result<String> {
-        let prompt_text = "Repository name";
+    fn prompt_repository(&self, defaults: &IntelligentDefaults, suggestions: &[IntelligentSuggestion]) -> Result<String> {
+        let prompt_text = t!("interactive.repository");
+
+        let naming = suggestions.iter().find_map(|suggestion| match suggestion.suggestion_type {
+            SuggestionType::RepositoryNaming(ref prefix) => Some((suggestion, prefix)),
+            _ => None,
+        });
+        if let Some((suggestion, prefix)) = naming {
+            let accept = t!("interactive.suggestion_repository", prefix = prefix.bright_green());
+            if self.offer_suggestion(prompt_text.clone(), suggestion, accept, t!("interactive.suggestion_other_repository"))? {
+                let input: String = Input::with_theme(&self.theme)
+                    .with_prompt(prompt_text)
+                    .with_initial_text(prefix.clone())
+                    .validate_with(|input: &String| -> Result<(), String> {
+                        match self.validate_repository_name(input) {
+                            ValidationResult::Valid => Ok(()),
+                            ValidationResult::Invalid(msg) => Err(msg),
+                        }
+                    })
+                    .interact_text()
+                    .context("Failed to get repository input")?;
+                return Ok(input);
+            }
+        }
         
         match &defaults.repository {
             Some(suggested_repo) => {
                 let input: String = Input::with_theme(&self.theme)
-                    .with_prompt(format!("{} (suggested: {})", prompt_text, suggested_repo.bright_green()))
+                    .with_prompt(t!("interactive.suggested", prompt = prompt_text, suggestion = suggested_repo.bright_green()))
                     .default(suggested_repo.clone())
                     .validate_with(|input: &String| -> Result<(), String> {
                         match self.validate_repository_name(input) {
@@ -141,9 +270,20 @@ impl InteractivePrompts {
     }
 
     /// Prompt for branch name with smart defaults
-    fn prompt_branch(&self, defaults: &IntelligentDefaults) -> Result<String> {
+    fn prompt_branch(&self, defaults: &IntelligentDefaults, suggestions: &[IntelligentSuggestion]) -> Result<String> {
+        let suggested = suggestions.iter().find_map(|suggestion| match suggestion.suggestion_type {
+            SuggestionType::BranchName(ref branch) => Some((suggestion, branch)),
+            _ => None,
+        });
+        if let Some((suggestion, branch)) = suggested {
+            let accept = t!("interactive.suggestion_branch", branch = branch.bright_green());
+            if self.offer_suggestion(t!("interactive.branch"), suggestion, accept, t!("interactive.suggestion_other_branch"))? {
+                return Ok(branch.clone());
+            }
+        }
+
         let input: String = Input::with_theme(&self.theme)
-            .with_prompt(format!("Branch name (suggested: {})", defaults.branch.bright_green()))
+            .with_prompt(t!("interactive.suggested", prompt = t!("interactive.branch"), suggestion = defaults.branch.bright_green()))
             .default(defaults.branch.clone())
             .validate_with(|input: &String| -> Result<(), String> {
                 match self.validate_branch_name(input) {
@@ -158,40 +298,33 @@ impl InteractivePrompts {
 
     /// Prompt for author mode with smart defaults
     fn prompt_author_mode(&self, defaults: &IntelligentDefaults) -> Result<AuthorMode> {
+        // Options are matched by position, so their text can be translated
         let options = match &defaults.author_mode {
-            AuthorMode::CurrentUser(identity) => {
-                vec![
-                    format!("Use your Git identity: {} <{}>", identity.name.bright_cyan(), identity.email.bright_cyan()),
-                    "Use generic time traveler identity".to_string(),
-                    "Specify custom author details".to_string(),
-                    "Ask me each time".to_string(),
-                ]
+            AuthorMode::CurrentUser(_) => {
+                vec![AuthorOption::Suggested, AuthorOption::TimeTraveler, AuthorOption::Custom, AuthorOption::AskEachTime]
             }
             AuthorMode::TimeTraveler => {
-                vec![
-                    "Use generic time traveler identity".to_string(),
-                    "Use your Git identity (if available)".to_string(),
-                    "Specify custom author details".to_string(),
-                    "Ask me each time".to_string(),
-                ]
+                vec![AuthorOption::TimeTraveler, AuthorOption::GitIdentity, AuthorOption::Custom, AuthorOption::AskEachTime]
             }
-            AuthorMode::Manual(identity) => {
-                vec![
-                    format!("Use custom identity: {} <{}>", identity.name.bright_cyan(), identity.email.bright_cyan()),
-                    "Use generic time traveler identity".to_string(),
-                    "Use your Git identity (if available)".to_string(),
-                    "Ask me each time".to_string(),
-                ]
+            AuthorMode::Manual(_) => {
+                vec![AuthorOption::Suggested, AuthorOption::TimeTraveler, AuthorOption::GitIdentity, AuthorOption::AskEachTime]
             }
             AuthorMode::AskEachTime => {
-                vec![
-                    "Ask me each time".to_string(),
-                    "Use generic time traveler identity".to_string(),
-                    "Use your Git identity (if available)".to_string(),
-                    "Specify custom author details".to_string(),
-                ]
+                vec![AuthorOption::AskEachTime, AuthorOption::TimeTraveler, AuthorOption::GitIdentity, AuthorOption::Custom]
             }
         };
+        let labels: Vec<String> = options.iter().map(|option| match (option, &defaults.author_mode) {
+            (AuthorOption::Suggested, AuthorMode::CurrentUser(identity)) => {
+                t!("interactive.author_git_identity", name = identity.name.bright_cyan(), email = identity.email.bright_cyan())
+            }
+            (AuthorOption::Suggested, AuthorMode::Manual(identity)) => {
+                t!("interactive.author_custom_identity", name = identity.name.bright_cyan(), email = identity.email.bright_cyan())
+            }
+            (AuthorOption::Suggested, _) | (AuthorOption::GitIdentity, _) => t!("interactive.author_git_identity_if_available"),
+            (AuthorOption::TimeTraveler, _) => t!("interactive.author_time_traveler"),
+            (AuthorOption::Custom, _) => t!("interactive.author_specify_custom"),
+            (AuthorOption::AskEachTime, _) => t!("interactive.author_ask_each_time"),
+        }).collect();
 
         let default_index = match &defaults.author_mode {
             AuthorMode::CurrentUser(_) => 0,
@@ -201,21 +334,21 @@ impl InteractivePrompts {
         };
 
         let selection = Select::with_theme(&self.theme)
-            .with_prompt("How should commits be authored?")
-            .items(&options)
+            .with_prompt(t!("interactive.author_prompt"))
+            .items(&labels)
             .default(default_index)
             .interact()
             .context("Failed to get author mode selection")?;
 
-        let result = match (&defaults.author_mode, selection) {
-            (AuthorMode::CurrentUser(identity), 0) => AuthorMode::CurrentUser(identity.clone()),
-            (AuthorMode::Manual(identity), 0) => AuthorMode::Manual(identity.clone()),
-            (_, sel) if options[sel].contains("time traveler") => AuthorMode::TimeTraveler,
-            (_, sel) if options[sel].contains("custom author") || options[sel].contains("Specify custom") => {
+        let result = match (&defaults.author_mode, options[selection]) {
+            (AuthorMode::CurrentUser(identity), AuthorOption::Suggested) => AuthorMode::CurrentUser(identity.clone()),
+            (AuthorMode::Manual(identity), AuthorOption::Suggested) => AuthorMode::Manual(identity.clone()),
+            (_, AuthorOption::TimeTraveler) => AuthorMode::TimeTraveler,
+            (_, AuthorOption::Custom) => {
                 // Prompt for custom author details
                 self.prompt_manual_author_details()?
             }
-            (_, sel) if options[sel].contains("Ask me") => AuthorMode::AskEachTime,
+            (_, AuthorOption::AskEachTime) => AuthorMode::AskEachTime,
             (AuthorMode::CurrentUser(identity), _) => AuthorMode::CurrentUser(identity.clone()),
             (AuthorMode::Manual(identity), _) => AuthorMode::Manual(identity.clone()),
             _ => AuthorMode::TimeTraveler,
@@ -226,17 +359,17 @@ impl InteractivePrompts {
 
     /// Prompt for manual author details
     fn prompt_manual_author_details(&self) -> Result<AuthorMode> {
-        println!("\n{}", "📝 Custom Author Details".bright_blue().bold());
-        println!("Specify the name and email for commit authorship:");
+        println!("\n📝 {}", t!("interactive.custom_author_title").bright_blue().bold());
+        println!("{}", t!("interactive.custom_author_intro"));
 
         let name: String = Input::with_theme(&self.theme)
-            .with_prompt("Author name")
+            .with_prompt(t!("interactive.author_name"))
             .validate_with(|input: &String| -> Result<(), String> {
                 let trimmed = input.trim();
                 if trimmed.is_empty() {
-                    Err("Author name cannot be empty".to_string())
+                    Err(t!("validation.author_name_empty"))
                 } else if trimmed.len() > 100 {
-                    Err("Author name too long (max 100 characters)".to_string())
+                    Err(t!("validation.author_name_too_long"))
                 } else {
                     Ok(())
                 }
@@ -245,7 +378,7 @@ impl InteractivePrompts {
             .context("Failed to get author name")?;
 
         let email: String = Input::with_theme(&self.theme)
-            .with_prompt("Author email")
+            .with_prompt(t!("interactive.author_email"))
             .validate_with(|input: &String| -> Result<(), String> {
                 match self.validate_email(input) {
                     ValidationResult::Valid => Ok(()),
@@ -263,8 +396,78 @@ impl InteractivePrompts {
         Ok(AuthorMode::Manual(identity))
     }
 
+    /// Pick an author per year. Years the author pool covers use its identity,
+    /// and with "Ask me each time" every other year is prompted for.
+    fn prompt_year_authors(&self, defaults: &IntelligentDefaults, author_mode: &AuthorMode, years: &[u32]) -> Result<BTreeMap<u32, AuthorMode>> {
+        // Interactive commits are made on January 1st
+        let pool_author = |year: u32| self.author_pool.as_ref().and_then(|pool| pool.author_for(year, 1, 1));
+        let ask = *author_mode == AuthorMode::AskEachTime;
+        let mut year_authors = BTreeMap::new();
+        if !ask && !years.iter().any(|&year| pool_author(year).is_some()) {
+            return Ok(year_authors);
+        }
+
+        println!("\n👥 {}", t!("interactive.year_authors_title").bright_blue().bold());
+
+        // Custom identities entered for one year are offered for the following ones
+        let mut known = Vec::new();
+        if let AuthorMode::CurrentUser(_) | AuthorMode::Manual(_) = defaults.author_mode {
+            known.push(defaults.author_mode.clone());
+        }
+        known.push(AuthorMode::TimeTraveler);
+        let mut previous = 0;
+
+        for &year in years {
+            if let Some(identity) = pool_author(year) {
+                println!("  {} {}", "✓".green(), t!("interactive.author_from_pool",
+                    year = year, name = identity.name.bright_cyan(), email = identity.email.bright_cyan()));
+                year_authors.insert(year, AuthorMode::Manual(identity.clone()));
+                continue;
+            }
+            if !ask {
+                continue;
+            }
+
+            let mut labels: Vec<String> = known.iter().map(author_display).collect();
+            labels.push(t!("interactive.author_specify_custom"));
+            let selection = Select::with_theme(&self.theme)
+                .with_prompt(t!("interactive.author_for_year", year = year))
+                .items(&labels)
+                .default(previous)
+                .interact()
+                .context("Failed to get author selection")?;
+
+            previous = if selection == known.len() {
+                let mode = self.prompt_manual_author_details()?;
+                match known.iter().position(|known_mode| *known_mode == mode) {
+                    Some(index) => index,
+                    None => {
+                        known.push(mode);
+                        known.len() - 1
+                    }
+                }
+            } else {
+                selection
+            };
+            year_authors.insert(year, known[previous].clone());
+        }
+
+        Ok(year_authors)
+    }
+
     /// Prompt for years with smart defaults and validation
-    fn prompt_years(&self, defaults: &IntelligentDefaults) -> Result<Vec<u32>> {
+    fn prompt_years(&self, defaults: &IntelligentDefaults, suggestions: &[IntelligentSuggestion]) -> Result<Vec<u32>> {
+        let next_year = suggestions.iter().find_map(|suggestion| match suggestion.suggestion_type {
+            SuggestionType::NextYear(year) => Some((suggestion, year)),
+            _ => None,
+        });
+        if let Some((suggestion, year)) = next_year {
+            let accept = t!("interactive.suggestion_year", year = year.to_string().bright_green());
+            if self.offer_suggestion(t!("interactive.years"), suggestion, accept, t!("interactive.suggestion_other_years"))? {
+                return Ok(vec![year]);
+            }
+        }
+
         let suggested_years = if defaults.suggested_years.len() == 1 {
             defaults.suggested_years[0].to_string()
         } else {
@@ -274,13 +477,13 @@ impl InteractivePrompts {
             )
         };
 
-        println!("\n{}", "Year selection options:".bright_yellow());
-        println!("  • Single year: {}", "1990".bright_green());
-        println!("  • Year range: {}", "1990-1995".bright_green());
-        println!("  • Multiple years: {}", "1990,1992,1994".bright_green());
+        println!("\n{}", t!("interactive.year_options").bright_yellow());
+        println!("  • {}", t!("interactive.year_single", example = "1990".bright_green()));
+        println!("  • {}", t!("interactive.year_range", example = "1990-1995".bright_green()));
+        println!("  • {}", t!("interactive.year_multiple", example = "1990,1992,1994".bright_green()));
 
         let input: String = Input::with_theme(&self.theme)
-            .with_prompt(format!("Years for time travel (suggested: {})", suggested_years.bright_green()))
+            .with_prompt(t!("interactive.suggested", prompt = t!("interactive.years"), suggestion = suggested_years.bright_green()))
             .default(suggested_years)
             .validate_with(|input: &String| -> Result<(), String> {
                 match self.validate_years_input(input) {
@@ -295,10 +498,20 @@ impl InteractivePrompts {
     }
 
     /// Prompt for hour with smart defaults
-    fn prompt_hour(&self, defaults: &IntelligentDefaults) -> Result<u32> {
+    fn prompt_hour(&self, defaults: &IntelligentDefaults, suggestions: &[IntelligentSuggestion]) -> Result<u32> {
+        let time_of_day = suggestions.iter().find_map(|suggestion| match suggestion.suggestion_type {
+            SuggestionType::TimeOfDay(hour) => Some((suggestion, hour)),
+            _ => None,
+        });
+        if let Some((suggestion, hour)) = time_of_day {
+            let accept = t!("interactive.suggestion_hour", hour = format!("{:02}:00", hour).bright_green());
+            if self.offer_suggestion(t!("interactive.hour_title"), suggestion, accept, t!("interactive.suggestion_other_hour"))? {
+                return Ok(hour);
+            }
+        }
+
         let input: String = Input::with_theme(&self.theme)
-            .with_prompt(format!("Hour for commits (0-23, suggested: {})", 
-                format!("{}:00", defaults.suggested_hour).bright_green()))
+            .with_prompt(t!("interactive.hour", suggestion = format!("{}:00", defaults.suggested_hour).bright_green()))
             .default(defaults.suggested_hour.to_string())
             .validate_with(|input: &String| -> Result<(), String> {
                 match self.validate_hour(input) {
@@ -314,12 +527,12 @@ impl InteractivePrompts {
 
     /// Prompt for GitHub username with smart defaults
     fn prompt_github_username(&self, defaults: &IntelligentDefaults) -> Result<String> {
-        let prompt_text = "GitHub username";
+        let prompt_text = t!("interactive.github_username");
         
         match &defaults.github_username {
             Some(suggested_username) => {
                 let input: String = Input::with_theme(&self.theme)
-                    .with_prompt(format!("{} (suggested: {})", prompt_text, suggested_username.bright_green()))
+                    .with_prompt(t!("interactive.suggested", prompt = prompt_text, suggestion = suggested_username.bright_green()))
                     .default(suggested_username.clone())
                     .validate_with(|input: &String| -> Result<(), String> {
                         match self.validate_github_username(input) {
@@ -347,14 +560,26 @@ impl InteractivePrompts {
         }
     }
 
-    /// Prompt for GitHub token (always secure input)
+    /// Prompt for GitHub token (secure input), unless the active profile provides one
     fn prompt_github_token(&self) -> Result<String> {
-        println!("\n{}", "GitHub Personal Access Token:".bright_yellow());
-        println!("  • Create at: {}", "https://github.com/settings/tokens".bright_blue().underline());
-        println!("  • Required permissions: {}", "repo (full control)".bright_green());
+        if let Some((name, profile)) = self.session_manager.active_profile() {
+            if let TokenSource::Env(ref variable) = profile.token_source {
+                match profile.token_source.resolve() {
+                    Some(token) => {
+                        println!("\n{}", t!("interactive.token_from_profile", variable = variable.bright_green(), profile = name));
+                        return Ok(token);
+                    }
+                    None => println!("\n{}", t!("interactive.token_variable_unset", variable = variable.bright_yellow(), profile = name)),
+                }
+            }
+        }
+
+        println!("\n{}", t!("interactive.token_title").bright_yellow());
+        println!("  • {}", t!("interactive.token_create_at", url = "https://github.com/settings/tokens".bright_blue().underline()));
+        println!("  • {}", t!("interactive.token_permissions", scope = t!("interactive.token_scope").bright_green()));
 
         let token: String = Password::with_theme(&self.theme)
-            .with_prompt("GitHub token (input hidden)")
+            .with_prompt(t!("interactive.token_prompt"))
             .validate_with(|input: &String| -> Result<(), String> {
                 match self.validate_github_token(input) {
                     ValidationResult::Valid => Ok(()),
@@ -369,11 +594,11 @@ impl InteractivePrompts {
 
     /// Prompt for force push option
     fn prompt_force_push(&self) -> Result<bool> {
-        println!("\n{}", "⚠️  Force Push Warning:".bright_red().bold());
-        println!("Force push will overwrite remote history. Use with caution!");
+        println!("\n⚠️  {}", t!("interactive.force_push_title").bright_red().bold());
+        println!("{}", t!("interactive.force_push_warning"));
 
         let force = Confirm::with_theme(&self.theme)
-            .with_prompt("Enable force push?")
+            .with_prompt(t!("interactive.force_push_prompt"))
             .default(false)
             .interact()
             .context("Failed to get force push confirmation")?;
@@ -383,47 +608,66 @@ impl InteractivePrompts {
 
     /// Display summary and get final confirmation
     fn display_summary_and_confirm(&self, choices: &UserChoices) -> Result<()> {
-        println!("\n{}", "📋 Summary:".bright_blue().bold());
-        println!("  Repository: {}", choices.repository.bright_green());
-        println!("  Branch: {}", choices.branch.bright_cyan());
-        
-        let author_display = match &choices.author_mode {
-            AuthorMode::CurrentUser(identity) => format!("Your identity: {} <{}>", identity.name, identity.email),
-            AuthorMode::TimeTraveler => "Time Traveler".to_string(),
-            AuthorMode::Manual(identity) => format!("Custom identity: {} <{}>", identity.name, identity.email),
-            AuthorMode::AskEachTime => "Ask each time".to_string(),
+        println!("\n📋 {}", t!("interactive.summary_title").bright_blue().bold());
+        if let Some(ref profile) = choices.profile {
+            println!("  {}", t!("interactive.summary_profile", value = profile.bright_cyan()));
+        }
+        let repository = match choices.owner {
+            Some(ref owner) => format!("{}/{}", owner, choices.repository),
+            None => choices.repository.clone(),
         };
-        println!("  Author: {}", author_display.bright_yellow());
+        println!("  {}", t!("interactive.summary_repository", value = repository.bright_green()));
+        println!("  {}", t!("interactive.summary_branch", value = choices.branch.bright_cyan()));
+        
+        println!("  {}", t!("interactive.summary_author", value = author_display(&choices.author_mode).bright_yellow()));
+        for (year, mode) in &choices.year_authors {
+            println!("    {}", t!("interactive.summary_year_author", year = year, value = author_display(mode).bright_yellow()));
+        }
         
         let years_display = if choices.years.len() == 1 {
             choices.years[0].to_string()
         } else {
-            format!("{} years ({}-{})", 
-                choices.years.len(),
-                choices.years.iter().min().unwrap(),
-                choices.years.iter().max().unwrap()
+            t!("interactive.summary_year_range",
+                count = choices.years.len(),
+                start = choices.years.iter().min().unwrap(),
+                end = choices.years.iter().max().unwrap()
             )
         };
-        println!("  Years: {}", years_display.bright_magenta());
-        println!("  Time: {}:00", format!("{:02}", choices.hour).bright_white());
-        println!("  Username: {}", choices.github_username.bright_green());
-        println!("  Force push: {}", if choices.force_push { "Yes".red() } else { "No".green() });
+        println!("  {}", t!("interactive.summary_years", value = years_display.bright_magenta()));
+        println!("  {}", t!("interactive.summary_time", value = format!("{:02}:00", choices.hour).bright_white()));
+        println!("  {}", t!("interactive.summary_username", value = choices.github_username.bright_green()));
+        let force_push = if choices.force_push { t!("common.yes").red() } else { t!("common.no").green() };
+        println!("  {}", t!("interactive.summary_force_push", value = force_push));
 
         let proceed = Confirm::with_theme(&self.theme)
-            .with_prompt("\nProceed with time travel?")
+            .with_prompt(format!("\n{}", t!("interactive.proceed_prompt")))
             .default(true)
             .interact()
             .context("Failed to get final confirmation")?;
 
         if !proceed {
-            anyhow::bail!("Operation cancelled by user");
+            anyhow::bail!(t!("common.cancelled_by_user"));
         }
 
         Ok(())
     }
 
     /// Learn from user choices for future sessions
-    fn learn_from_choices(&mut self, choices: &UserChoices) -> Result<()> {
+    fn learn_from_choices(&mut self, choices: &UserChoices, suggestions: &[IntelligentSuggestion]) -> Result<()> {
+        // Learn which suggestions were taken, so they are offered more or less confidently
+        for suggestion in offered_suggestions(suggestions) {
+            let accepted = match suggestion.suggestion_type {
+                SuggestionType::NextYear(year) => choices.years.contains(&year),
+                SuggestionType::RepositoryNaming(ref prefix) => choices.repository.starts_with(prefix.as_str()),
+                SuggestionType::BranchName(ref branch) => choices.branch == *branch,
+                SuggestionType::TimeOfDay(hour) => choices.hour == hour,
+                // Not offered in any prompt
+                SuggestionType::AuthorMode(_) => continue,
+            };
+            let choice_type = if accepted { "suggestion_accepted" } else { "suggestion_rejected" };
+            self.session_manager.learn_from_choice(choice_type, suggestion.suggestion_type.kind());
+        }
+
         // Learn repository preference
         self.session_manager.learn_from_choice("repository", &choices.repository);
 
@@ -459,13 +703,13 @@ impl InteractivePrompts {
     fn validate_repository_name(&self, input: &str) -> ValidationResult {
         let trimmed = input.trim();
         if trimmed.is_empty() {
-            return ValidationResult::Invalid("Repository name cannot be empty".to_string());
+            return ValidationResult::Invalid(t!("validation.repository_empty"));
         }
         if trimmed.len() > 100 {
-            return ValidationResult::Invalid("Repository name too long (max 100 characters)".to_string());
+            return ValidationResult::Invalid(t!("validation.repository_too_long"));
         }
         if !trimmed.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.') {
-            return ValidationResult::Invalid("Repository name can only contain letters, numbers, hyphens, underscores, and dots".to_string());
+            return ValidationResult::Invalid(t!("validation.repository_characters"));
         }
         ValidationResult::Valid
     }
@@ -473,13 +717,13 @@ impl InteractivePrompts {
     fn validate_branch_name(&self, input: &str) -> ValidationResult {
         let trimmed = input.trim();
         if trimmed.is_empty() {
-            return ValidationResult::Invalid("Branch name cannot be empty".to_string());
+            return ValidationResult::Invalid(t!("validation.branch_empty"));
         }
         if trimmed.starts_with('-') || trimmed.ends_with('-') {
-            return ValidationResult::Invalid("Branch name cannot start or end with a hyphen".to_string());
+            return ValidationResult::Invalid(t!("validation.branch_hyphen"));
         }
         if trimmed.contains("..") || trimmed.contains(' ') {
-            return ValidationResult::Invalid("Branch name cannot contain spaces or consecutive dots".to_string());
+            return ValidationResult::Invalid(t!("validation.branch_characters"));
         }
         ValidationResult::Valid
     }
@@ -488,9 +732,9 @@ impl InteractivePrompts {
         match self.parse_years_input(input) {
             Ok(years) => {
                 if years.is_empty() {
-                    ValidationResult::Invalid("At least one year must be specified".to_string())
+                    ValidationResult::Invalid(t!("validation.years_empty"))
                 } else if years.len() > 50 {
-                    ValidationResult::Invalid("Too many years specified (max 50)".to_string())
+                    ValidationResult::Invalid(t!("validation.years_too_many"))
                 } else {
                     ValidationResult::Valid
                 }
@@ -502,24 +746,24 @@ impl InteractivePrompts {
     fn validate_hour(&self, input: &str) -> ValidationResult {
         match input.trim().parse::<u32>() {
             Ok(hour) if hour <= 23 => ValidationResult::Valid,
-            Ok(_) => ValidationResult::Invalid("Hour must be between 0 and 23".to_string()),
-            Err(_) => ValidationResult::Invalid("Please enter a valid number".to_string()),
+            Ok(_) => ValidationResult::Invalid(t!("validation.hour_range")),
+            Err(_) => ValidationResult::Invalid(t!("validation.not_a_number")),
         }
     }
 
     fn validate_github_username(&self, input: &str) -> ValidationResult {
         let trimmed = input.trim();
         if trimmed.is_empty() {
-            return ValidationResult::Invalid("GitHub username cannot be empty".to_string());
+            return ValidationResult::Invalid(t!("validation.username_empty"));
         }
         if trimmed.len() > 39 {
-            return ValidationResult::Invalid("GitHub username too long (max 39 characters)".to_string());
+            return ValidationResult::Invalid(t!("validation.username_too_long"));
         }
         if !trimmed.chars().all(|c| c.is_alphanumeric() || c == '-') {
-            return ValidationResult::Invalid("GitHub username can only contain letters, numbers, and hyphens".to_string());
+            return ValidationResult::Invalid(t!("validation.username_characters"));
         }
         if trimmed.starts_with('-') || trimmed.ends_with('-') {
-            return ValidationResult::Invalid("GitHub username cannot start or end with a hyphen".to_string());
+            return ValidationResult::Invalid(t!("validation.username_hyphen"));
         }
         ValidationResult::Valid
     }
@@ -527,10 +771,10 @@ impl InteractivePrompts {
     fn validate_github_token(&self, input: &str) -> ValidationResult {
         let trimmed = input.trim();
         if trimmed.is_empty() {
-            return ValidationResult::Invalid("GitHub token cannot be empty".to_string());
+            return ValidationResult::Invalid(t!("validation.token_empty"));
         }
         if trimmed.len() < 20 {
-            return ValidationResult::Invalid("GitHub token seems too short".to_string());
+            return ValidationResult::Invalid(t!("validation.token_too_short"));
         }
         ValidationResult::Valid
     }
@@ -538,29 +782,29 @@ impl InteractivePrompts {
     fn validate_email(&self, input: &str) -> ValidationResult {
         let trimmed = input.trim();
         if trimmed.is_empty() {
-            return ValidationResult::Invalid("Email cannot be empty".to_string());
+            return ValidationResult::Invalid(t!("validation.email_empty"));
         }
         
         // Basic email validation - contains @ and has parts before and after
         if !trimmed.contains('@') {
-            return ValidationResult::Invalid("Email must contain @ symbol".to_string());
+            return ValidationResult::Invalid(t!("validation.email_missing_at"));
         }
         
         let parts: Vec<&str> = trimmed.split('@').collect();
         if parts.len() != 2 {
-            return ValidationResult::Invalid("Email must have exactly one @ symbol".to_string());
+            return ValidationResult::Invalid(t!("validation.email_multiple_at"));
         }
         
         if parts[0].is_empty() || parts[1].is_empty() {
-            return ValidationResult::Invalid("Email must have content before and after @".to_string());
+            return ValidationResult::Invalid(t!("validation.email_parts"));
         }
         
         if !parts[1].contains('.') {
-            return ValidationResult::Invalid("Email domain must contain a dot".to_string());
+            return ValidationResult::Invalid(t!("validation.email_domain"));
         }
         
         if trimmed.len() > 254 {
-            return ValidationResult::Invalid("Email too long (max 254 characters)".to_string());
+            return ValidationResult::Invalid(t!("validation.email_too_long"));
         }
         
         ValidationResult::Valid
@@ -575,18 +819,18 @@ impl InteractivePrompts {
             let parts: Vec<&str> = trimmed.split('-').collect();
             if parts.len() == 2 {
                 let start = parts[0].trim().parse::<u32>()
-                    .context("Invalid start year in range")?;
+                    .with_context(|| t!("validation.invalid_start_year"))?;
                 let end = parts[1].trim().parse::<u32>()
-                    .context("Invalid end year in range")?;
+                    .with_context(|| t!("validation.invalid_end_year"))?;
                 
                 if start > end {
-                    anyhow::bail!("Start year must be less than or equal to end year");
+                    anyhow::bail!(t!("validation.year_range_order"));
                 }
                 if end - start > 50 {
-                    anyhow::bail!("Year range too large (max 50 years)");
+                    anyhow::bail!(t!("validation.year_range_too_large"));
                 }
                 if start < 1970 || end > 2030 {
-                    anyhow::bail!("Years must be between 1970 and 2030");
+                    anyhow::bail!(t!("validation.years_out_of_range"));
                 }
                 
                 return Ok((start..=end).collect());
@@ -599,9 +843,9 @@ impl InteractivePrompts {
                 .split(',')
                 .map(|s| {
                     let year = s.trim().parse::<u32>()
-                        .context("Invalid year in list")?;
+                        .with_context(|| t!("validation.invalid_year_in_list"))?;
                     if year < 1970 || year > 2030 {
-                        anyhow::bail!("Year {} must be between 1970 and 2030", year);
+                        anyhow::bail!(t!("validation.year_out_of_range", year = year));
                     }
                     Ok(year)
                 })
@@ -611,9 +855,9 @@ impl InteractivePrompts {
         
         // Handle single year
         let year = trimmed.parse::<u32>()
-            .context("Invalid year format")?;
+            .with_context(|| t!("validation.invalid_year"))?;
         if year < 1970 || year > 2030 {
-            anyhow::bail!("Year must be between 1970 and 2030");
+            anyhow::bail!(t!("validation.single_year_out_of_range"));
         }
         
         Ok(vec![year])
@@ -622,6 +866,37 @@ impl InteractivePrompts {
 
 
 
+/// Describe an author mode, as in the summary
+fn author_display(mode: &AuthorMode) -> String {
+    match mode {
+        AuthorMode::CurrentUser(identity) => t!("interactive.summary_author_yours", name = identity.name, email = identity.email),
+        AuthorMode::TimeTraveler => t!("interactive.summary_author_time_traveler"),
+        AuthorMode::Manual(identity) => t!("interactive.summary_author_custom", name = identity.name, email = identity.email),
+        AuthorMode::AskEachTime => t!("interactive.summary_author_ask"),
+    }
+}
+
+/// The suggestions the prompts show: only the first of each kind, later ones are never seen
+fn offered_suggestions(suggestions: &[IntelligentSuggestion]) -> impl Iterator<Item = &IntelligentSuggestion> {
+    let mut kinds = BTreeSet::new();
+    suggestions.iter().filter(move |suggestion| kinds.insert(suggestion.suggestion_type.kind()))
+}
+
+/// Five dots filled by confidence, colored from red to green, followed by the percentage
+fn confidence_indicator(confidence: f32) -> String {
+    let confidence = confidence.clamp(0.0, 1.0);
+    let filled = (confidence * 5.0).round() as usize;
+    let dots = format!("{}{}", "●".repeat(filled), "○".repeat(5 - filled));
+    let dots = if confidence >= 0.8 {
+        dots.green()
+    } else if confidence >= 0.6 {
+        dots.yellow()
+    } else {
+        dots.red()
+    };
+    t!("interactive.suggestion_confidence", dots = dots, percent = (confidence * 100.0).round() as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -764,6 +1039,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_confidence_indicator() {
+        let high = confidence_indicator(0.81);
+        assert!(high.contains("●●●●○"));
+        assert!(high.contains("81%"));
+        assert!(confidence_indicator(1.4).contains("●●●●●"));
+        assert!(confidence_indicator(0.0).contains("○○○○○"));
+    }
+
+    #[test]
+    fn test_offered_suggestions() {
+        let suggestion = |suggestion_type| IntelligentSuggestion { suggestion_type, confidence: 0.9, reasoning: String::new() };
+        let suggestions = [
+            suggestion(SuggestionType::NextYear(1993)),
+            suggestion(SuggestionType::TimeOfDay(18)),
+            suggestion(SuggestionType::NextYear(1995)),
+        ];
+        let offered: Vec<_> = offered_suggestions(&suggestions).map(|suggestion| &suggestion.suggestion_type).collect();
+        assert!(matches!(offered[..], [SuggestionType::NextYear(1993), SuggestionType::TimeOfDay(18)]));
+    }
+
+    #[test]
+    fn test_author_for_year() {
+        let jane = GitIdentity { name: "Jane".to_string(), email: "jane@example.com".to_string() };
+        let old = GitIdentity { name: "Jane".to_string(), email: "jane@old.example".to_string() };
+        let mut choices = UserChoices {
+            repository: "history".to_string(),
+            branch: "main".to_string(),
+            author_mode: AuthorMode::CurrentUser(jane.clone()),
+            year_authors: BTreeMap::from([(2001, AuthorMode::Manual(old.clone())), (2002, AuthorMode::TimeTraveler)]),
+            years: vec![2001, 2002, 2003],
+            hour: 12,
+            github_username: "jane".to_string(),
+            github_token: Secret::new("token"),
+            force_push: false,
+            profile: None,
+            owner: None,
+        };
+
+        assert_eq!(choices.author_for_year(2001), Some(old));
+        assert_eq!(choices.author_for_year(2002), None);
+        assert_eq!(choices.author_for_year(2003), Some(jane));
+
+        choices.author_mode = AuthorMode::AskEachTime;
+        assert_eq!(choices.author_for_year(2003), None);
+    }
+
     #[test]
     fn test_author_mode_variants() -> Result<()> {
         use crate::git_context::GitIdentity;
@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use chrono::DateTime;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub mod git_context;
 pub mod git_operations;
@@ -11,16 +11,57 @@ pub mod interactive;
 pub mod date_parser;
 pub mod errors;
 pub mod dry_run;
+pub mod schedule;
+pub mod author_pool;
+pub mod mirror;
+pub mod verify;
+pub mod calendar;
+pub mod cancel;
+pub mod logging;
+pub mod progress;
+pub mod secret;
+pub mod i18n;
 
 pub use git_context::{GitContext, GitContextDetector, GitIdentity, GitRemote};
-pub use git_operations::{GitOperations, TimeTravelCommitConfig, RepositoryConfig, GitCredentials, CommitResult, RepositoryResult};
-pub use github_client::{GitHubClient, CreateRepositoryRequest, Repository, User, Branch, TokenInfo};
-pub use session::{SessionManager, SessionData, SessionSuggestions, SessionStats, UserPreferences, RecentContext};
-pub use defaults::{DefaultsEngine, IntelligentDefaults, AuthorMode, ContextAnalysis, DetectedPattern};
+pub use git_operations::{GitOperations, TimeTravelCommitConfig, RepositoryConfig, GitCredentials, CommitResult, RepositoryResult, DateShift, HistoryShiftConfig, HistoryRewriteResult, ShiftedCommit, RedateConfig, RedateTarget};
+pub use github_client::{GitHubClient, CreateRepositoryRequest, CreatePullRequestRequest, UpdateRepositoryRequest, PullRequest, Repository, User, Branch, TokenInfo};
+pub use session::{SessionManager, SessionData, SessionFormat, SessionSuggestions, SessionStats, UserPreferences, RecentContext, Profile, TokenSource, SuggestionFeedback};
+pub use defaults::{DefaultsEngine, IntelligentDefaults, IntelligentSuggestion, SuggestionType, AuthorMode, ContextAnalysis, DetectedPattern};
 pub use interactive::{InteractivePrompts, UserChoices, ValidationResult};
-pub use date_parser::{DateInput, DateParser, TimestampConfig, generate_timestamps};
-pub use errors::{TimeTravelError, AuthError, RepoError, NetworkError, format_error_for_user};
-pub use dry_run::{DryRunExecutor, DryRunConfig, DryRunPlan, display_and_confirm_dry_run};
+pub use date_parser::{DateInput, DateParser, TimestampConfig, generate_timestamps, parse_offset};
+pub use errors::{TimeTravelError, AuthError, RepoError, NetworkError, format_error_for_user, debug_report, error_envelope, exit_code_for, exit_codes, ErrorFormat};
+pub use dry_run::{DryRunExecutor, DryRunConfig, DryRunPlan, display_and_confirm_dry_run, display_and_confirm_dry_run_with_pr};
+pub use schedule::{CommitSchedule, ScheduleEntry, ScheduleFormat};
+pub use author_pool::{resolve_author, AuthorPool, AuthorRange};
+pub use mirror::{MirrorConfig, MirrorKey, MirrorResult, MessageMode, SourceCommit};
+pub use calendar::{ContributionCalendar, PreviewFormat, YearGrid};
+pub use progress::{ProgressCallback, ProgressEvent, ProgressSink, SinkProgress};
+pub use cancel::{CancellationToken, is_cancellation, remove_tracked_temp_dirs};
+pub use secret::Secret;
+pub use i18n::{Locale, set_locale};
+pub use verify::{VerificationReport, VerificationTarget, DayVerification, ExpectedContribution};
+
+/// Description prefix of repositories created by the tool
+pub const REPOSITORY_MARKER: &str = "Time travel repository";
+
+/// Topic that marks a repository as created by the tool
+pub const REPOSITORY_TOPIC: &str = "git-timetraveler";
+
+/// Whether a repository was created by the tool, judged by its description or topics
+pub fn is_time_travel_repository(repository: &Repository) -> bool {
+    repository.description.as_deref().is_some_and(|description| {
+        description.starts_with(REPOSITORY_MARKER) || description.ends_with(&format!("({})", REPOSITORY_MARKER))
+    }) || repository.topics.iter().any(|topic| topic == REPOSITORY_TOPIC)
+}
+
+/// A custom description that still carries the marker, e.g. "Retro games (Time travel repository)"
+pub fn marked_description(description: &str) -> String {
+    if description.starts_with(REPOSITORY_MARKER) {
+        description.to_string()
+    } else {
+        format!("{} ({})", description, REPOSITORY_MARKER)
+    }
+}
 
 /// Configuration for creating a time-traveled repository
 #[derive(Debug, Clone)]
@@ -30,10 +71,14 @@ pub struct TimeTravelConfig {
     pub day: u32,
     pub hour: u32,
     pub username: String,
-    pub token: String,
+    pub token: Secret,
     pub repo_name: Option<String>,
     pub branch: String,
     pub author: Option<GitIdentity>,
+    /// Organization that owns the repository; the user's own account when unset
+    pub owner: Option<String>,
+    /// Interrupts the pipeline between steps and aborts transfers in flight
+    pub cancellation: CancellationToken,
 }
 
 impl TimeTravelConfig {
@@ -44,7 +89,7 @@ impl TimeTravelConfig {
         day: u32,
         hour: u32,
         username: String,
-        token: String,
+        token: impl Into<Secret>,
         repo_name: Option<String>,
         branch: String,
         author: Option<GitIdentity>,
@@ -62,7 +107,8 @@ impl TimeTravelConfig {
             .context("Invalid hour provided")?;
         let validated_username = validate_username(&username)
             .context("Invalid username provided")?;
-        let validated_token = validate_token(&token)
+        let token = token.into();
+        let validated_token = validate_token(token.expose())
             .context("Invalid token provided")?;
         
         // Validate repository name if provided
@@ -77,13 +123,53 @@ impl TimeTravelConfig {
             day: validated_day,
             hour: validated_hour,
             username: validated_username,
-            token: validated_token,
+            token: Secret::new(validated_token),
             repo_name,
             branch,
             author,
+            owner: None,
+            cancellation: CancellationToken::new(),
         })
     }
 
+    /// Stop the pipeline when `cancellation` is cancelled
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Target a repository owned by an organization
+    pub fn with_owner(mut self, owner: Option<String>) -> Result<Self> {
+        self.owner = match owner {
+            Some(owner) => Some(errors::validation::validate_username(&owner)
+                .context("Invalid owner provided")?),
+            None => None,
+        };
+        Ok(self)
+    }
+
+    /// Account that owns the repository (organization or user)
+    pub fn owner(&self) -> &str {
+        self.owner.as_deref().unwrap_or(&self.username)
+    }
+
+    /// Whether the repository belongs to an organization rather than the user
+    pub fn is_organization_owned(&self) -> bool {
+        self.owner.as_deref().is_some_and(|owner| !owner.eq_ignore_ascii_case(&self.username))
+    }
+
+    /// HTTPS clone URL of the repository
+    pub fn clone_url(&self) -> String {
+        format!("https://github.com/{}/{}.git", self.owner(), self.repo_name())
+    }
+
+    /// Create a GitHub client that targets the repository owner
+    pub fn github_client(&self) -> Result<GitHubClient> {
+        Ok(GitHubClient::new(self.username.clone(), self.token.clone())
+            .context("Failed to create GitHub client")?
+            .with_owner(self.owner.clone()))
+    }
+
     /// Get the repository name (custom or year)
     pub fn repo_name(&self) -> String {
         if let Some(ref name) = self.repo_name {
@@ -103,6 +189,14 @@ impl TimeTravelConfig {
         Ok(datetime.format("%Y-%m-%dT%H:%M:%S").to_string())
     }
 
+    /// Identity used for the backdated commits
+    pub fn commit_author(&self) -> GitIdentity {
+        self.author.clone().unwrap_or_else(|| GitIdentity {
+            name: "Git Time Traveler".to_string(),
+            email: "timetraveler@example.com".to_string(),
+        })
+    }
+
     /// Get the formatted date string for display
     pub fn formatted_date(&self) -> String {
         format!("{}-{:02}-{:02} at {:02}:00:00", 
@@ -110,11 +204,136 @@ impl TimeTravelConfig {
     }
 }
 
-/// Progress callback trait for reporting progress
-pub trait ProgressCallback {
-    fn set_message(&self, message: &str);
-    fn increment(&self);
-    fn finish(&self, message: &str);
+/// Options for delivering commits through a pull request instead of pushing
+/// to the target branch directly
+#[derive(Debug, Clone)]
+pub struct PullRequestOptions {
+    /// Branch the commits are pushed to
+    pub branch: String,
+    pub title: String,
+    pub body: String,
+    /// Enable auto-merge once the pull request is open
+    pub auto_merge: bool,
+}
+
+impl PullRequestOptions {
+    /// Generate a branch name, title and body summarizing the traveled years
+    pub fn for_years(years: &[u32], auto_merge: bool) -> Self {
+        let mut sorted = years.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        let span = match (sorted.first(), sorted.last()) {
+            (Some(first), Some(last)) if first != last => format!("{}-{}", first, last),
+            (Some(first), _) => first.to_string(),
+            _ => "commits".to_string(),
+        };
+        let stamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+        let year_list = sorted.iter().map(|y| format!("- {}", y)).collect::<Vec<_>>().join("\n");
+
+        Self {
+            branch: format!("timetravel/{}-{}", span, stamp),
+            title: if sorted.len() == 1 {
+                format!("Time travel commit for {}", span)
+            } else {
+                format!("Time travel commits for {} ({} years)", span, sorted.len())
+            },
+            body: format!(
+                "This pull request adds backdated commits created by Git Time Traveler.\n\n\
+                Years traveled:\n{}\n",
+                year_list
+            ),
+            auto_merge,
+        }
+    }
+}
+
+/// Repository settings applied once the commits have landed
+#[derive(Debug, Clone, Default)]
+pub struct RepositoryLifecycle {
+    pub topics: Vec<String>,
+    pub homepage: Option<String>,
+    pub description: Option<String>,
+    /// "public" or "private"
+    pub visibility: Option<String>,
+    /// Archive the repository as the last step
+    pub archive: bool,
+}
+
+impl RepositoryLifecycle {
+    /// Create lifecycle settings, validating topics and visibility
+    pub fn new(
+        topics: Vec<String>,
+        homepage: Option<String>,
+        description: Option<String>,
+        visibility: Option<String>,
+        archive: bool,
+    ) -> Result<Self> {
+        use errors::validation::validate_topic;
+
+        let topics = topics.iter()
+            .map(|topic| validate_topic(topic))
+            .collect::<std::result::Result<Vec<String>, _>>()
+            .context("Invalid topic provided")?;
+        // One of GitHub's 20 topics is kept for the marker topic
+        if topics.iter().filter(|topic| *topic != REPOSITORY_TOPIC).count() > 19 {
+            return Err(errors::TimeTravelError::invalid_input(
+                "topics",
+                &topics.join(","),
+                "too many topics (max 19)",
+                "GitHub allows at most 20 topics per repository, and one is the git-timetraveler marker"
+            ).into());
+        }
+
+        let visibility = match visibility.map(|v| v.trim().to_lowercase()) {
+            Some(v) if v == "public" || v == "private" => Some(v),
+            Some(v) => return Err(errors::TimeTravelError::invalid_input(
+                "visibility",
+                &v,
+                "must be 'public' or 'private'",
+                "Use --visibility public or --visibility private"
+            ).into()),
+            None => None,
+        };
+
+        Ok(Self { topics, homepage, description, visibility, archive })
+    }
+
+    /// Whether there is anything to apply
+    pub fn is_empty(&self) -> bool {
+        self.topics.is_empty()
+            && self.homepage.is_none()
+            && self.description.is_none()
+            && self.visibility.is_none()
+            && !self.archive
+    }
+
+    /// Topics to set: the requested ones plus the marker topic, so `repos list` still finds the repository
+    pub fn topics_with_marker(&self) -> Vec<String> {
+        let mut topics = self.topics.clone();
+        if !topics.iter().any(|topic| topic == REPOSITORY_TOPIC) {
+            topics.push(REPOSITORY_TOPIC.to_string());
+        }
+        topics
+    }
+
+    /// Human readable list of the settings changes (archiving excluded)
+    pub fn describe_changes(&self) -> Vec<String> {
+        let mut changes = Vec::new();
+        if !self.topics.is_empty() {
+            changes.push(format!("topics: {}", self.topics_with_marker().join(", ")));
+        }
+        if let Some(ref homepage) = self.homepage {
+            changes.push(format!("homepage: {}", homepage));
+        }
+        if let Some(ref description) = self.description {
+            changes.push(format!("description: {}", marked_description(description)));
+        }
+        if let Some(ref visibility) = self.visibility {
+            changes.push(format!("visibility: {}", visibility));
+        }
+        changes
+    }
 }
 
 /// Create a time-traveled repository and push it to GitHub
@@ -132,6 +351,21 @@ pub async fn create_time_traveled_repo_with_options(
     progress: Option<&dyn ProgressCallback>,
     force: bool,
     dry_run: bool,
+) -> Result<()> {
+    create_time_traveled_repo_on_branch(config, None, progress, force, dry_run).await
+}
+
+/// Create a time-traveled commit and push it to `push_branch` instead of the
+/// configured branch when one is given.
+///
+/// The push branch is cloned when it already exists on GitHub, so several
+/// years can be stacked on the same branch before a pull request is opened.
+pub async fn create_time_traveled_repo_on_branch(
+    config: &TimeTravelConfig,
+    push_branch: Option<&str>,
+    progress: Option<&dyn ProgressCallback>,
+    force: bool,
+    dry_run: bool,
 ) -> Result<()> {
     // Handle dry-run mode
     if dry_run {
@@ -141,6 +375,7 @@ pub async fn create_time_traveled_repo_with_options(
             show_risks: true,
             require_confirmation: false, // Don't require confirmation in dry-run
             interactive_confirmations: false,
+            show_heatmap: true,
         };
         
         let executor = dry_run::DryRunExecutor::new(dry_run_config);
@@ -153,77 +388,73 @@ pub async fn create_time_traveled_repo_with_options(
         return Ok(());
     }
 
-    let report_progress = |msg: &str| {
-        if let Some(p) = &progress {
-            p.set_message(msg);
-            p.increment();
-        }
-    };
+    let _active = config.cancellation.enter();
+    let cancellation = &config.cancellation;
+    let mut relay = progress::ProgressRelay::new(progress);
 
-    // Initialize GitHub client and Git operations
-    let github_client = GitHubClient::new(config.username.clone(), config.token.clone())
-        .context("Failed to create GitHub client")?;
-    let mut git_ops = GitOperations::new();
+    // Initialize GitHub client
+    let github_client = config.github_client()?;
 
-    report_progress("Validating GitHub token...");
+    cancellation.check("token validation")?;
     
     // Validate token and check permissions
     github_client.check_permissions().await
         .context("GitHub token validation failed")?;
-
-    report_progress("Checking repository existence...");
+    relay.emit(ProgressEvent::TokenValidated);
     
     // Check if repository exists, create if it doesn't
     let repo_exists = github_client.repository_exists(&config.repo_name()).await
         .context("Failed to check repository existence")?;
+    github_client.check_organization_access(!repo_exists).await
+        .context("Cannot use the organization as repository owner")?;
+    relay.emit(ProgressEvent::RepoChecked { repository: config.repo_name(), exists: repo_exists });
 
     if !repo_exists {
-        report_progress("Creating repository on GitHub...");
+        cancellation.check("repository creation")?;
         
-        let description = format!("Time travel repository for year {}", config.year);
-        github_client.create_repository_with_defaults(
-            &config.repo_name(),
-            Some(&description),
-            false, // public repository
-        ).await.context("Failed to create repository on GitHub")?;
+        let description = format!("{} for year {}", REPOSITORY_MARKER, config.year);
+        create_marked_repository(&github_client, &config.repo_name(), &description).await?;
+        relay.emit(ProgressEvent::RepoCreated { repository: config.repo_name() });
         
         // Wait a moment for repository to be fully initialized
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
     }
 
-    report_progress("Cloning repository...");
-    
+    // Continue an existing pull request branch, otherwise start from the base branch
+    let clone_branch = match push_branch {
+        Some(branch) if repo_exists && github_client.branch_exists(&config.repo_name(), branch).await? => branch.to_string(),
+        _ => config.branch.clone(),
+    };
+
     // Set up repository configuration
-    let remote_url = format!("https://github.com/{}/{}.git", config.username, config.repo_name());
+    let credentials = GitCredentials {
+        username: config.username.clone(),
+        token: config.token.clone(),
+    };
     let repo_config = RepositoryConfig {
-        url: remote_url,
-        branch: config.branch.clone(),
+        url: config.clone_url(),
+        branch: clone_branch,
         local_path: None,
-        credentials: Some(GitCredentials {
-            username: config.username.clone(),
-            token: config.token.clone(),
-        }),
+        credentials: Some(credentials.clone()),
     };
 
-    // Clone the repository
-    let repo_result = git_ops.clone_repository(&repo_config)
-        .context("Failed to clone repository")?;
-
-    // Open the cloned repository
-    let repo = git_ops.open_repository(&repo_result.repository_path)
-        .context("Failed to open cloned repository")?;
-
-    report_progress("Creating time travel content...");
+    // git2 is synchronous, so clone, commit and push run on the blocking thread pool
+    let mut git_ops = GitOperations::new()
+        .with_cancellation(cancellation.clone())
+        .with_progress(relay.sink());
+    let (git_ops, repo_result) = relay.run_blocking(move || {
+        let repo_result = git_ops.clone_repository(&repo_config)
+            .context("Failed to clone repository")?;
+        Ok((git_ops, repo_result))
+    }).await?;
     
     // Create time travel file
     let year_file = format!("timetravel-{}.md", config.year);
-    let file_path = Path::new(&year_file);
     let file_content = git_ops.generate_time_travel_content(config.year, &config.repo_name());
     
-    git_ops.create_file_with_content(&repo_result.repository_path, file_path, &file_content)
+    git_ops.create_file_with_content(&repo_result.repository_path, Path::new(&year_file), &file_content)
         .context("Failed to create time travel file")?;
-
-    report_progress("Creating backdated commit...");
+    relay.emit(ProgressEvent::ContentWritten { path: year_file.clone() });
     
     // Parse timestamp for commit
     let timestamp_str = config.commit_timestamp()?;
@@ -232,43 +463,328 @@ pub async fn create_time_traveled_repo_with_options(
         .with_timezone(&chrono::Utc);
 
     // Set up commit configuration
-    let author = config.author.clone().unwrap_or_else(|| GitIdentity {
-        name: "Git Time Traveler".to_string(),
-        email: "timetraveler@example.com".to_string(),
-    });
+    let author = config.commit_author();
 
     let commit_config = TimeTravelCommitConfig {
         timestamp,
         author: author.clone(),
         committer: author,
         message: format!("Time travel commit for {}", config.year),
-        files_to_add: vec![file_path.to_path_buf()],
+        files_to_add: vec![PathBuf::from(&year_file)],
     };
 
     // Create the time travel commit
-    let _commit_result = git_ops.create_time_travel_commit(&repo, &commit_config)
-        .context("Failed to create time travel commit")?;
+    cancellation.check("commit")?;
+    let repository_path = repo_result.repository_path.clone();
+    let git_ops = relay.run_blocking(move || {
+        let repo = git_ops.open_repository(&repository_path)
+            .context("Failed to open cloned repository")?;
+        git_ops.create_time_travel_commit(&repo, &commit_config)
+            .context("Failed to create time travel commit")?;
+        Ok(git_ops)
+    }).await?;
 
-    report_progress("Pushing to GitHub...");
-    
     // Push to remote
+    let target_branch = push_branch.unwrap_or(&config.branch).to_string();
+    relay.run_blocking(move || {
+        let repo = git_ops.open_repository(&repo_result.repository_path)
+            .context("Failed to open cloned repository")?;
+        git_ops.push_branch_to_remote(&repo, "origin", &repo_result.current_branch, &target_branch, Some(&credentials), force)
+            .context("Failed to push to GitHub")
+    }).await?;
+
+    relay.emit(ProgressEvent::YearDone { year: config.year });
+
+    Ok(())
+}
+
+/// Result of processing all years of one repository in a concurrent run
+#[derive(Debug)]
+pub struct RepositoryOutcome {
+    pub repository: String,
+    /// Years that were committed and pushed, in processing order
+    pub completed_years: Vec<u32>,
+    /// Years that were not attempted because an earlier year failed
+    pub skipped_years: Vec<u32>,
+    pub error: Option<anyhow::Error>,
+}
+
+/// Group configurations by target repository, keeping the order of first appearance
+pub fn group_by_repository(configs: &[TimeTravelConfig]) -> Vec<(String, Vec<TimeTravelConfig>)> {
+    let mut groups: Vec<(String, Vec<TimeTravelConfig>)> = Vec::new();
+    for config in configs {
+        let repository = config.repo_name();
+        match groups.iter_mut().find(|(name, _)| *name == repository) {
+            Some((_, group)) => group.push(config.clone()),
+            None => groups.push((repository, vec![config.clone()])),
+        }
+    }
+    groups
+}
+
+/// Forwards progress for a run of several years, finishing only after the last one
+struct RepositoryProgress<'a> {
+    inner: &'a dyn ProgressCallback,
+    remaining: std::cell::Cell<usize>,
+}
+
+impl ProgressCallback for RepositoryProgress<'_> {
+    fn set_message(&self, message: &str) {
+        self.inner.set_message(message);
+    }
+
+    fn increment(&self) {
+        self.inner.increment();
+    }
+
+    fn finish(&self, message: &str) {
+        let remaining = self.remaining.get().saturating_sub(1);
+        self.remaining.set(remaining);
+        if remaining == 0 {
+            self.inner.finish(message);
+        }
+    }
+}
+
+/// Process independent repositories concurrently, at most `jobs` at a time.
+///
+/// Years that target the same repository still run one after another, since
+/// each one builds on the previous push. `progress_for` is called once per
+/// repository with its name and the number of progress steps planned for it.
+pub async fn create_time_traveled_repos<'a, P>(
+    configs: &[TimeTravelConfig],
+    jobs: usize,
+    push_branch: Option<&str>,
+    force: bool,
+    progress_for: P,
+) -> Vec<RepositoryOutcome>
+where
+    P: Fn(&str, usize) -> Option<Box<dyn ProgressCallback + 'a>>,
+{
+    use futures::stream::{self, StreamExt};
+
+    let groups = group_by_repository(configs);
+    let tasks = groups.into_iter().map(|(repository, group)| {
+        let progress = progress_for(&repository, progress::year_steps(group.len()));
+        async move {
+            let progress = progress.as_deref().map(|inner| RepositoryProgress {
+                inner,
+                remaining: std::cell::Cell::new(group.len()),
+            });
+
+            let mut outcome = RepositoryOutcome {
+                repository,
+                completed_years: Vec::new(),
+                skipped_years: Vec::new(),
+                error: None,
+            };
+            for config in &group {
+                if outcome.error.is_some() {
+                    outcome.skipped_years.push(config.year);
+                    continue;
+                }
+                let callback = progress.as_ref().map(|p| p as &dyn ProgressCallback);
+                match create_time_traveled_repo_on_branch(config, push_branch, callback, force, false).await {
+                    Ok(()) => outcome.completed_years.push(config.year),
+                    Err(e) => outcome.error = Some(e.context(format!("Year {} failed", config.year))),
+                }
+            }
+            outcome
+        }
+    });
+
+    stream::iter(tasks)
+        .buffer_unordered(jobs.max(1))
+        .collect()
+        .await
+}
+
+/// Create a public repository with the marker description and topic
+async fn create_marked_repository(github_client: &GitHubClient, repo_name: &str, description: &str) -> Result<()> {
+    github_client.create_repository_with_defaults(repo_name, Some(description), false).await
+        .context("Failed to create repository on GitHub")?;
+    // The description marks the repository as well, so a failed topic update is not fatal
+    if let Err(e) = github_client.replace_topics(repo_name, &[REPOSITORY_TOPIC.to_string()]).await {
+        tracing::warn!(target: "github", repository = repo_name, error = %format!("{:#}", e), "Failed to set the marker topic");
+    }
+    Ok(())
+}
+
+/// Apply topics, settings and (last) archiving to the repository after a run
+pub async fn apply_repository_lifecycle(config: &TimeTravelConfig, lifecycle: &RepositoryLifecycle) -> Result<()> {
+    if lifecycle.is_empty() {
+        return Ok(());
+    }
+
+    let github_client = config.github_client()?;
+    let repo_name = config.repo_name();
+
+    if !lifecycle.topics.is_empty() {
+        github_client.replace_topics(&repo_name, &lifecycle.topics_with_marker()).await
+            .context("Failed to set repository topics")?;
+    }
+
+    let settings = UpdateRepositoryRequest {
+        description: lifecycle.description.as_deref().map(marked_description),
+        homepage: lifecycle.homepage.clone(),
+        visibility: lifecycle.visibility.clone(),
+        archived: None,
+    };
+    if settings.description.is_some() || settings.homepage.is_some() || settings.visibility.is_some() {
+        github_client.update_repository(&repo_name, &settings).await
+            .context("Failed to update repository settings")?;
+    }
+
+    // Archived repositories are read-only, so this must come last
+    if lifecycle.archive {
+        let archive = UpdateRepositoryRequest {
+            archived: Some(true),
+            ..Default::default()
+        };
+        github_client.update_repository(&repo_name, &archive).await
+            .context("Failed to archive repository")?;
+    }
+
+    Ok(())
+}
+
+/// Check the profile's contribution calendar for the days in `target`, polling for up to `wait`
+/// while GitHub may still be processing the push
+pub async fn verify_time_travel_contributions(
+    config: &TimeTravelConfig,
+    target: &VerificationTarget,
+    wait: std::time::Duration,
+) -> Result<VerificationReport> {
+    let github_client = config.github_client()?;
+    verify::verify_contributions_within(&github_client, target, wait).await
+}
+
+/// Open a pull request from the time travel branch into the configured branch
+pub async fn open_pull_request(config: &TimeTravelConfig, options: &PullRequestOptions) -> Result<PullRequest> {
+    let github_client = config.github_client()?;
+
+    let request = CreatePullRequestRequest {
+        title: options.title.clone(),
+        head: options.branch.clone(),
+        base: config.branch.clone(),
+        body: options.body.clone(),
+    };
+    let pull_request = github_client.create_pull_request(&config.repo_name(), &request).await
+        .context("Failed to open pull request")?;
+
+    if options.auto_merge {
+        github_client.enable_auto_merge(&pull_request, "merge").await?;
+    }
+
+    Ok(pull_request)
+}
+
+
+/// Create every commit of an imported schedule in one repository and push them together
+pub async fn create_scheduled_repo_with_options(
+    config: &TimeTravelConfig,
+    schedule: &schedule::CommitSchedule,
+    progress: Option<&dyn ProgressCallback>,
+    force: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let default_author = config.commit_author();
+    let commits = schedule.to_commit_configs(&default_author);
+
+    // Handle dry-run mode
+    if dry_run {
+        let dry_run_config = dry_run::DryRunConfig {
+            show_detailed_operations: true,
+            show_file_previews: false,
+            show_risks: true,
+            require_confirmation: false,
+            interactive_confirmations: false,
+            show_heatmap: true,
+        };
+
+        let executor = dry_run::DryRunExecutor::new(dry_run_config);
+        let plan = executor.create_schedule_plan(config, &commits, force)?;
+        executor.display_plan(&plan)?;
+
+        if let Some(p) = progress {
+            p.finish("✅ Dry run complete - no changes made");
+        }
+        return Ok(());
+    }
+
+    let _active = config.cancellation.enter();
+    let cancellation = &config.cancellation;
+    let mut relay = progress::ProgressRelay::new(progress);
+    let github_client = config.github_client()?;
+    let mut git_ops = GitOperations::new()
+        .with_cancellation(cancellation.clone())
+        .with_progress(relay.sink());
+
+    cancellation.check("token validation")?;
+    github_client.check_permissions().await
+        .context("GitHub token validation failed")?;
+    relay.emit(ProgressEvent::TokenValidated);
+
+    let repo_exists = github_client.repository_exists(&config.repo_name()).await
+        .context("Failed to check repository existence")?;
+    github_client.check_organization_access(!repo_exists).await
+        .context("Cannot use the organization as repository owner")?;
+    relay.emit(ProgressEvent::RepoChecked { repository: config.repo_name(), exists: repo_exists });
+
+    if !repo_exists {
+        cancellation.check("repository creation")?;
+        let description = format!("{} with an imported commit schedule", REPOSITORY_MARKER);
+        create_marked_repository(&github_client, &config.repo_name(), &description).await?;
+        relay.emit(ProgressEvent::RepoCreated { repository: config.repo_name() });
+
+        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    }
+
     let credentials = GitCredentials {
         username: config.username.clone(),
         token: config.token.clone(),
     };
+    let repo_config = RepositoryConfig {
+        url: config.clone_url(),
+        branch: config.branch.clone(),
+        local_path: None,
+        credentials: Some(credentials.clone()),
+    };
 
-    git_ops.push_to_remote(&repo, "origin", &config.branch, Some(&credentials), force)
-        .context("Failed to push to GitHub")?;
+    let total_commits = commits.len();
+    let branch = config.branch.clone();
+    let cancellation = cancellation.clone();
+    relay.run_blocking(move || {
+        let repo_result = git_ops.clone_repository(&repo_config)
+            .context("Failed to clone repository")?;
+        let repo = git_ops.open_repository(&repo_result.repository_path)
+            .context("Failed to open cloned repository")?;
+
+        // Every scheduled commit appends a line so it carries its own change
+        let schedule_path = repo_result.repository_path.join(schedule::SCHEDULE_FILE);
+        let mut content = std::fs::read_to_string(&schedule_path)
+            .unwrap_or_else(|_| "# Time Travel Schedule\n\n".to_string());
+
+        for commit in &commits {
+            cancellation.check("commit")?;
+
+            content.push_str(&format!("- {} {}\n", commit.timestamp.format("%Y-%m-%d %H:%M"), commit.message));
+            git_ops.create_file_with_content(&repo_result.repository_path, Path::new(schedule::SCHEDULE_FILE), &content)
+                .context("Failed to update schedule file")?;
+            git_ops.create_time_travel_commit(&repo, commit)
+                .with_context(|| format!("Failed to create scheduled commit '{}'", commit.message))?;
+        }
 
-    if let Some(p) = progress {
-        p.finish("✅ Time travel complete!");
-    }
+        git_ops.push_to_remote(&repo, "origin", &branch, Some(&credentials), force)
+            .context("Failed to push to GitHub")
+    }).await?;
+
+    relay.emit(ProgressEvent::ScheduleDone { commits: total_commits });
 
     Ok(())
 }
 
 
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -395,4 +911,51 @@ mod tests {
         assert_eq!(author.name, "Custom Author");
         assert_eq!(author.email, "custom@example.com");
     }
+
+    #[test]
+    fn test_group_by_repository() {
+        let config = |year: u32, repo: Option<&str>| TimeTravelConfig::new(
+            year, 1, 1, 12,
+            "testuser".to_string(),
+            "ghp_test123".to_string(),
+            repo.map(str::to_string),
+            "main".to_string(),
+            None,
+        ).unwrap();
+
+        let groups = group_by_repository(&[
+            config(1990, None),
+            config(1991, Some("shared")),
+            config(1992, None),
+            config(1993, Some("shared")),
+        ]);
+
+        let names: Vec<&str> = groups.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["1990", "shared", "1992"]);
+        let shared_years: Vec<u32> = groups[1].1.iter().map(|c| c.year).collect();
+        assert_eq!(shared_years, vec![1991, 1993]);
+    }
+
+    #[test]
+    fn test_time_travel_repository_detection() {
+        let repository = |description: Option<&str>, topics: &[&str]| -> Repository {
+            serde_json::from_value(serde_json::json!({
+                "id": 1, "name": "1990", "full_name": "octocat/1990",
+                "description": description, "private": false,
+                "html_url": "", "clone_url": "", "ssh_url": "",
+                "default_branch": "main", "created_at": "", "updated_at": "",
+                "topics": topics,
+            })).unwrap()
+        };
+
+        assert!(is_time_travel_repository(&repository(Some("Time travel repository for year 1990"), &[])));
+        assert!(is_time_travel_repository(&repository(None, &["retro", REPOSITORY_TOPIC])));
+        assert!(!is_time_travel_repository(&repository(Some("My project"), &["rust"])));
+
+        // Custom descriptions keep the marker
+        let description = marked_description("Retro games");
+        assert_eq!(description, "Retro games (Time travel repository)");
+        assert!(is_time_travel_repository(&repository(Some(&description), &[])));
+        assert_eq!(marked_description("Time travel repository for year 1990"), "Time travel repository for year 1990");
+    }
 } 
\ No newline at end of file
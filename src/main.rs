@@ -1,12 +1,32 @@
 use anyhow::{Result, Context};
-use clap::Parser;
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use colored::*;
 use dialoguer::{theme::ColorfulTheme, Select, Input, Password};
-use git_timetraveler::{create_time_traveled_repo_with_options, ProgressCallback, TimeTravelConfig, InteractivePrompts, AuthorMode, format_error_for_user, display_and_confirm_dry_run};
+use git_timetraveler::{create_time_traveled_repo_with_options, ProgressCallback, TimeTravelConfig, InteractivePrompts, format_error_for_user, debug_report, display_and_confirm_dry_run};
+use git_timetraveler::{GitOperations, GitContextDetector, GitCredentials, DateParser, DateShift, HistoryShiftConfig, parse_offset};
+use git_timetraveler::{create_scheduled_repo_with_options, resolve_author, AuthorPool, CommitSchedule};
+use git_timetraveler::{mirror, GitIdentity, MirrorConfig, MirrorKey, MessageMode, TimeTravelCommitConfig};
+use git_timetraveler::{schedule, RedateConfig, RedateTarget};
+use git_timetraveler::{create_time_traveled_repo_on_branch, open_pull_request, display_and_confirm_dry_run_with_pr, PullRequestOptions};
+use git_timetraveler::{apply_repository_lifecycle, RepositoryLifecycle};
+use git_timetraveler::{is_time_travel_repository, GitHubClient, Repository};
+use git_timetraveler::{verify_time_travel_contributions, VerificationReport, VerificationTarget};
+use git_timetraveler::{ContributionCalendar, DryRunConfig, DryRunExecutor, DryRunPlan};
+use git_timetraveler::{create_time_traveled_repos, group_by_repository};
+use git_timetraveler::{is_cancellation, remove_tracked_temp_dirs, CancellationToken};
+use git_timetraveler::progress::{schedule_steps, year_steps, YEAR_STEPS};
+use git_timetraveler::logging::{self, LogFormat};
+use git_timetraveler::{set_locale, t, Locale, Secret};
+use git_timetraveler::{Profile, SessionManager, TokenSource};
+use git_timetraveler::errors::{validation, error_envelope, exit_code_for, exit_codes, ErrorFormat, TimeTravelError};
+use chrono::TimeZone;
 use indicatif::{ProgressBar, ProgressStyle, MultiProgress};
+use std::path::PathBuf;
 use std::process;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use ctrlc;
 use std::panic;
 use atty::Stream;
@@ -34,8 +54,32 @@ EXAMPLES:
         --years 1990,1992,1994 --hour 14 --author-name \"John Doe\" \\
         --author-email john@example.com --message \"Custom commit message\"
 
+    # Import a commit schedule planned in a spreadsheet
+    git-timetraveler --no-menu --username myuser --token ghp_xxx --repo myrepo \\
+        --schedule commits.csv
+
+    # Deliver the commits through a pull request when the branch is protected
+    git-timetraveler --no-menu --username myuser --token ghp_xxx --repo myrepo \\
+        --years 1990-1995 --via-pr --auto-merge
+
+    # Commit your staged changes in the current repository as if made in 2005
+    git-timetraveler --here --year 2005 --month 6 --day 15 --message \"Add parser\" --push
+
+    # Shift an existing local repository's history back ten years
+    git-timetraveler shift --offset -10y
+
+    # Move the last three commits so the oldest lands on 2012-03-01
+    git-timetraveler redate HEAD~3..HEAD --to 2012-03-01
+
+    # Mirror your work commits as anonymized activity into a public repo
+    git-timetraveler mirror ~/work/api ~/work/web --target ./activity \\
+        --author-email me@company.com --as-email me@users.noreply.github.com
+
 For more information, visit: https://github.com/chama-x/Git-Timetraveler")]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Year to travel back to (e.g., 1990)
     #[arg(short, long, default_value = "1990")]
     year: u32,
@@ -45,12 +89,12 @@ struct Args {
     years: Option<String>,
 
     /// GitHub username
-    #[arg(short, long, value_name = "USERNAME")]
+    #[arg(short, long, value_name = "USERNAME", global = true)]
     username: Option<String>,
 
     /// GitHub personal access token
-    #[arg(short, long, value_name = "TOKEN", hide = true)]
-    token: Option<String>,
+    #[arg(short, long, value_name = "TOKEN", hide = true, global = true)]
+    token: Option<Secret>,
 
     /// Month (1-12)
     #[arg(short, long, default_value = "1", value_name = "MONTH")]
@@ -68,6 +112,10 @@ struct Args {
     #[arg(long, value_name = "REPO")]
     repo: Option<String>,
 
+    /// Organization that owns the repository (defaults to your own account)
+    #[arg(long, value_name = "ORG", global = true)]
+    owner: Option<String>,
+
     /// Branch to push commits to
     #[arg(long, default_value = "main", value_name = "BRANCH")]
     branch: String,
@@ -80,16 +128,20 @@ struct Args {
     #[arg(long, value_name = "EMAIL")]
     author_email: Option<String>,
 
+    /// Whether the author fields were filled in from --profile rather than given as flags
+    #[arg(skip)]
+    author_from_profile: bool,
+
     /// Custom commit message template (use {year} placeholder)
     #[arg(long, value_name = "MESSAGE")]
     message: Option<String>,
 
     /// Skip all confirmation prompts
-    #[arg(long)]
+    #[arg(long, global = true)]
     yes: bool,
 
     /// Force push (overwrite remote branch - use with caution)
-    #[arg(long)]
+    #[arg(long, global = true)]
     force: bool,
 
     /// Run in non-interactive mode (no menu, use only CLI args)
@@ -97,15 +149,35 @@ struct Args {
     no_menu: bool,
 
     /// Enable verbose output for debugging
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Record API requests, git steps and decisions in FILE (tokens are redacted)
+    #[arg(long, value_name = "FILE", global = true)]
+    log_file: Option<PathBuf>,
+
+    /// Format of log records: text or json (one object per line)
+    #[arg(long, value_name = "FORMAT", default_value = "text", value_parser = ["text", "json"], global = true)]
+    log_format: String,
+
+    /// How failures are reported: text, or json for scripts (a single object on stderr)
+    #[arg(long, value_name = "FORMAT", default_value = "text", value_parser = ["text", "json"], global = true)]
+    error_format: String,
+
+    /// Language of prompts and messages, e.g. en or es (default: from LC_ALL, LC_MESSAGES or LANG)
+    #[arg(long, value_name = "LANG", global = true)]
+    lang: Option<String>,
+
+    /// Saved profile providing username, token, author, owner and hour (see `profile add`)
+    #[arg(long, value_name = "NAME", global = true)]
+    profile: Option<String>,
+
     /// Dry run - show what would be done without making changes
-    #[arg(long)]
+    #[arg(long, global = true)]
     dry_run: bool,
 
     /// Quiet mode - minimal output
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     quiet: bool,
 
     /// Create repository if it doesn't exist
@@ -116,9 +188,242 @@ struct Args {
     #[arg(long)]
     private: bool,
 
-    /// Repository description (only when creating new repo)
+    /// Repository description, updated after the commits are pushed
     #[arg(long, value_name = "DESCRIPTION")]
     description: Option<String>,
+
+    /// Comma-separated repository topics to set after the run (replaces existing topics)
+    #[arg(long, value_name = "TOPICS", value_delimiter = ',')]
+    topics: Vec<String>,
+
+    /// Repository homepage URL to set after the run
+    #[arg(long, value_name = "URL")]
+    homepage: Option<String>,
+
+    /// Change repository visibility after the run (public or private)
+    #[arg(long, value_name = "VISIBILITY", value_parser = ["public", "private"])]
+    visibility: Option<String>,
+
+    /// Archive the repository once all commits are pushed
+    #[arg(long, conflicts_with_all = ["via_pr", "here"])]
+    archive: bool,
+
+    /// Write the projected contribution calendar of a dry run to an .svg or .html file
+    #[arg(long, value_name = "FILE", requires = "dry_run")]
+    preview_out: Option<PathBuf>,
+
+    /// JSON file with existing contributions to overlay on the preview
+    #[arg(long, value_name = "FILE", requires = "preview_out")]
+    preview_overlay: Option<PathBuf>,
+
+    /// After pushing, check the contribution calendar and report days that do not show up
+    #[arg(long, conflicts_with = "here")]
+    verify: bool,
+
+    /// Seconds to keep checking while GitHub is still processing the push (0 checks once)
+    #[arg(long, default_value = "60", value_name = "SECONDS", requires = "verify")]
+    verify_wait: u64,
+
+    /// Create a separate repository for every year (named after the year)
+    #[arg(long, conflicts_with_all = ["repo", "via_pr", "schedule", "here"])]
+    repo_per_year: bool,
+
+    /// Number of repositories processed at the same time
+    #[arg(long, default_value = "4", value_name = "N", value_parser = clap::value_parser!(u16).range(1..=16))]
+    jobs: u16,
+
+    /// Push to a new branch and open a pull request instead of pushing to --branch
+    #[arg(long, conflicts_with_all = ["here", "schedule"])]
+    via_pr: bool,
+
+    /// Enable auto-merge on the pull request opened by --via-pr
+    #[arg(long, requires = "via_pr")]
+    auto_merge: bool,
+
+    /// Commit the files staged in the current repository with a backdated date
+    #[arg(long, conflicts_with_all = ["years", "schedule"])]
+    here: bool,
+
+    /// Push the backdated commit to the detected GitHub remote (with --here)
+    #[arg(long, requires = "here")]
+    push: bool,
+
+    /// Import a commit schedule (CSV or JSON rows of datetime, message, count[, author])
+    #[arg(long, value_name = "FILE", conflicts_with = "years")]
+    schedule: Option<PathBuf>,
+
+    /// Attribute commits to identities by date range (CSV or JSON rows of from, until, author)
+    #[arg(long, value_name = "FILE")]
+    author_pool: Option<PathBuf>,
+}
+
+impl Args {
+    /// Whether --author-name or --author-email was given, as opposed to filled in from a profile
+    fn has_author_flags(&self) -> bool {
+        !self.author_from_profile && (self.author_name.is_some() || self.author_email.is_some())
+    }
+}
+
+/// Subcommands that work on existing repositories instead of creating new ones
+#[derive(Subcommand)]
+enum Command {
+    /// Shift the dates of an existing local repository's history
+    Shift(ShiftArgs),
+    /// Mirror your activity from local repositories into a contribution repository
+    Mirror(MirrorArgs),
+    /// Re-date selected commits of the current repository
+    Redate(RedateArgs),
+    /// List or delete repositories created by the tool
+    #[command(subcommand)]
+    Repos(ReposCommand),
+    /// Manage named profiles, e.g. for work and personal accounts
+    #[command(subcommand)]
+    Profile(ProfileCommand),
+}
+
+/// Subcommands of `profile`
+#[derive(Subcommand)]
+enum ProfileCommand {
+    /// List the saved profiles
+    List,
+    /// Create or update a profile (its account comes from --username and --owner)
+    Add(ProfileAddArgs),
+    /// Delete a profile together with the preferences learned under it
+    Remove {
+        /// Profile to delete
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+}
+
+/// Arguments for the `profile add` subcommand
+#[derive(clap::Args)]
+struct ProfileAddArgs {
+    /// Profile name, e.g. work or personal
+    #[arg(value_name = "NAME")]
+    name: String,
+
+    /// Author name for commits made with this profile
+    #[arg(long, value_name = "NAME", requires = "author_email")]
+    author_name: Option<String>,
+
+    /// Author email for commits made with this profile
+    #[arg(long, value_name = "EMAIL", requires = "author_name")]
+    author_email: Option<String>,
+
+    /// Environment variable holding the token for this profile (otherwise it is asked for)
+    #[arg(long, value_name = "VAR")]
+    token_env: Option<String>,
+
+    /// Preferred commit hours, the first one being the default (e.g., 9,14)
+    #[arg(long, value_name = "HOURS", value_delimiter = ',', value_parser = clap::value_parser!(u32).range(0..=23))]
+    hours: Vec<u32>,
+}
+
+/// Subcommands of `repos`
+#[derive(Subcommand)]
+enum ReposCommand {
+    /// List repositories created by the tool (matched by description or topic)
+    List,
+    /// Delete repositories created by the tool, after a typed confirmation
+    Delete(ReposDeleteArgs),
+}
+
+/// Arguments for the `repos delete` subcommand
+#[derive(clap::Args)]
+struct ReposDeleteArgs {
+    /// Repositories to delete (must have been created by the tool)
+    #[arg(value_name = "REPO", required_unless_present = "all")]
+    names: Vec<String>,
+
+    /// Delete every repository created by the tool
+    #[arg(long, conflicts_with = "names")]
+    all: bool,
+}
+
+/// Arguments for the `shift` subcommand
+#[derive(clap::Args)]
+struct ShiftArgs {
+    /// Path to the local repository
+    #[arg(long, default_value = ".", value_name = "PATH")]
+    path: PathBuf,
+
+    /// Move every commit by an offset (e.g., -10y, +3w, -90d, 6h)
+    #[arg(long, value_name = "OFFSET", allow_hyphen_values = true,
+          conflicts_with = "window", required_unless_present = "window")]
+    offset: Option<String>,
+
+    /// Map the whole history into a date window (e.g., 2010-01-01..2012-12-31)
+    #[arg(long, value_name = "START..END")]
+    window: Option<String>,
+
+    /// Also move committer dates (author dates are always moved)
+    #[arg(long)]
+    committer_dates: bool,
+
+    /// Push the rewritten branches after shifting (requires --force for existing remotes)
+    #[arg(long)]
+    push: bool,
+
+    /// Remote to push the rewritten branches to
+    #[arg(long, default_value = "origin", value_name = "REMOTE")]
+    remote: String,
+}
+
+/// Arguments for the `mirror` subcommand
+#[derive(clap::Args)]
+struct MirrorArgs {
+    /// Local repositories to scan for your commits
+    #[arg(required = true, value_name = "SOURCE")]
+    sources: Vec<PathBuf>,
+
+    /// Local repository that receives the mirrored commits
+    #[arg(long, value_name = "PATH")]
+    target: PathBuf,
+
+    /// Author email to match (repeatable; defaults to the target's Git identity)
+    #[arg(long = "author-email", value_name = "EMAIL")]
+    author_emails: Vec<String>,
+
+    /// Only mirror commits inside this date window (e.g., 2020-01-01..2021-12-31)
+    #[arg(long, value_name = "START..END")]
+    range: Option<String>,
+
+    /// How to carry commit messages over: keep, hash or redact
+    #[arg(long, default_value = "redact", value_name = "MODE")]
+    messages: String,
+
+    /// Name for mirrored commits (defaults to the source author)
+    #[arg(long, value_name = "NAME")]
+    as_name: Option<String>,
+
+    /// Email for mirrored commits, e.g. your GitHub noreply address
+    #[arg(long, value_name = "EMAIL")]
+    as_email: Option<String>,
+
+    /// Push the target's current branch after mirroring
+    #[arg(long)]
+    push: bool,
+
+    /// Remote to push the target branch to
+    #[arg(long, default_value = "origin", value_name = "REMOTE")]
+    remote: String,
+}
+
+/// Arguments for the `redate` subcommand
+#[derive(clap::Args)]
+struct RedateArgs {
+    /// Commit or range to re-date (e.g., HEAD~2 or HEAD~5..HEAD)
+    #[arg(value_name = "REV-RANGE")]
+    range: String,
+
+    /// New date for the oldest selected commit, or a CSV/JSON schedule with one row per commit
+    #[arg(long, value_name = "DATE|SCHEDULE")]
+    to: String,
+
+    /// Also rewrite committer dates (author dates are always rewritten)
+    #[arg(long)]
+    committer_dates: bool,
 }
 
 /// Enhanced progress bar with better visual feedback and status tracking
@@ -132,7 +437,12 @@ struct CliProgressBar {
 impl CliProgressBar {
     /// Create a new progress bar for single operations
     fn new() -> Self {
-        let pb = ProgressBar::new(6);
+        Self::with_steps(YEAR_STEPS)
+    }
+
+    /// Create a new progress bar for an operation with a known number of steps
+    fn with_steps(total_steps: usize) -> Self {
+        let pb = ProgressBar::new(total_steps as u64);
         pb.set_style(
             ProgressStyle::default_bar()
                 .template("{spinner:.green} [{elapsed_precise}] {bar:40.cyan/blue} {pos:>2}/{len:2} {msg}")
@@ -143,14 +453,14 @@ impl CliProgressBar {
             pb,
             multi_progress: None,
             current_step: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
-            total_steps: 6,
+            total_steps,
         }
     }
 
     /// Create a new progress bar for multi-year operations
     fn new_multi_year(total_years: usize) -> Self {
         let multi = Arc::new(MultiProgress::new());
-        let pb = multi.add(ProgressBar::new((total_years * 6) as u64));
+        let pb = multi.add(ProgressBar::new(year_steps(total_years) as u64));
 
         pb.set_style(
             ProgressStyle::default_bar()
@@ -163,14 +473,14 @@ impl CliProgressBar {
             pb,
             multi_progress: Some(multi),
             current_step: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
-            total_steps: total_years * 6,
+            total_steps: year_steps(total_years),
         }
     }
 
     /// Create a year-specific sub-progress bar
     fn create_year_progress(&self, year: u32) -> Option<ProgressBar> {
         if let Some(ref multi) = self.multi_progress {
-            let year_pb = multi.add(ProgressBar::new(6));
+            let year_pb = multi.add(ProgressBar::new(YEAR_STEPS as u64));
             year_pb.set_style(
                 ProgressStyle::default_bar()
                     .template(&format!("  {} {{spinner:.blue}} [{{elapsed_precise}}] {{bar:30.cyan/blue}} {{pos}}/{{len}} {{msg}}",
@@ -184,6 +494,20 @@ impl CliProgressBar {
         }
     }
 
+    /// Create a sub-progress bar for one repository of a concurrent run
+    fn create_repository_progress(&self, repository: &str, steps: usize) -> Option<ProgressBar> {
+        let multi = self.multi_progress.as_ref()?;
+        let repo_pb = multi.add(ProgressBar::new(steps as u64));
+        repo_pb.set_style(
+            ProgressStyle::default_bar()
+                .template(&format!("  {} {{spinner:.blue}} [{{elapsed_precise}}] {{bar:30.cyan/blue}} {{pos}}/{{len}} {{msg}}",
+                    format!("{}:", repository).bright_yellow()))
+                .unwrap()
+                .progress_chars("█▉▊▋▌▍▎▏  "),
+        );
+        Some(repo_pb)
+    }
+
     /// Set a status message with step information
     fn set_status(&self, message: &str, step: usize, total: usize) {
         let formatted_msg = format!("[{}/{}] {}", step, total, message);
@@ -191,40 +515,41 @@ impl CliProgressBar {
     }
 
     /// Display operation summary
-    fn display_summary(&self, years: &[u32], repository: &str, username: &str) {
-        println!("\n{}", "📋 Operation Summary".bright_blue().bold());
-        println!("  {} {}", "Repository:".cyan(), repository.bright_green());
-        println!("  {} {}", "GitHub User:".cyan(), username.bright_green());
+    /// `repositories` are full `owner/name` names, one per repository the run pushes to
+    fn display_summary(&self, years: &[u32], repositories: &[String], username: &str) {
+        println!("\n📋 {}", t!("banner.summary_title").bright_blue().bold());
+        println!("  {} {}", t!("banner.repository").cyan(), repositories.join(", ").bright_green());
+        println!("  {} {}", t!("banner.github_user").cyan(), username.bright_green());
 
         let years_display = if years.len() == 1 {
             years[0].to_string()
         } else {
-            format!("{} years ({}-{})",
-                years.len(),
-                years.iter().min().unwrap(),
-                years.iter().max().unwrap()
+            t!("banner.year_range",
+                count = years.len(),
+                start = years.iter().min().unwrap(),
+                end = years.iter().max().unwrap()
             )
         };
-        println!("  {} {}", "Years:".cyan(), years_display.bright_magenta());
+        println!("  {} {}", t!("banner.years").cyan(), years_display.bright_magenta());
         println!();
     }
 
     /// Display completion summary with results
-    fn display_completion(&self, years: &[u32], repository: &str, username: &str) {
-        println!("\n{}", "🎉 Time Travel Complete!".bright_green().bold());
+    fn display_completion(&self, years: &[u32], repository: &str, owner: &str) {
+        println!("\n🎉 {}", t!("banner.complete").bright_green().bold());
         let years_count = years.len();
         let years_text = if years_count == 1 { "1".to_string() } else { years_count.to_string() };
-        println!("  {} commits created across {} years",
-            years_count.to_string().bright_yellow(),
-            years_text.bright_magenta()
-        );
-        println!("  {} {}", "Repository:".cyan(), repository.bright_green());
+        println!("  {}", t!("banner.commits_created",
+            commits = years_count.to_string().bright_yellow(),
+            years = years_text.bright_magenta()
+        ));
+        println!("  {} {}", t!("banner.repository").cyan(), repository.bright_green());
         println!("  {} https://github.com/{}/{}",
-            "View at:".cyan(),
-            username.bright_blue(),
+            t!("banner.view_at").cyan(),
+            owner.bright_blue(),
             repository.bright_blue().underline()
         );
-        println!("\n{}", "Check your GitHub profile to see the backdated commits!".cyan());
+        println!("\n{}", t!("banner.check_profile").cyan());
     }
 }
 
@@ -251,10 +576,16 @@ impl ProgressCallback for CliProgressBar {
 }
 
 /// Run the interactive time travel workflow using the new smart defaults system
-async fn run_interactive_time_travel() -> Result<()> {
+async fn run_interactive_time_travel(profile: Option<&str>, author_pool: Option<&std::path::Path>, cancellation: CancellationToken) -> Result<()> {
     // Initialize the interactive prompts system
     let mut interactive = InteractivePrompts::new()
         .context("Failed to initialize interactive prompts system")?;
+    if profile.is_some() {
+        interactive = interactive.with_profile(profile)?;
+    }
+    if let Some(path) = author_pool {
+        interactive = interactive.with_author_pool(AuthorPool::from_path(path)?);
+    }
 
     // Get current working directory for context
     let current_path = std::env::current_dir().ok();
@@ -264,17 +595,6 @@ async fn run_interactive_time_travel() -> Result<()> {
     let choices = interactive.run_interactive_workflow(current_path_ref)
         .context("Failed to complete interactive workflow")?;
 
-    // Convert UserChoices to the format needed for time travel
-    let author_identity = match &choices.author_mode {
-        AuthorMode::CurrentUser(identity) => Some(identity.clone()),
-        AuthorMode::TimeTraveler => None, // Use default time traveler identity
-        AuthorMode::Manual(identity) => Some(identity.clone()),
-        AuthorMode::AskEachTime => {
-            // For now, default to time traveler - in future could prompt each time
-            None
-        }
-    };
-
     // Create appropriate progress bar based on number of years
     let progress_bar = if choices.years.len() > 1 {
         CliProgressBar::new_multi_year(choices.years.len())
@@ -283,7 +603,8 @@ async fn run_interactive_time_travel() -> Result<()> {
     };
 
     // Display operation summary
-    progress_bar.display_summary(&choices.years, &choices.repository, &choices.github_username);
+    let owner = choices.owner.as_deref().unwrap_or(&choices.github_username);
+    progress_bar.display_summary(&choices.years, &[format!("{}/{}", owner, choices.repository)], &choices.github_username);
 
     // Create configurations for all years
     let mut configs = Vec::new();
@@ -297,8 +618,11 @@ async fn run_interactive_time_travel() -> Result<()> {
             choices.github_token.clone(),
             Some(choices.repository.clone()),
             choices.branch.clone(),
-            author_identity.clone(),
-        ).context("Failed to create time travel configuration")?;
+            // None uses the default time traveler identity
+            choices.author_for_year(*year),
+        ).context("Failed to create time travel configuration")?
+            .with_owner(choices.owner.clone())?
+            .with_cancellation(cancellation.clone());
         configs.push(config);
     }
 
@@ -307,34 +631,34 @@ async fn run_interactive_time_travel() -> Result<()> {
         .context("Failed to display dry run information")?;
 
     if !confirmed {
-        println!("\n{} {}", "❌".red(), "Operation cancelled by user".red());
+        println!("\n{} {}", "❌".red(), t!("common.cancelled_by_user").red());
         return Ok(());
     }
 
     // Additional confirmation for potentially destructive operations
     if choices.force_push || choices.years.len() > 5 {
-        println!("\n{}", "⚠️  Additional Confirmation Required".yellow().bold());
+        println!("\n⚠️  {}", t!("banner.additional_confirmation").yellow().bold());
 
         if choices.force_push {
-            println!("  {} Force push will overwrite remote history", "•".red());
+            println!("  {} {}", "•".red(), t!("banner.force_push_warning"));
         }
         if choices.years.len() > 5 {
-            println!("  {} Processing {} years may take significant time", "•".yellow(), choices.years.len());
+            println!("  {} {}", "•".yellow(), t!("banner.many_years_warning", count = choices.years.len()));
         }
 
         let final_confirm = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
-            .with_prompt("Are you absolutely sure you want to proceed?")
+            .with_prompt(t!("banner.final_confirm"))
             .default(false)
             .interact()
             .context("Failed to get final confirmation")?;
 
         if !final_confirm {
-            println!("\n{} {}", "❌".red(), "Operation cancelled by user".red());
+            println!("\n{} {}", "❌".red(), t!("common.cancelled_by_user").red());
             return Ok(());
         }
     }
 
-    println!("{}", "🚀 Starting time travel operation...".bright_green().bold());
+    println!("🚀 {}", t!("banner.starting").bright_green().bold());
 
     // Process each year with enhanced progress tracking
     for (index, year) in choices.years.iter().enumerate() {
@@ -345,7 +669,7 @@ async fn run_interactive_time_travel() -> Result<()> {
         };
 
         println!("\n{} {} ({}/{})",
-            "Processing year:".cyan(),
+            t!("banner.processing_year").cyan(),
             year.to_string().bright_yellow(),
             (index + 1).to_string().bright_white(),
             choices.years.len().to_string().bright_white()
@@ -361,23 +685,21 @@ async fn run_interactive_time_travel() -> Result<()> {
         };
 
         if let Err(e) = create_time_traveled_repo_with_options(config, Some(progress_callback), choices.force_push, false).await {
-            eprintln!("\n{}", format_error_for_user(&e));
-            std::process::exit(1);
+            if is_cancellation(&e) {
+                report_interruption(&choices.years[..index], &choices.years[index..]);
+            }
+            exit_with_error(&e, false, false);
         }
 
         if let Some(year_pb) = year_progress {
-            year_pb.finish_with_message(format!("✅ Year {} complete", year));
+            year_pb.finish_with_message(format!("✅ {}", t!("banner.year_complete", year = year)));
         }
 
-        println!("✅ {} {} {}",
-            "Commit for year".green(),
-            year.to_string().bright_yellow(),
-            "created successfully!".green()
-        );
+        println!("✅ {}", t!("banner.year_created", year = year.to_string().bright_yellow()).green());
     }
 
     // Display completion summary
-    progress_bar.display_completion(&choices.years, &choices.repository, &choices.github_username);
+    progress_bar.display_completion(&choices.years, &choices.repository, choices.owner.as_deref().unwrap_or(&choices.github_username));
 
     Ok(())
 }
@@ -401,6 +723,27 @@ impl ProgressCallback for YearProgressWrapper {
     }
 }
 
+/// Progress of one repository in a concurrent run, also advancing the overall bar
+struct RepositoryProgressWrapper {
+    pb: ProgressBar,
+    overall: ProgressBar,
+}
+
+impl ProgressCallback for RepositoryProgressWrapper {
+    fn set_message(&self, message: &str) {
+        self.pb.set_message(message.to_string());
+    }
+
+    fn increment(&self) {
+        self.pb.inc(1);
+        self.overall.inc(1);
+    }
+
+    fn finish(&self, message: &str) {
+        self.pb.finish_with_message(message.to_string());
+    }
+}
+
 /// Clear the terminal screen in a cross-platform way
 fn clear_screen() {
     if cfg!(target_os = "windows") {
@@ -415,29 +758,91 @@ async fn main() -> Result<()> {
     // Set a panic hook for user-friendly error reporting
     panic::set_hook(Box::new(|info| {
         eprintln!("\n❌ An unexpected error occurred: {}\nIf this is a bug, please report it at https://github.com/chama-x/Git-Timetraveler/issues", info);
+        // Release builds abort on panic, so temporary clones would otherwise be left behind
+        remove_tracked_temp_dirs();
         std::process::exit(1);
     }));
 
-    let args = Args::parse();
+    // Handle Ctrl+C gracefully: a running operation stops at its next step and
+    // cleans up, a second Ctrl+C (or one while idle) exits right away. Either way
+    // the exit status is 130, like the default SIGINT handling scripts expect.
+    let cancellation = CancellationToken::new();
+    let handler_token = cancellation.clone();
+    ctrlc::set_handler(move || {
+        if handler_token.is_active() && !handler_token.is_cancelled() {
+            eprintln!("\n{}", t!("banner.interrupted").yellow());
+            handler_token.cancel();
+        } else {
+            remove_tracked_temp_dirs();
+            println!("\n{}", t!("banner.goodbye"));
+            process::exit(exit_codes::CANCELLED);
+        }
+    }).expect("Error setting Ctrl-C handler");
+
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    // Validated by clap, so the name is always known
+    let _ = ERROR_FORMAT.set(ErrorFormat::from_name(&args.error_format).unwrap_or(ErrorFormat::Text));
+    match Locale::detect(args.lang.as_deref()) {
+        Ok(locale) => set_locale(locale),
+        Err(e) => exit_with_error(&e, false, false),
+    }
+    if !matches!(args.command, Some(Command::Profile(_))) {
+        let hour_given = matches.value_source("hour") == Some(ValueSource::CommandLine);
+        if let Err(e) = apply_profile(&mut args, hour_given) {
+            exit_with_error(&e, false, false);
+        }
+    }
+
+    // Scrub the token from logs and error output before anything can print it
+    // Holding a copy keeps it registered after `args` has been consumed
+    let _token = args.token.clone();
+
+    // A log file records everything; --verbose alone logs to stderr
+    if args.log_file.is_some() || args.verbose {
+        let level = if args.log_file.is_some() { tracing::Level::TRACE } else { tracing::Level::DEBUG };
+        if let Err(e) = LogFormat::from_name(&args.log_format)
+            .and_then(|format| logging::init(args.log_file.as_deref(), format, level))
+        {
+            exit_with_error(&e, false, false);
+        }
+        tracing::info!(target: "cli", version = env!("CARGO_PKG_VERSION"), args = ?std::env::args().collect::<Vec<_>>(), "Starting");
+    }
+    if let Some(command) = args.command.take() {
+        let result = match command {
+            Command::Shift(shift_args) => run_shift(&args, shift_args),
+            Command::Mirror(mirror_args) => run_mirror(&args, mirror_args),
+            Command::Redate(redate_args) => run_redate(&args, redate_args),
+            Command::Repos(repos_command) => run_repos(&args, repos_command).await,
+            Command::Profile(profile_command) => run_profile(&args, profile_command),
+        };
+        if let Err(e) = result {
+            exit_with_error(&e, args.verbose, false);
+        }
+        return Ok(());
+    }
+
+    if args.here {
+        if let Err(e) = run_here_mode(&args) {
+            exit_with_error(&e, args.verbose, false);
+        }
+        return Ok(());
+    }
+
     if args.no_menu {
-        return run_non_interactive_mode(args).await;
+        let (verbose, quiet) = (args.verbose, args.quiet);
+        if let Err(e) = run_non_interactive_mode(args, cancellation).await {
+            exit_with_error(&e, verbose, quiet);
+        }
+        return Ok(());
     }
 
     // TTY check for interactive menu only
     if !atty::is(Stream::Stdout) || !atty::is(Stream::Stdin) {
-        eprintln!("\n❌ This CLI requires an interactive terminal (TTY) for interactive mode.\nIf you are using npx or a non-interactive shell, try running with the --no-menu flag and provide all required arguments.\nExample: npx git-timetraveler --no-menu --username <user> --token <token> --repo <repo> --year <year> ...");
+        eprintln!("\n❌ {}\nExample: npx git-timetraveler --no-menu --username <user> --token <token> --repo <repo> --year <year> ...", t!("banner.requires_tty"));
         std::process::exit(1);
     }
 
-    // Handle Ctrl+C gracefully
-    let running = Arc::new(AtomicBool::new(true));
-    let r = running.clone();
-    ctrlc::set_handler(move || {
-        println!("\nExiting. Goodbye!");
-        r.store(false, Ordering::SeqCst);
-        process::exit(0);
-    }).expect("Error setting Ctrl-C handler");
-
     // Clear screen for better presentation
     clear_screen();
 
@@ -451,27 +856,27 @@ _____ _                  _____                    _
     "#.bright_blue().bold());
 
     println!("{}", "🕰️  Git Time Traveler".bright_blue().bold());
-    println!("{}", "created by CHX".bright_magenta().italic());
-    println!("{}", "Create backdated commits to enhance your GitHub contribution graph\n".cyan());
+    println!("{}", t!("banner.created_by").bright_magenta().italic());
+    println!("{}\n", t!("banner.tagline").cyan());
 
     // Display helpful context information
-    println!("{}", "What this tool does:".bright_yellow());
-    println!("  • {} Create commits with custom timestamps", "✓".green());
-    println!("  • {} Push to GitHub repositories safely", "✓".green());
-    println!("  • {} Support single years or year ranges", "✓".green());
-    println!("  • {} Remember your preferences for future use", "✓".green());
+    println!("{}", t!("banner.features_title").bright_yellow());
+    println!("  • {} {}", "✓".green(), t!("banner.feature_timestamps"));
+    println!("  • {} {}", "✓".green(), t!("banner.feature_push"));
+    println!("  • {} {}", "✓".green(), t!("banner.feature_ranges"));
+    println!("  • {} {}", "✓".green(), t!("banner.feature_preferences"));
     println!();
 
     let menu_items = vec![
-        "🚀 Create backdated commit(s)",
-        "📖 View usage examples",
-        "🔑 Learn about GitHub tokens",
-        "⚙️  Configuration options",
-        "❌ Exit",
+        format!("🚀 {}", t!("menu.create")),
+        format!("📖 {}", t!("menu.examples")),
+        format!("🔑 {}", t!("menu.tokens")),
+        format!("⚙️  {}", t!("menu.configuration")),
+        format!("❌ {}", t!("menu.exit")),
     ];
 
     let selection = Select::with_theme(&ColorfulTheme::default())
-        .with_prompt("What would you like to do?")
+        .with_prompt(t!("menu.prompt"))
         .items(&menu_items)
         .default(0)
         .interact()
@@ -480,9 +885,8 @@ _____ _                  _____                    _
     match selection {
         0 => {
             // Use the new interactive prompts system with smart defaults
-            if let Err(e) = run_interactive_time_travel().await {
-                eprintln!("\n{}", format_error_for_user(&e));
-                std::process::exit(1);
+            if let Err(e) = run_interactive_time_travel(args.profile.as_deref(), args.author_pool.as_deref(), cancellation).await {
+                exit_with_error(&e, false, false);
             }
         }
         1 => {
@@ -495,7 +899,7 @@ _____ _                  _____                    _
             display_configuration_options();
         }
         4 => {
-            println!("\n{} {}", "👋".bright_blue(), "Thanks for using Git Time Traveler!".cyan());
+            println!("\n{} {}", "👋".bright_blue(), t!("menu.thanks").cyan());
             return Ok(());
         }
         _ => unreachable!(),
@@ -506,77 +910,86 @@ _____ _                  _____                    _
 
 /// Display usage examples and common patterns
 fn display_usage_examples() {
-    println!("\n{}", "📖 Usage Examples".bright_blue().bold());
-    println!("{}", "Here are common ways to use Git Time Traveler:\n".cyan());
+    println!("\n📖 {}", t!("examples.title").bright_blue().bold());
+    println!("{}\n", t!("examples.intro").cyan());
 
-    println!("{}", "Interactive Mode (Recommended):".bright_yellow());
+    println!("{}", t!("examples.interactive").bright_yellow());
     println!("  {}", "git-timetraveler".bright_green());
     println!("  {}", "npx git-timetraveler".bright_green());
-    println!("  → Guided prompts with smart defaults\n");
+    println!("  → {}\n", t!("examples.interactive_note"));
 
-    println!("{}", "Non-Interactive Mode:".bright_yellow());
+    println!("{}", t!("examples.non_interactive").bright_yellow());
     println!("  {}", "git-timetraveler --no-menu --username myuser --token ghp_xxx --repo myrepo --year 1990".bright_green());
     println!("  {}", "git-timetraveler --no-menu --username myuser --token ghp_xxx --repo myrepo --years 1990-1995".bright_green());
-    println!("  → Perfect for scripts and automation\n");
-
-    println!("{}", "Common Options:".bright_yellow());
-    println!("  {} Create commits for a single year", "--year 1990".bright_cyan());
-    println!("  {} Create commits across a range", "--years 1990-1995".bright_cyan());
-    println!("  {} Specify custom repository name", "--repo my-project".bright_cyan());
-    println!("  {} Set commit time (0-23)", "--hour 14".bright_cyan());
-    println!("  {} Force push (use with caution)", "--force".bright_cyan());
-
-    println!("\n{}", "💡 Pro Tips:".bright_yellow());
-    println!("  • The tool remembers your preferences between sessions");
-    println!("  • Use ranges like 1990-1995 for multiple years at once");
-    println!("  • Commits are created at 6 PM by default for realistic timing");
-    println!("  • Your GitHub token needs 'repo' permissions");
-
-    println!("\n{}", "Press Enter to continue...".dimmed());
+    println!("  → {}\n", t!("examples.non_interactive_note"));
+
+    println!("{}", t!("examples.options").bright_yellow());
+    println!("  {} {}", "--year 1990".bright_cyan(), t!("examples.option_year"));
+    println!("  {} {}", "--years 1990-1995".bright_cyan(), t!("examples.option_years"));
+    println!("  {} {}", "--repo my-project".bright_cyan(), t!("examples.option_repo"));
+    println!("  {} {}", "--hour 14".bright_cyan(), t!("examples.option_hour"));
+    println!("  {} {}", "--force".bright_cyan(), t!("examples.option_force"));
+    println!("  {} {}", "--lang es".bright_cyan(), t!("examples.option_lang"));
+    println!("  {} {}", "--profile work".bright_cyan(), t!("examples.option_profile"));
+
+    println!("\n💡 {}", t!("examples.tips").bright_yellow());
+    println!("  • {}", t!("examples.tip_preferences"));
+    println!("  • {}", t!("examples.tip_ranges"));
+    println!("  • {}", t!("examples.tip_hour"));
+    println!("  • {}", t!("examples.tip_token"));
+
+    println!("\n{}", t!("common.press_enter").dimmed());
     let _ = std::io::stdin().read_line(&mut String::new());
 }
 
 /// Display GitHub token creation help
 fn display_github_token_help() {
-    println!("\n{}", "🔑 GitHub Personal Access Token Setup".bright_blue().bold());
-    println!("{}", "You need a GitHub token to create and push to repositories.\n".cyan());
-
-    println!("{}", "Step-by-step instructions:".bright_yellow());
-    println!("  {} Go to: {}", "1.".bright_white(), "https://github.com/settings/tokens".bright_blue().underline());
-    println!("  {} Click: {}", "2.".bright_white(), "Generate new token (classic)".bright_green());
-    println!("  {} Set expiration: {}", "3.".bright_white(), "Choose your preferred duration".cyan());
-    println!("  {} Select scopes: {}", "4.".bright_white(), "Check 'repo' (Full control of private repositories)".bright_green());
-    println!("  {} Click: {}", "5.".bright_white(), "Generate token".bright_green());
-    println!("  {} Copy the token: {}", "6.".bright_white(), "Save it securely - you won't see it again!".yellow());
-
-    println!("\n{}", "Required Permissions:".bright_yellow());
-    println!("  {} {} - Create, read, and write repositories", "✓".green(), "repo".bright_cyan());
-    println!("  {} {} - Access public repositories (included in repo)", "✓".green(), "public_repo".bright_cyan());
-
-    println!("\n{}", "Security Notes:".bright_yellow());
-    println!("  • {} Never share your token with others", "⚠️".yellow());
-    println!("  • {} Store it securely (password manager recommended)", "🔒".blue());
-    println!("  • {} You can revoke it anytime from GitHub settings", "🔄".cyan());
-    println!("  • {} This tool stores it locally and never transmits it elsewhere", "🛡️".green());
-
-    println!("\n{}", "Press Enter to continue...".dimmed());
+    println!("\n🔑 {}", t!("tokens.title").bright_blue().bold());
+    println!("{}\n", t!("tokens.intro").cyan());
+
+    println!("{}", t!("tokens.steps").bright_yellow());
+    println!("  {} {}", "1.".bright_white(), t!("tokens.step_open", url = "https://github.com/settings/tokens".bright_blue().underline()));
+    println!("  {} {}", "2.".bright_white(), t!("tokens.step_generate", button = "Generate new token (classic)".bright_green()));
+    println!("  {} {}", "3.".bright_white(), t!("tokens.step_expiration", hint = t!("tokens.step_expiration_hint").cyan()));
+    println!("  {} {}", "4.".bright_white(), t!("tokens.step_scopes", hint = t!("tokens.step_scopes_hint").bright_green()));
+    println!("  {} {}", "5.".bright_white(), t!("tokens.step_confirm", button = "Generate token".bright_green()));
+    println!("  {} {}", "6.".bright_white(), t!("tokens.step_copy", hint = t!("tokens.step_copy_hint").yellow()));
+
+    println!("\n{}", t!("tokens.permissions").bright_yellow());
+    println!("  {} {} - {}", "✓".green(), "repo".bright_cyan(), t!("tokens.permission_repo"));
+    println!("  {} {} - {}", "✓".green(), "public_repo".bright_cyan(), t!("tokens.permission_public_repo"));
+
+    println!("\n{}", t!("tokens.security").bright_yellow());
+    println!("  • {} {}", "⚠️".yellow(), t!("tokens.security_share"));
+    println!("  • {} {}", "🔒".blue(), t!("tokens.security_store"));
+    println!("  • {} {}", "🔄".cyan(), t!("tokens.security_revoke"));
+    println!("  • {} {}", "🛡️".green(), t!("tokens.security_local"));
+
+    println!("\n{}", t!("common.press_enter").dimmed());
     let _ = std::io::stdin().read_line(&mut String::new());
 }
 
 /// Run the non-interactive mode with comprehensive argument validation and processing
-async fn run_non_interactive_mode(args: Args) -> Result<()> {
+async fn run_non_interactive_mode(args: Args, cancellation: CancellationToken) -> Result<()> {
     // Validate required arguments
     let validation_errors = validate_non_interactive_args(&args);
     if !validation_errors.is_empty() {
-        eprintln!("{} {}", "❌ Validation errors:".red().bold(), "The following issues were found:");
+        if error_format() == ErrorFormat::Json {
+            exit_with_usage_error(&validation_errors.join("; "));
+        }
+        eprintln!("❌ {} {}", t!("run.validation_errors").red().bold(), t!("run.issues_found"));
         for error in &validation_errors {
             eprintln!("  • {}", error.red());
         }
-        eprintln!("\n{}", "Example usage:".bright_yellow());
+        eprintln!("\n{}", t!("run.example_usage").bright_yellow());
         eprintln!("  {}", "git-timetraveler --no-menu --username myuser --token ghp_xxx --repo myrepo --year 1990".bright_green());
         eprintln!("  {}", "git-timetraveler --no-menu --username myuser --token ghp_xxx --repo myrepo --years 1990-1995".bright_green());
-        eprintln!("\n{}", "For more help, run: git-timetraveler --help".cyan());
-        std::process::exit(1);
+        eprintln!("\n{}", t!("run.more_help").cyan());
+        std::process::exit(exit_codes::INVALID_INPUT);
+    }
+
+    if let Some(ref schedule_path) = args.schedule {
+        return run_schedule_mode(&args, schedule_path, cancellation).await;
     }
 
     // Parse years from arguments
@@ -584,13 +997,11 @@ async fn run_non_interactive_mode(args: Args) -> Result<()> {
 
     // Validate year range
     if years.is_empty() {
-        eprintln!("❌ No valid years specified");
-        std::process::exit(1);
+        exit_with_usage_error(&t!("run.no_years"));
     }
 
     if years.len() > 50 {
-        eprintln!("❌ Too many years specified (maximum 50)");
-        std::process::exit(1);
+        exit_with_usage_error(&t!("run.too_many_years", max = 50));
     }
 
     // Extract required values (already validated)
@@ -613,14 +1024,20 @@ async fn run_non_interactive_mode(args: Args) -> Result<()> {
     } else {
         None // Use default time traveler identity
     };
+    // Author flags win, the author pool covers its dates, and the profile's author fills in the rest
+    let (explicit_author, fallback_author) = if args.has_author_flags() {
+        (author_identity, None)
+    } else {
+        (None, author_identity)
+    };
+    let author_pool = load_author_pool(&args)?;
 
     // Set up output verbosity
     let verbose = args.verbose;
     let quiet = args.quiet;
 
     if verbose && quiet {
-        eprintln!("❌ Cannot use both --verbose and --quiet flags");
-        std::process::exit(1);
+        exit_with_usage_error(&t!("run.verbose_and_quiet"));
     }
 
     // Create configurations for all years
@@ -633,20 +1050,47 @@ async fn run_non_interactive_mode(args: Args) -> Result<()> {
             args.hour,
             username.clone(),
             token.clone(),
-            Some(repo_name.clone()),
+            if args.repo_per_year { None } else { Some(repo_name.clone()) },
             args.branch.clone(),
-            author_identity.clone(),
-        ).context("Failed to create time travel configuration")?;
+            resolve_author(
+                explicit_author.as_ref(),
+                author_pool.as_ref(),
+                chrono::NaiveDate::from_ymd_opt(*year as i32, args.month, args.day),
+                fallback_author.as_ref(),
+            ),
+        ).context("Failed to create time travel configuration")?
+            .with_owner(args.owner.clone())?
+            .with_cancellation(cancellation.clone());
         configs.push(config);
     }
 
+    // Commits for all years are stacked on one branch and delivered in a single pull request
+    let pull_request_options = if args.via_pr {
+        Some(PullRequestOptions::for_years(&years, args.auto_merge))
+    } else {
+        None
+    };
+
+    let lifecycle = RepositoryLifecycle::new(
+        args.topics.clone(),
+        args.homepage.clone(),
+        args.description.clone(),
+        args.visibility.clone(),
+        args.archive,
+    )?;
+
     // Handle dry run mode
     if args.dry_run {
-        let confirmed = display_and_confirm_dry_run(&configs, false)
+        let confirmed = display_and_confirm_dry_run_with_pr(&configs, pull_request_options.as_ref(), Some(&lifecycle), false)
             .context("Failed to display dry run information")?;
 
+        if let Some(ref preview_path) = args.preview_out {
+            let plan = DryRunExecutor::new(DryRunConfig::default()).create_plan(&configs)?;
+            write_calendar_preview(&plan, preview_path, args.preview_overlay.as_deref())?;
+        }
+
         if !confirmed && !args.yes {
-            println!("\n{} {}", "❌".red(), "Dry run completed - use --yes to proceed without confirmation".yellow());
+            println!("\n{} {}", "❌".red(), t!("run.dry_run_completed").yellow());
         }
         return Ok(());
     }
@@ -663,99 +1107,1120 @@ async fn run_non_interactive_mode(args: Args) -> Result<()> {
     };
 
     // Display operation summary (unless quiet)
+    let repositories = group_by_repository(&configs);
     if let Some(ref pb) = progress_bar {
-        pb.display_summary(&years, &repo_name, &username);
+        let names: Vec<String> = repositories.iter()
+            .map(|(repository, group)| format!("{}/{}", group[0].owner(), repository))
+            .collect();
+        pb.display_summary(&years, &names, &username);
     }
 
     // Show confirmation for potentially destructive operations (unless --yes is used)
     if !args.yes && (args.force || years.len() > 5) {
-        println!("\n{}", "⚠️  Confirmation Required".yellow().bold());
+        println!("\n⚠️  {}", t!("dry_run.confirmation_required").yellow().bold());
 
         if args.force {
-            println!("  {} Force push will overwrite remote history", "•".red());
+            println!("  {} {}", "•".red(), t!("banner.force_push_warning"));
         }
         if years.len() > 5 {
-            println!("  {} Processing {} years may take significant time", "•".yellow(), years.len());
+            println!("  {} {}", "•".yellow(), t!("banner.many_years_warning", count = years.len()));
         }
 
-        println!("\n{}", "Use --yes flag to skip this confirmation or run interactively for more control".cyan());
-        println!("{} {}", "❌".red(), "Operation cancelled - confirmation required".red());
+        println!("\n{}", t!("run.skip_confirmation").cyan());
+        println!("{} {}", "❌".red(), t!("run.confirmation_cancelled").red());
         std::process::exit(1);
     }
 
     if !quiet {
-        println!("{}", "🚀 Starting non-interactive time travel operation...".bright_green().bold());
+        println!("🚀 {}", t!("banner.starting").bright_green().bold());
     }
 
-    // Process each year
-    for (index, year) in years.iter().enumerate() {
-        let year_progress = if let Some(ref pb) = progress_bar {
-            if years.len() > 1 {
-                pb.create_year_progress(*year)
+    if repositories.len() > 1 {
+        // Independent repositories run concurrently, years of one repository in order
+        run_repositories_concurrently(&configs, args.jobs as usize, args.force, progress_bar.as_ref(), quiet, verbose).await;
+    } else {
+        // Process each year
+        for (index, year) in years.iter().enumerate() {
+            let year_progress = if let Some(ref pb) = progress_bar {
+                if years.len() > 1 {
+                    pb.create_year_progress(*year)
+                } else {
+                    None
+                }
             } else {
                 None
+            };
+
+            if !quiet {
+                println!("\n{} {} ({}/{})",
+                    t!("banner.processing_year").cyan(),
+                    year.to_string().bright_yellow(),
+                    (index + 1).to_string().bright_white(),
+                    years.len().to_string().bright_white()
+                );
             }
-        } else {
-            None
-        };
 
-        if !quiet {
-            println!("\n{} {} ({}/{})",
-                "Processing year:".cyan(),
-                year.to_string().bright_yellow(),
-                (index + 1).to_string().bright_white(),
-                years.len().to_string().bright_white()
-            );
-        }
+            let config = &configs[index];
 
-        let config = &configs[index];
+            // Execute with appropriate progress callback
+            let year_wrapper;
+            let progress_callback: Option<&dyn ProgressCallback> = if let Some(ref year_pb) = year_progress {
+                year_wrapper = YearProgressWrapper { pb: year_pb.clone() };
+                Some(&year_wrapper)
+            } else if let Some(ref pb) = progress_bar {
+                Some(pb)
+            } else {
+                None
+            };
 
-        // Execute with appropriate progress callback
-        let year_wrapper;
-        let progress_callback: Option<&dyn ProgressCallback> = if let Some(ref year_pb) = year_progress {
-            year_wrapper = YearProgressWrapper { pb: year_pb.clone() };
-            Some(&year_wrapper)
-        } else if let Some(ref pb) = progress_bar {
-            Some(pb)
-        } else {
-            None
-        };
+            let push_branch = pull_request_options.as_ref().map(|options| options.branch.as_str());
+            if let Err(e) = create_time_traveled_repo_on_branch(config, push_branch, progress_callback, args.force, false).await {
+                if is_cancellation(&e) {
+                    report_interruption(&years[..index], &years[index..]);
+                }
+                exit_with_error(&e, verbose, quiet);
+            }
 
-        if let Err(e) = create_time_traveled_repo_with_options(config, progress_callback, args.force, false).await {
-            if !quiet {
-                eprintln!("\n{}", format_error_for_user(&e));
+            if let Some(year_pb) = year_progress {
+                year_pb.finish_with_message(format!("✅ {}", t!("banner.year_complete", year = year)));
             }
-            if verbose {
-                eprintln!("\n{} {:?}", "Debug info:".dimmed(), e);
+
+            if !quiet {
+                println!("✅ {}", t!("banner.year_created", year = year.to_string().bright_yellow()).green());
+            } else if verbose {
+                println!("{}", t!("banner.year_complete", year = year));
             }
-            std::process::exit(1);
         }
+    }
 
-        if let Some(year_pb) = year_progress {
-            year_pb.finish_with_message(format!("✅ Year {} complete", year));
+    if let Some(ref options) = pull_request_options {
+        match open_pull_request(&configs[0], options).await {
+            Ok(pull_request) => {
+                if !quiet {
+                    println!("\n{} {} {}", "🔀".green(), t!("run.pull_request_opened").green(), pull_request.html_url.bright_blue().underline());
+                    if options.auto_merge {
+                        println!("  {} {}", t!("run.auto_merge").cyan(), t!("run.auto_merge_enabled").bright_yellow());
+                    }
+                } else {
+                    println!("{}", pull_request.html_url);
+                }
+            }
+            Err(e) => {
+                report_error(&e, verbose, false);
+                if error_format() == ErrorFormat::Text {
+                    eprintln!("{} {}", t!("run.pushed_to_branch").yellow(), options.branch.bright_cyan());
+                }
+                std::process::exit(exit_code_for(&e));
+            }
         }
+    }
 
+    if !lifecycle.is_empty() {
+        for (_, group) in &repositories {
+            if let Err(e) = apply_repository_lifecycle(&group[0], &lifecycle).await {
+                report_error(&e, verbose, false);
+                if error_format() == ErrorFormat::Text {
+                    eprintln!("{}", t!("run.settings_failed").yellow());
+                }
+                std::process::exit(exit_code_for(&e));
+            }
+        }
         if !quiet {
-            println!("✅ {} {} {}",
-                "Commit for year".green(),
-                year.to_string().bright_yellow(),
-                "created successfully!".green()
-            );
-        } else if verbose {
-            println!("Year {} processed successfully", year);
+            for change in lifecycle.describe_changes() {
+                println!("{} {}", "🏷️".cyan(), change);
+            }
+            if lifecycle.archive {
+                println!("{} {}", "📦".yellow(), t!("run.archived").yellow());
+            }
+        }
+    }
+
+    if args.verify {
+        let push_branch = pull_request_options.as_ref().map_or(args.branch.as_str(), |options| options.branch.as_str());
+        for (_, group) in &repositories {
+            if let Some(target) = VerificationTarget::for_configs(group, push_branch) {
+                verify_and_report(&group[0], &target, args.verify_wait, quiet).await;
+            }
         }
     }
 
     // Display completion summary (unless quiet)
     if let Some(ref pb) = progress_bar {
-        pb.display_completion(&years, &repo_name, &username);
+        for (repository, group) in &repositories {
+            let group_years: Vec<u32> = group.iter().map(|config| config.year).collect();
+            pb.display_completion(&group_years, repository, group[0].owner());
+        }
     } else if !quiet {
-        println!("✅ All {} years processed successfully!", years.len());
+        println!("✅ {}", t!("run.all_years_processed", count = years.len()));
+    }
+
+    Ok(())
+}
+
+/// Process several repositories concurrently and exit with a per-repository report on failure
+async fn run_repositories_concurrently(
+    configs: &[TimeTravelConfig],
+    jobs: usize,
+    force: bool,
+    progress_bar: Option<&CliProgressBar>,
+    quiet: bool,
+    verbose: bool,
+) {
+    let outcomes = create_time_traveled_repos(configs, jobs, None, force, |repository, steps| {
+        let pb = progress_bar?.create_repository_progress(repository, steps)?;
+        Some(Box::new(RepositoryProgressWrapper {
+            pb,
+            overall: progress_bar?.pb.clone(),
+        }) as Box<dyn ProgressCallback>)
+    }).await;
+
+    let failed: Vec<_> = outcomes.iter().filter(|outcome| outcome.error.is_some()).collect();
+    if !quiet {
+        println!();
+        for outcome in &outcomes {
+            let years = outcome.completed_years.iter().map(u32::to_string).collect::<Vec<_>>().join(", ");
+            if outcome.error.is_none() {
+                println!("✅ {} {} {}", outcome.repository.bright_green(), t!("run.pushed_years").green(), years.bright_yellow());
+            } else {
+                println!("❌ {} {} {}", outcome.repository.bright_red(), t!("run.pushed_years").yellow(),
+                    if years.is_empty() { t!("run.none") } else { years }.bright_yellow());
+            }
+        }
+    }
+
+    if failed.is_empty() {
+        return;
+    }
+    if failed.iter().any(|outcome| outcome.error.as_ref().is_some_and(is_cancellation)) {
+        let pushed: Vec<u32> = outcomes.iter().flat_map(|outcome| outcome.completed_years.iter().copied()).collect();
+        let remaining: Vec<u32> = configs.iter().map(|config| config.year).filter(|year| !pushed.contains(year)).collect();
+        report_interruption(&pushed, &remaining);
+    }
+    if error_format() == ErrorFormat::Json {
+        let repositories: Vec<_> = failed.iter().filter_map(|outcome| {
+            let e = outcome.error.as_ref()?;
+            tracing::error!(target: "cli", repository = %outcome.repository, error = ?e, "Repository failed");
+            let mut envelope = error_envelope(e);
+            envelope["repository"] = outcome.repository.clone().into();
+            envelope["pushed_years"] = outcome.completed_years.clone().into();
+            envelope["not_attempted"] = outcome.skipped_years.clone().into();
+            Some(envelope)
+        }).collect();
+        // The first failure decides the exit code; every failure is listed
+        let first = failed[0].error.as_ref().expect("failed outcomes carry an error");
+        let mut envelope = error_envelope(first);
+        envelope["repositories"] = repositories.into();
+        eprintln!("{}", envelope);
+        std::process::exit(exit_code_for(first));
+    }
+    let exit_code = failed[0].error.as_ref().map(exit_code_for).unwrap_or(exit_codes::FAILURE);
+    for outcome in failed {
+        if let Some(ref e) = outcome.error {
+            eprintln!("\n{} {}", t!("run.repository").red(), outcome.repository.bright_white());
+            tracing::error!(target: "cli", repository = %outcome.repository, error = ?e, "Repository failed");
+            eprintln!("{}", format_error_for_user(e));
+            if !outcome.skipped_years.is_empty() {
+                eprintln!("{} {:?}", t!("run.not_attempted").yellow(), outcome.skipped_years);
+            }
+            if verbose {
+                eprintln!("\n{} {}", t!("run.debug_info").dimmed(), debug_report(e));
+            }
+        }
+    }
+    std::process::exit(exit_code);
+}
+
+/// Report which years made it to GitHub before an interruption and exit
+fn report_interruption(pushed: &[u32], remaining: &[u32]) -> ! {
+    let list = |years: &[u32]| if years.is_empty() {
+        t!("run.none")
+    } else {
+        years.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+    };
+    remove_tracked_temp_dirs();
+    if error_format() == ErrorFormat::Json {
+        let mut envelope = error_envelope(&TimeTravelError::cancelled("time travel").into());
+        envelope["pushed_years"] = pushed.to_vec().into();
+        envelope["not_pushed_years"] = remaining.to_vec().into();
+        eprintln!("{}", envelope);
+    } else {
+        eprintln!("\n{} {}", "⏹️".yellow(), t!("run.interrupted").yellow().bold());
+        eprintln!("  {} {}", t!("run.pushed").green(), list(pushed).bright_yellow());
+        eprintln!("  {} {}", t!("run.not_pushed").red(), list(remaining).bright_yellow());
+    }
+    std::process::exit(exit_codes::CANCELLED);
+}
+
+/// Format selected with --error-format, set once at startup
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
+fn error_format() -> ErrorFormat {
+    ERROR_FORMAT.get().copied().unwrap_or(ErrorFormat::Text)
+}
+
+/// Log a failure and print it in the selected error format.
+/// `quiet` hides the text report; the JSON envelope is always printed for scripts.
+fn report_error(e: &anyhow::Error, verbose: bool, quiet: bool) {
+    tracing::error!(target: "cli", error = ?e, "Operation failed");
+    match error_format() {
+        ErrorFormat::Json => eprintln!("{}", error_envelope(e)),
+        ErrorFormat::Text => {
+            if !quiet {
+                eprintln!("\n{}", format_error_for_user(e));
+            }
+            if verbose {
+                eprintln!("\n{} {}", t!("run.debug_info").dimmed(), debug_report(e));
+            }
+        }
+    }
+}
+
+/// Report a failure and exit with the stable exit code of its category
+fn exit_with_error(e: &anyhow::Error, verbose: bool, quiet: bool) -> ! {
+    report_error(e, verbose, quiet);
+    std::process::exit(exit_code_for(e));
+}
+
+/// Report invalid command-line arguments and exit with the invalid-input code
+fn exit_with_usage_error(message: &str) -> ! {
+    if error_format() == ErrorFormat::Json {
+        let error = TimeTravelError::invalid_input("arguments", "", message, "Run git-timetraveler --help for usage");
+        let mut envelope = error_envelope(&error.into());
+        envelope["error"]["message"] = message.into();
+        eprintln!("{}", envelope);
+    } else {
+        eprintln!("❌ {}", message);
     }
+    std::process::exit(exit_codes::INVALID_INPUT);
+}
 
+/// Export the planned commits of a dry run as an SVG or HTML calendar
+fn write_calendar_preview(plan: &DryRunPlan, path: &std::path::Path, overlay: Option<&std::path::Path>) -> Result<()> {
+    let existing = match overlay {
+        Some(overlay_path) => {
+            let content = std::fs::read_to_string(overlay_path)
+                .with_context(|| format!("Failed to read contribution data {}", overlay_path.display()))?;
+            Some(ContributionCalendar::from_json(&content)?)
+        }
+        None => None,
+    };
+
+    ContributionCalendar::from_plan(plan).write_preview(path, existing.as_ref())?;
+    println!("{} {} {}", "🖼️".green(), t!("run.preview_written").green(), path.display().to_string().bright_white());
     Ok(())
 }
 
+/// Run the post-push contribution check and print the per-day report.
+/// A failed check is reported but does not fail the run, since the commits are already pushed.
+async fn verify_and_report(config: &TimeTravelConfig, target: &VerificationTarget, wait_secs: u64, quiet: bool) {
+    if !quiet && wait_secs > 0 {
+        println!("\n{} {}", "⏳".cyan(), t!("verify.waiting", seconds = wait_secs).dimmed());
+    }
+    match verify_time_travel_contributions(config, target, Duration::from_secs(wait_secs)).await {
+        Ok(report) => display_verification_report(&report, quiet),
+        Err(e) => {
+            eprintln!("\n{} {}", "⚠️".yellow(), t!("verify.failed").yellow());
+            tracing::warn!(target: "cli", error = ?e, "Verification failed");
+            eprintln!("{}", format_error_for_user(&e));
+        }
+    }
+}
+
+/// Print which traveled days show contributions on the profile
+fn display_verification_report(report: &VerificationReport, quiet: bool) {
+    if quiet {
+        for day in report.days.iter().filter(|day| !day.has_contributions()) {
+            println!("missing {} {}", day.date, day.reason.as_deref().unwrap_or(""));
+        }
+        return;
+    }
+
+    println!("\n🔎 {}", t!("verify.title").bright_blue().bold());
+    for day in &report.days {
+        if day.has_contributions() {
+            println!("  {} {} {}", "✅".green(), day.date.to_string().bright_yellow(),
+                t!("verify.contributions", count = day.contributions).dimmed());
+        } else {
+            println!("  {} {} {}", "❌".red(), day.date.to_string().bright_yellow(), t!("verify.no_contributions").red());
+            if let Some(ref reason) = day.reason {
+                println!("      {} {}", t!("verify.likely_reason").dimmed(), reason);
+            }
+        }
+    }
+    println!("  {} {}", t!("verify.result").cyan(), t!("verify.days_with_contributions",
+        count = report.with_contributions().to_string().bright_green(), total = report.days.len().to_string().bright_white()));
+    // Totals include other repositories, so a day with contributions does not prove this push counted
+    println!("  {}", t!("verify.counts_include_all").dimmed());
+}
+
+/// Commit the staged files of the repository in the current directory with a backdated date
+fn run_here_mode(args: &Args) -> Result<()> {
+    let mut detector = GitContextDetector::new();
+    let context = detector.detect_context(None)?;
+    let repo_path = match (context.is_git_repo, context.workdir.clone()) {
+        (true, Some(path)) => path,
+        _ => anyhow::bail!("--here must be run inside a Git working tree"),
+    };
+
+    let git_ops = GitOperations::new();
+    let repo = git_ops.open_repository(&repo_path)?;
+    let branch = git_ops.ensure_safe_to_commit(&repo)?;
+
+    if !context.has_staged_files() {
+        anyhow::bail!("No staged changes - use 'git add' to stage the files you want to commit");
+    }
+
+    let year = validation::validate_year(args.year)?;
+    let month = validation::validate_month(args.month)?;
+    let day = validation::validate_day(args.day)?;
+    let hour = validation::validate_hour(args.hour)?;
+    let timestamp = chrono::Utc.with_ymd_and_hms(year as i32, month, day, hour, 0, 0)
+        .single()
+        .ok_or_else(|| anyhow::anyhow!("{}-{:02}-{:02} is not a valid date", year, month, day))?;
+
+    // Author flags win over the author pool, which wins over the profile's author and Git config
+    let pool_author = load_author_pool(args)?
+        .filter(|_| !args.has_author_flags())
+        .and_then(|pool| pool.author_on(timestamp.date_naive()).cloned());
+    let author = match (pool_author, &args.author_name, &args.author_email, &context.user_identity) {
+        (Some(identity), _, _, _) => identity,
+        (None, Some(name), Some(email), _) => GitIdentity { name: name.clone(), email: email.clone() },
+        (None, name, email, Some(identity)) => GitIdentity {
+            name: name.clone().unwrap_or_else(|| identity.name.clone()),
+            email: email.clone().unwrap_or_else(|| identity.email.clone()),
+        },
+        _ => anyhow::bail!("No author identity - pass --author-name and --author-email or set user.name and user.email"),
+    };
+
+    let message = args.message.clone()
+        .unwrap_or_else(|| "Time travel commit for {year}".to_string())
+        .replace("{year}", &year.to_string());
+
+    let github_remote = if args.push {
+        Some(context.get_github_remote().cloned()
+            .ok_or_else(|| anyhow::anyhow!("No GitHub remote found to push to"))?)
+    } else {
+        None
+    };
+
+    if !args.quiet {
+        println!("{}", "📌 Backdated Commit in Current Repository".bright_blue().bold());
+        println!("  {} {}", "Repository:".cyan(), repo_path.display().to_string().bright_green());
+        println!("  {} {}", "Branch:".cyan(), branch.bright_cyan());
+        println!("  {} {}", "Date:".cyan(), timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string().bright_magenta());
+        println!("  {} {} <{}>", "Author:".cyan(), author.name.bright_white(), author.email.dimmed());
+        println!("  {} {}", "Message:".cyan(), message.bright_white());
+        println!("  {}", "Staged files:".cyan());
+        for file in &context.staged_files {
+            println!("    {} {}", "•".green(), file.display().to_string().bright_yellow());
+        }
+        if let Some(ref remote) = github_remote {
+            println!("  {} {} ({})", "Push to:".cyan(), remote.name.bright_green(), remote.url.dimmed());
+        }
+        println!();
+    }
+
+    if args.dry_run {
+        println!("{} {}", "🔍".blue(), "Dry run - no commit was created".dimmed());
+        return Ok(());
+    }
+
+    if !args.yes && atty::is(Stream::Stdin) {
+        let confirm = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Create this backdated commit?")
+            .default(true)
+            .interact()
+            .context("Failed to get confirmation")?;
+        if !confirm {
+            println!("\n{} {}", "❌".red(), "Operation cancelled by user".red());
+            return Ok(());
+        }
+    }
+
+    let commit_config = TimeTravelCommitConfig {
+        timestamp,
+        author: author.clone(),
+        committer: author,
+        message,
+        files_to_add: Vec::new(),
+    };
+    let result = git_ops.commit_staged_changes(&repo, &commit_config)
+        .context("Failed to create backdated commit")?;
+
+    if !args.quiet {
+        println!("✅ {} {} {}", "Created commit".green(), result.commit_id[..7].bright_yellow(),
+            format!("on {}", branch).green());
+    }
+
+    if let Some(remote) = github_remote {
+        let credentials = match (&args.username, &args.token) {
+            (Some(username), Some(token)) => Some(GitCredentials {
+                username: username.clone(),
+                token: token.clone(),
+            }),
+            _ => None,
+        };
+
+        if !args.quiet {
+            println!("{} {} {} {}", "⬆️".blue(), "Pushing".cyan(), branch.bright_green(), format!("to {}", remote.name).dimmed());
+        }
+        git_ops.push_to_remote(&repo, &remote.name, &branch, credentials.as_ref(), args.force)
+            .with_context(|| format!("Failed to push branch '{}'", branch))?;
+    }
+
+    Ok(())
+}
+
+/// Load the --author-pool file, if one was given
+fn load_author_pool(args: &Args) -> Result<Option<AuthorPool>> {
+    args.author_pool.as_deref().map(AuthorPool::from_path).transpose()
+}
+
+/// Import a commit schedule file and create all of its commits in one repository
+async fn run_schedule_mode(args: &Args, schedule_path: &std::path::Path, cancellation: CancellationToken) -> Result<()> {
+    let mut schedule = match CommitSchedule::from_path(schedule_path) {
+        Ok(schedule) => schedule,
+        Err(e) => exit_with_error(&e, args.verbose, false),
+    };
+    // Rows without an author of their own take the pool's identity for their date,
+    // unless author flags were given for them
+    match load_author_pool(args) {
+        Ok(Some(pool)) if !args.has_author_flags() => pool.apply_to_schedule(&mut schedule),
+        Ok(_) => {}
+        Err(e) => exit_with_error(&e, args.verbose, false),
+    }
+
+    let years = schedule.years();
+    let username = args.username.clone().unwrap();
+    let token = args.token.clone().unwrap();
+    let repo_name = args.repo.clone().unwrap_or_else(|| {
+        format!("timetravel-{}-{}", years.iter().min().unwrap(), years.iter().max().unwrap())
+    });
+
+    let author_identity = if args.author_name.is_some() || args.author_email.is_some() {
+        Some(git_timetraveler::GitIdentity {
+            name: args.author_name.clone().unwrap_or_else(|| "Git Time Traveler".to_string()),
+            email: args.author_email.clone().unwrap_or_else(|| "timetraveler@example.com".to_string()),
+        })
+    } else {
+        None
+    };
+
+    // Repository and credential settings are shared with the year-based pipeline
+    let config = TimeTravelConfig::new(
+        years[0],
+        args.month,
+        args.day,
+        args.hour,
+        username.clone(),
+        token,
+        Some(repo_name.clone()),
+        args.branch.clone(),
+        author_identity,
+    ).context("Failed to create time travel configuration")?
+        .with_owner(args.owner.clone())?
+        .with_cancellation(cancellation);
+
+    if args.dry_run {
+        create_scheduled_repo_with_options(&config, &schedule, None, args.force, true).await?;
+        if let Some(ref preview_path) = args.preview_out {
+            let commits = schedule.to_commit_configs(&config.commit_author());
+            let plan = DryRunExecutor::new(DryRunConfig::default()).create_schedule_plan(&config, &commits, args.force)?;
+            write_calendar_preview(&plan, preview_path, args.preview_overlay.as_deref())?;
+        }
+        return Ok(());
+    }
+
+    let total_commits = schedule.total_commits();
+    let progress_bar = if !args.quiet {
+        println!("\n{}", "📋 Imported Schedule".bright_blue().bold());
+        println!("  {} {}", "File:".cyan(), schedule_path.display().to_string().bright_green());
+        println!("  {} {} rows, {} commits", "Schedule:".cyan(),
+            schedule.entries.len().to_string().bright_white(), total_commits.to_string().bright_yellow());
+        Some(CliProgressBar::with_steps(schedule_steps(total_commits)))
+    } else {
+        None
+    };
+    if let Some(ref pb) = progress_bar {
+        pb.display_summary(&years, &[format!("{}/{}", config.owner(), repo_name)], &username);
+    }
+
+    if !args.yes && (args.force || total_commits > 50) {
+        println!("\n{}", "⚠️  Confirmation Required".yellow().bold());
+        if args.force {
+            println!("  {} Force push will overwrite remote history", "•".red());
+        }
+        if total_commits > 50 {
+            println!("  {} The schedule creates {} commits", "•".yellow(), total_commits);
+        }
+        println!("\n{}", "Use --yes flag to skip this confirmation or run with --dry-run to review the schedule".cyan());
+        println!("{} {}", "❌".red(), "Operation cancelled - confirmation required".red());
+        std::process::exit(1);
+    }
+
+    let progress_callback = progress_bar.as_ref().map(|pb| pb as &dyn ProgressCallback);
+    if let Err(e) = create_scheduled_repo_with_options(&config, &schedule, progress_callback, args.force, false).await {
+        if is_cancellation(&e) {
+            // Scheduled commits are pushed together, so nothing reached GitHub
+            report_interruption(&[], &years);
+        }
+        exit_with_error(&e, args.verbose, args.quiet);
+    }
+
+    if args.verify {
+        let commits = schedule.to_commit_configs(&config.commit_author());
+        verify_and_report(&config, &VerificationTarget::for_commits(&config, &commits), args.verify_wait, args.quiet).await;
+    }
+
+    if !args.quiet {
+        println!("\n{}", "🎉 Schedule Imported!".bright_green().bold());
+        println!("  {} commits created across {} years",
+            total_commits.to_string().bright_yellow(), years.len().to_string().bright_magenta());
+        println!("  {} https://github.com/{}/{}",
+            "View at:".cyan(), config.owner().bright_blue(), repo_name.bright_blue().underline());
+    }
+
+    Ok(())
+}
+
+/// Shift the dates of every commit in an existing local repository
+fn run_shift(args: &Args, shift_args: ShiftArgs) -> Result<()> {
+    let shift = match (&shift_args.offset, &shift_args.window) {
+        (Some(offset), _) => DateShift::Offset(parse_offset(offset)?),
+        (None, Some(window)) => {
+            let (start, end) = DateParser::new()?.parse_window(window)?;
+            DateShift::Window { start, end }
+        }
+        (None, None) => anyhow::bail!("Specify either --offset or --window"),
+    };
+    let config = HistoryShiftConfig {
+        shift,
+        shift_committer: shift_args.committer_dates,
+    };
+
+    // Locate the repository the same way context detection does
+    let mut detector = GitContextDetector::new();
+    let context = detector.detect_context(Some(&shift_args.path))?;
+    let repo_path = match (context.is_git_repo, context.workdir.or(context.repo_path)) {
+        (true, Some(path)) => path,
+        _ => anyhow::bail!("No Git repository found at {}", shift_args.path.display()),
+    };
+
+    let git_ops = GitOperations::new();
+    let repo = git_ops.open_repository(&repo_path)?;
+    let preview = git_ops.preview_history_shift(&repo, &config)?;
+
+    if !args.quiet {
+        println!("{}", "🕰️  History Shift".bright_blue().bold());
+        println!("  {} {}", "Repository:".cyan(), repo_path.display().to_string().bright_green());
+        println!("  {} {}", "Commits:".cyan(), preview.len().to_string().bright_white());
+        if let (Some(first), Some(last)) = (
+            preview.iter().min_by_key(|c| c.original_author_time),
+            preview.iter().max_by_key(|c| c.original_author_time),
+        ) {
+            println!("  {} {} → {}", "Original span:".cyan(),
+                first.original_author_time.format("%Y-%m-%d"), last.original_author_time.format("%Y-%m-%d"));
+            println!("  {} {} → {}", "New span:".cyan(),
+                first.new_author_time.format("%Y-%m-%d").to_string().bright_magenta(),
+                last.new_author_time.format("%Y-%m-%d").to_string().bright_magenta());
+        }
+        if args.verbose || args.dry_run {
+            println!();
+            for commit in &preview {
+                println!("  {} {} → {} {}",
+                    commit.original_id[..7].dimmed(),
+                    commit.original_author_time.format("%Y-%m-%d %H:%M"),
+                    commit.new_author_time.format("%Y-%m-%d %H:%M").to_string().bright_yellow(),
+                    commit.summary.dimmed());
+            }
+        }
+        println!();
+    }
+
+    if args.dry_run {
+        println!("{} {}", "🔍".blue(), "Dry run - no commits were rewritten".dimmed());
+        return Ok(());
+    }
+
+    if !args.yes {
+        if !atty::is(Stream::Stdin) {
+            println!("{}", "Use --yes flag to skip this confirmation or run interactively".cyan());
+            anyhow::bail!("Confirmation required to rewrite history");
+        }
+        let confirm = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Rewrite the dates of all commits above? Commit IDs will change")
+            .default(false)
+            .interact()
+            .context("Failed to get confirmation")?;
+        if !confirm {
+            println!("\n{} {}", "❌".red(), "Operation cancelled by user".red());
+            return Ok(());
+        }
+    }
+
+    let result = git_ops.shift_history(&repo, &config)
+        .context("Failed to shift repository history")?;
+
+    if !args.quiet {
+        println!("✅ {} {} {}", "Rewrote".green(), result.commits.len().to_string().bright_yellow(), "commits".green());
+        for reference in &result.refs_updated {
+            println!("  {} {}", "Updated:".cyan(), reference.bright_green());
+        }
+    }
+
+    if shift_args.push {
+        let credentials = match (&args.username, &args.token) {
+            (Some(username), Some(token)) => Some(GitCredentials {
+                username: username.clone(),
+                token: token.clone(),
+            }),
+            _ => None,
+        };
+
+        for branch in &result.branches_updated {
+            if !args.quiet {
+                println!("{} {} {}", "⬆️".blue(), "Pushing".cyan(), branch.bright_green());
+            }
+            git_ops.push_to_remote(&repo, &shift_args.remote, branch, credentials.as_ref(), args.force)
+                .with_context(|| format!("Failed to push branch '{}'", branch))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Rewrite the dates of selected commits in the current repository
+fn run_redate(args: &Args, redate_args: RedateArgs) -> Result<()> {
+    let mut detector = GitContextDetector::new();
+    let context = detector.detect_context(None)?;
+    let repo_path = match (context.is_git_repo, context.workdir.clone()) {
+        (true, Some(path)) => path,
+        _ => anyhow::bail!("redate must be run inside a Git working tree"),
+    };
+
+    if !context.is_clean() {
+        anyhow::bail!("Working tree has uncommitted changes ({}) - commit or stash them before re-dating", context.summary());
+    }
+
+    // A schedule file assigns one timestamp per commit; anything else is a date
+    let schedule_path = std::path::Path::new(&redate_args.to);
+    let target = if schedule_path.is_file() {
+        let schedule = CommitSchedule::from_path(schedule_path)?;
+        let placeholder = GitIdentity { name: String::new(), email: String::new() };
+        RedateTarget::Schedule(schedule.to_commit_configs(&placeholder).iter().map(|c| c.timestamp).collect())
+    } else {
+        RedateTarget::Date(schedule::parse_datetime(&redate_args.to)?)
+    };
+
+    let config = RedateConfig {
+        range: redate_args.range.clone(),
+        target,
+        redate_committer: redate_args.committer_dates,
+    };
+
+    let git_ops = GitOperations::new();
+    let repo = git_ops.open_repository(&repo_path)?;
+    let preview = git_ops.preview_redate(&repo, &config)?;
+
+    if !args.quiet {
+        println!("{}", "🕰️  Re-date Commits".bright_blue().bold());
+        println!("  {} {}", "Repository:".cyan(), repo_path.display().to_string().bright_green());
+        println!("  {} {} ({} commits)", "Range:".cyan(), redate_args.range.bright_white(), preview.len());
+        println!();
+        for commit in &preview {
+            println!("  {} {} → {} {}",
+                commit.original_id[..7].dimmed(),
+                commit.original_author_time.format("%Y-%m-%d %H:%M"),
+                commit.new_author_time.format("%Y-%m-%d %H:%M").to_string().bright_yellow(),
+                commit.summary.dimmed());
+        }
+        println!();
+    }
+
+    if args.dry_run {
+        println!("{} {}", "🔍".blue(), "Dry run - no commits were rewritten".dimmed());
+        return Ok(());
+    }
+
+    if !args.yes {
+        if !atty::is(Stream::Stdin) {
+            println!("{}", "Use --yes flag to skip this confirmation or run interactively".cyan());
+            anyhow::bail!("Confirmation required to rewrite history");
+        }
+        let confirm = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Rewrite these commits and rebase their descendants?")
+            .default(false)
+            .interact()
+            .context("Failed to get confirmation")?;
+        if !confirm {
+            println!("\n{} {}", "❌".red(), "Operation cancelled by user".red());
+            return Ok(());
+        }
+    }
+
+    let result = git_ops.redate_commits(&repo, &config)
+        .context("Failed to re-date commits")?;
+
+    if !args.quiet {
+        println!("✅ {} {} {}", "Re-dated".green(), result.commits.len().to_string().bright_yellow(), "commits".green());
+        for reference in &result.refs_updated {
+            println!("  {} {}", "Updated:".cyan(), reference.bright_green());
+        }
+        for backup in &result.backup_refs {
+            println!("  {} {}", "Backup:".cyan(), backup.dimmed());
+        }
+        println!("\n{}", "Restore a branch with: git reset --hard <backup ref>".dimmed());
+    }
+
+    Ok(())
+}
+
+/// Mirror matching commits from local repositories into a target repository
+fn run_mirror(args: &Args, mirror_args: MirrorArgs) -> Result<()> {
+    let message_mode: MessageMode = mirror_args.messages.parse()?;
+
+    let target = GitOperations::new().open_repository(&mirror_args.target)
+        .with_context(|| format!("Failed to open target repository {}", mirror_args.target.display()))?;
+
+    let mut detector = GitContextDetector::new();
+    let target_context = detector.detect_context(Some(&mirror_args.target))?;
+
+    // Without explicit emails, mirror the commits of whoever owns the target
+    let author_emails = if mirror_args.author_emails.is_empty() {
+        match target_context.user_identity {
+            Some(ref identity) => vec![identity.email.clone()],
+            None => anyhow::bail!("No author email to match - pass --author-email or set user.email in Git config"),
+        }
+    } else {
+        mirror_args.author_emails.clone()
+    };
+
+    let (since, until) = match mirror_args.range {
+        Some(ref range) => {
+            let (start, end) = DateParser::new()?.parse_window(range)?;
+            (Some(start), Some(end))
+        }
+        None => (None, None),
+    };
+
+    let identity = match (&mirror_args.as_name, &mirror_args.as_email) {
+        (None, None) => None,
+        (name, email) => Some(GitIdentity {
+            name: name.clone()
+                .or_else(|| target_context.user_identity.as_ref().map(|i| i.name.clone()))
+                .unwrap_or_else(|| "Git Time Traveler".to_string()),
+            email: email.clone()
+                .or_else(|| target_context.user_identity.as_ref().map(|i| i.email.clone()))
+                .unwrap_or_else(|| "timetraveler@example.com".to_string()),
+        }),
+    };
+
+    let config = MirrorConfig {
+        sources: mirror_args.sources.clone(),
+        author_emails,
+        since,
+        until,
+        message_mode,
+        identity,
+        key: MirrorKey::load_or_create(&target, args.dry_run)?,
+    };
+
+    let commits = mirror::collect_source_commits(&config)?;
+
+    if !args.quiet {
+        println!("{}", "🪞 Activity Mirror".bright_blue().bold());
+        println!("  {} {}", "Sources:".cyan(), mirror_args.sources.len().to_string().bright_white());
+        println!("  {} {}", "Matching commits:".cyan(), commits.len().to_string().bright_yellow());
+        println!("  {} {}", "Target:".cyan(), mirror_args.target.display().to_string().bright_green());
+        if args.verbose || args.dry_run {
+            println!();
+            for commit in &commits {
+                println!("  {} {} {}",
+                    commit.author_time.format("%Y-%m-%d %H:%M").to_string().bright_yellow(),
+                    commit.repository.dimmed(),
+                    mirror::mirror_message(commit, config.message_mode, &config.key));
+            }
+        }
+        println!();
+    }
+
+    if args.dry_run {
+        println!("{} {}", "🔍".blue(), "Dry run - no mirror commits were created".dimmed());
+        return Ok(());
+    }
+
+    let result = mirror::mirror_commits(&target, &commits, &config)?;
+
+    if !args.quiet {
+        println!("✅ {} {} {}", "Created".green(), result.created.len().to_string().bright_yellow(), "mirror commits".green());
+        if result.skipped > 0 {
+            println!("  {} {} already mirrored", "Skipped:".cyan(), result.skipped);
+        }
+    }
+
+    if mirror_args.push && !result.created.is_empty() {
+        let branch = target.head().ok()
+            .and_then(|head| head.shorthand().map(str::to_string))
+            .ok_or_else(|| anyhow::anyhow!("Target repository has no current branch to push"))?;
+        let credentials = match (&args.username, &args.token) {
+            (Some(username), Some(token)) => Some(GitCredentials {
+                username: username.clone(),
+                token: token.clone(),
+            }),
+            _ => None,
+        };
+
+        if !args.quiet {
+            println!("{} {} {}", "⬆️".blue(), "Pushing".cyan(), branch.bright_green());
+        }
+        GitOperations::new().push_to_remote(&target, &mirror_args.remote, &branch, credentials.as_ref(), args.force)
+            .with_context(|| format!("Failed to push branch '{}'", branch))?;
+    }
+
+    Ok(())
+}
+
+/// List or delete the repositories created by the tool
+async fn run_repos(args: &Args, command: ReposCommand) -> Result<()> {
+    let username = get_username(args.username.clone())?;
+    let token = get_token(args.token.clone())?;
+    let owner = match args.owner {
+        Some(ref owner) => Some(validation::validate_username(owner)?),
+        None => None,
+    };
+    let client = GitHubClient::new(username, token)?.with_owner(owner);
+
+    let repositories: Vec<Repository> = client.list_repositories().await?
+        .into_iter()
+        .filter(is_time_travel_repository)
+        .collect();
+
+    match command {
+        ReposCommand::List => {
+            if repositories.is_empty() {
+                if !args.quiet {
+                    println!("{} {}", "📭".dimmed(), format!("No time travel repositories found for '{}'", client.owner()).dimmed());
+                }
+                return Ok(());
+            }
+
+            if args.quiet {
+                for repository in &repositories {
+                    println!("{}", repository.full_name);
+                }
+                return Ok(());
+            }
+
+            println!("{}", "📚 Time Travel Repositories".bright_blue().bold());
+            for repository in &repositories {
+                let mut flags = Vec::new();
+                if repository.private {
+                    flags.push("private");
+                }
+                if repository.archived {
+                    flags.push("archived");
+                }
+                println!("  {} {} {}",
+                    "•".cyan(),
+                    repository.full_name.bright_green(),
+                    if flags.is_empty() { String::new() } else { format!("({})", flags.join(", ")) }.dimmed());
+                if args.verbose {
+                    println!("      {} {}", "Description:".dimmed(), repository.description.as_deref().unwrap_or(""));
+                    println!("      {} {}", "Updated:".dimmed(), repository.updated_at);
+                    println!("      {} {}", "URL:".dimmed(), repository.html_url.bright_blue());
+                }
+            }
+            println!("\n  {} {}", "Total:".cyan(), repositories.len().to_string().bright_yellow());
+            Ok(())
+        }
+        ReposCommand::Delete(delete_args) => {
+            let selected: Vec<&Repository> = if delete_args.all {
+                repositories.iter().collect()
+            } else {
+                let mut selected = Vec::new();
+                for name in &delete_args.names {
+                    let name = name.rsplit('/').next().unwrap_or(name);
+                    match repositories.iter().find(|repository| repository.name.eq_ignore_ascii_case(name)) {
+                        Some(repository) => selected.push(repository),
+                        None => anyhow::bail!("'{}/{}' was not created by git-timetraveler (or does not exist) - refusing to delete it", client.owner(), name),
+                    }
+                }
+                selected
+            };
+
+            if selected.is_empty() {
+                println!("{} {}", "📭".dimmed(), "Nothing to delete".dimmed());
+                return Ok(());
+            }
+
+            println!("{}", "🗑️  Repositories to delete".red().bold());
+            for repository in &selected {
+                println!("  {} {}", "•".red(), repository.full_name.bright_white());
+            }
+
+            if args.dry_run {
+                println!("\n{} {}", "🔍".blue(), "Dry run - no repositories were deleted".dimmed());
+                return Ok(());
+            }
+
+            if !args.yes {
+                if !atty::is(Stream::Stdin) {
+                    anyhow::bail!("Deleting repositories requires a typed confirmation - run in a terminal or pass --yes");
+                }
+                let expected = if selected.len() == 1 {
+                    selected[0].full_name.clone()
+                } else {
+                    format!("delete {} repositories", selected.len())
+                };
+                println!("\n{} {}", "⚠️".yellow(), "This permanently deletes the repositories and cannot be undone.".yellow());
+                let typed: String = Input::with_theme(&ColorfulTheme::default())
+                    .with_prompt(format!("Type '{}' to confirm", expected))
+                    .allow_empty(true)
+                    .interact_text()?;
+                if typed.trim() != expected {
+                    println!("{} {}", "❌".red(), "Confirmation did not match - nothing was deleted".red());
+                    return Ok(());
+                }
+            }
+
+            for (deleted, repository) in selected.iter().enumerate() {
+                client.delete_repository(&repository.name).await
+                    .with_context(|| format!("Failed to delete '{}' ({} of {} deleted)", repository.full_name, deleted, selected.len()))?;
+                if !args.quiet {
+                    println!("{} {} {}", "✅".green(), "Deleted".green(), repository.full_name.bright_white());
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Fill in what the `--profile` provides and the command line left out
+fn apply_profile(args: &mut Args, hour_given: bool) -> Result<()> {
+    let Some(name) = args.profile.clone() else {
+        return Ok(());
+    };
+    let mut session = SessionManager::new()?;
+    session.activate_profile(Some(&name))?;
+    let (_, profile) = session.active_profile().expect("activated profile exists");
+
+    if args.username.is_none() {
+        args.username = profile.github_username.clone();
+    }
+    if args.token.is_none() {
+        args.token = profile.token_source.resolve().map(Secret::new);
+    }
+    if args.owner.is_none() {
+        args.owner = profile.default_owner.clone();
+    }
+    if let Some(ref author) = profile.author {
+        if args.author_name.is_none() && args.author_email.is_none() {
+            args.author_name = Some(author.name.clone());
+            args.author_email = Some(author.email.clone());
+            args.author_from_profile = true;
+        }
+    }
+    if !hour_given {
+        if let Some(&hour) = profile.preferred_hours.first() {
+            args.hour = hour;
+        }
+    }
+    Ok(())
+}
+
+/// Create, list and delete named profiles
+fn run_profile(args: &Args, command: ProfileCommand) -> Result<()> {
+    let mut session = SessionManager::new()?;
+    // Changes could not be saved without overwriting the newer release's session
+    if session.is_read_only() && !matches!(command, ProfileCommand::List) {
+        return Err(TimeTravelError::configuration("session file", &t!("session.newer_version_read_only")).into());
+    }
+
+    match command {
+        ProfileCommand::List => {
+            let names = session.profile_names();
+            if args.quiet {
+                for name in names {
+                    println!("{}", name);
+                }
+                return Ok(());
+            }
+            if names.is_empty() {
+                println!("{} {}", "📭".dimmed(), t!("profile.none").dimmed());
+                return Ok(());
+            }
+
+            println!("{}", t!("profile.title").bright_blue().bold());
+            for name in names {
+                let profile = session.profile(name).expect("listed profiles exist");
+                println!("  {} {}", "•".cyan(), name.bright_green());
+                println!("      {} {}", t!("profile.username").dimmed(), profile.github_username.as_deref().unwrap_or("-"));
+                if let Some(ref author) = profile.author {
+                    println!("      {} {} <{}>", t!("profile.author").dimmed(), author.name, author.email);
+                }
+                let token = match profile.token_source {
+                    TokenSource::Prompt => t!("profile.token_prompt"),
+                    TokenSource::Env(ref variable) => format!("${}", variable),
+                };
+                println!("      {} {}", t!("profile.token").dimmed(), token);
+                if let Some(ref owner) = profile.default_owner {
+                    println!("      {} {}", t!("profile.owner").dimmed(), owner);
+                }
+                if !profile.preferred_hours.is_empty() {
+                    let hours: Vec<String> = profile.preferred_hours.iter().map(|hour| format!("{:02}:00", hour)).collect();
+                    println!("      {} {}", t!("profile.hours").dimmed(), hours.join(", "));
+                }
+            }
+            Ok(())
+        }
+        ProfileCommand::Add(add_args) => {
+            if args.token.is_some() {
+                anyhow::bail!(t!("profile.token_not_stored"));
+            }
+            let github_username = match args.username {
+                Some(ref username) => Some(validation::validate_username(username)?),
+                None => None,
+            };
+            let default_owner = match args.owner {
+                Some(ref owner) => Some(validation::validate_username(owner)?),
+                None => None,
+            };
+            let author = match (add_args.author_name, add_args.author_email) {
+                (Some(name), Some(email)) => Some(GitIdentity { name, email }),
+                _ => None,
+            };
+            let profile = Profile {
+                github_username,
+                author,
+                token_source: add_args.token_env.map_or(TokenSource::Prompt, TokenSource::Env),
+                default_owner,
+                preferred_hours: add_args.hours,
+                ..Profile::default()
+            };
+
+            let existed = session.profile(&add_args.name).is_some();
+            session.set_profile(&add_args.name, profile)?;
+            session.save()?;
+            if !args.quiet {
+                let action = if existed { t!("profile.updated") } else { t!("profile.created") };
+                println!("{} {} {}", "✅".green(), action.green(), add_args.name.bright_white());
+                println!("  {} {}", t!("profile.use_with").dimmed(), format!("--profile {}", add_args.name).bright_cyan());
+            }
+            Ok(())
+        }
+        ProfileCommand::Remove { name } => {
+            // Fails with the list of known profiles if there is no such profile
+            session.activate_profile(Some(&name))?;
+            session.remove_profile(&name);
+            session.save()?;
+            if !args.quiet {
+                println!("{} {} {}", "✅".green(), t!("profile.removed").green(), name.bright_white());
+            }
+            Ok(())
+        }
+    }
+}
+
 /// Validate arguments for non-interactive mode
 fn validate_non_interactive_args(args: &Args) -> Vec<String> {
     let mut errors = Vec::new();
@@ -773,8 +2238,8 @@ fn validate_non_interactive_args(args: &Args) -> Vec<String> {
     let year_flag_present = std::env::args().any(|arg| arg == "--year" || arg == "-y");
     let years_flag_present = std::env::args().any(|arg| arg == "--years");
 
-    if !year_flag_present && !years_flag_present {
-        errors.push("Must specify either --year or --years".to_string());
+    if !year_flag_present && !years_flag_present && args.schedule.is_none() {
+        errors.push("Must specify either --year, --years or --schedule".to_string());
     }
 
     // Validate year range
@@ -884,17 +2349,17 @@ fn parse_years_string(years_str: &str) -> Result<Vec<u32>> {
 
 /// Display configuration options and help
 fn display_configuration_options() {
-    println!("\n{}", "⚙️  Configuration Options".bright_blue().bold());
-    println!("{}", "Git Time Traveler learns from your usage patterns.\n".cyan());
+    println!("\n⚙️  {}", t!("configuration.title").bright_blue().bold());
+    println!("{}\n", t!("configuration.intro").cyan());
 
-    println!("{}", "Stored Preferences:".bright_yellow());
-    println!("  • {} Recent repository names", "📁".blue());
-    println!("  • {} Preferred author modes", "👤".blue());
-    println!("  • {} Common year patterns", "📅".blue());
-    println!("  • {} GitHub username", "🐙".blue());
-    println!("  • {} Default commit timing", "⏰".blue());
+    println!("{}", t!("configuration.preferences").bright_yellow());
+    println!("  • {} {}", "📁".blue(), t!("configuration.preference_repositories"));
+    println!("  • {} {}", "👤".blue(), t!("configuration.preference_author"));
+    println!("  • {} {}", "📅".blue(), t!("configuration.preference_years"));
+    println!("  • {} {}", "🐙".blue(), t!("configuration.preference_username"));
+    println!("  • {} {}", "⏰".blue(), t!("configuration.preference_timing"));
 
-    println!("\n{}", "Configuration Location:".bright_yellow());
+    println!("\n{}", t!("configuration.location").bright_yellow());
     if let Some(config_dir) = dirs::config_dir() {
         let config_path = config_dir.join("git-timetraveler");
         println!("  {}", config_path.display().to_string().bright_cyan());
@@ -902,16 +2367,16 @@ fn display_configuration_options() {
         println!("  {}", "~/.config/git-timetraveler/".bright_cyan());
     }
 
-    println!("\n{}", "Reset Options:".bright_yellow());
-    println!("  • Delete the configuration directory to start fresh");
-    println!("  • Individual preferences reset automatically after 30 days of inactivity");
-    println!("  • GitHub tokens are stored securely using your system keychain");
+    println!("\n{}", t!("configuration.reset").bright_yellow());
+    println!("  • {}", t!("configuration.reset_delete"));
+    println!("  • {}", t!("configuration.reset_expiry"));
+    println!("  • {}", t!("configuration.reset_tokens"));
 
-    println!("\n{}", "Command Line Override:".bright_yellow());
-    println!("  Use {} to bypass all interactive prompts", "--no-menu".bright_cyan());
-    println!("  All preferences can be overridden with command line arguments");
+    println!("\n{}", t!("configuration.override").bright_yellow());
+    println!("  {}", t!("configuration.override_no_menu", flag = "--no-menu".bright_cyan()));
+    println!("  {}", t!("configuration.override_arguments"));
 
-    println!("\n{}", "Press Enter to continue...".dimmed());
+    println!("\n{}", t!("common.press_enter").dimmed());
     let _ = std::io::stdin().read_line(&mut String::new());
 }
 
@@ -920,21 +2385,21 @@ fn get_username(username: Option<String>) -> Result<String> {
         Some(u) => Ok(u),
         None => {
             let username: String = Input::new()
-                .with_prompt("GitHub Username")
+                .with_prompt(t!("interactive.github_username"))
                 .interact_text()?;
             Ok(username)
         }
     }
 }
 
-fn get_token(token: Option<String>) -> Result<String> {
+fn get_token(token: Option<Secret>) -> Result<Secret> {
     match token {
         Some(t) => Ok(t),
         None => {
             let token: String = Password::new()
-                .with_prompt("GitHub Personal Access Token")
+                .with_prompt(t!("interactive.token_prompt"))
                 .interact()?;
-            Ok(token)
+            Ok(Secret::new(token))
         }
     }
 }
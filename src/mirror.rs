@@ -0,0 +1,448 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use git2::{ConfigLevel, Oid, Repository, Signature, Sort};
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use zeroize::Zeroizing;
+
+use crate::errors::TimeTravelError;
+use crate::git_context::GitIdentity;
+
+/// Trailer that links a mirrored commit to its (hashed) source commit
+pub const MIRROR_TRAILER: &str = "Mirror-Id:";
+
+/// Local Git config entry of the target repository holding its mirror key
+pub const MIRROR_KEY_CONFIG: &str = "timetraveler.mirrorkey";
+
+const MIRROR_KEY_LEN: usize = 32;
+
+/// How source commit messages are carried into the mirror repository
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageMode {
+    /// Keep the original summary line
+    Keep,
+    /// Replace the message with a short hash of the original
+    Hash,
+    /// Replace the message with a generic placeholder
+    Redact,
+}
+
+impl FromStr for MessageMode {
+    type Err = TimeTravelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "keep" => Ok(Self::Keep),
+            "hash" => Ok(Self::Hash),
+            "redact" => Ok(Self::Redact),
+            _ => Err(TimeTravelError::invalid_input(
+                "message mode",
+                s,
+                "unknown mode",
+                "Use one of: keep, hash, redact"
+            )),
+        }
+    }
+}
+
+/// Secret of one mirror target, used to hash source commit ids and messages so they
+/// cannot be matched against known commits without it
+#[derive(Debug, Clone)]
+pub struct MirrorKey(hmac::Key);
+
+impl MirrorKey {
+    /// The target's key from its local Git config, generated and stored there on first use.
+    /// A dry run uses a throwaway key instead of storing a new one.
+    pub fn load_or_create(target: &Repository, dry_run: bool) -> Result<Self> {
+        let mut config = target.config()
+            .and_then(|config| config.open_level(ConfigLevel::Local))
+            .context("Failed to open the target repository's Git config")?;
+
+        let secret = match config.get_string(MIRROR_KEY_CONFIG) {
+            Ok(encoded) => Zeroizing::new(decode_hex(&encoded).ok_or_else(|| TimeTravelError::configuration(
+                MIRROR_KEY_CONFIG,
+                "not a valid mirror key - restore it from a backup of the target or remove it to start a new mirror",
+            ))?),
+            // A new key would not recognize the ids already in history and mirror everything again
+            Err(_) if !existing_mirror_ids(target)?.is_empty() => {
+                return Err(TimeTravelError::configuration(
+                    MIRROR_KEY_CONFIG,
+                    "missing, but the target already has mirrored commits - copy the key from the original \
+                     target with 'git config timetraveler.mirrorkey <KEY>'",
+                ).into());
+            }
+            Err(_) => {
+                let mut secret = Zeroizing::new(vec![0; MIRROR_KEY_LEN]);
+                SystemRandom::new().fill(&mut secret)
+                    .map_err(|_| anyhow::anyhow!("Failed to generate a mirror key"))?;
+                if !dry_run {
+                    config.set_str(MIRROR_KEY_CONFIG, &encode_hex(&secret))
+                        .context("Failed to store the mirror key")?;
+                }
+                secret
+            }
+        };
+        Ok(Self(hmac::Key::new(hmac::HMAC_SHA256, &secret)))
+    }
+
+    /// Hex HMAC-SHA256 of `data`
+    fn sign(&self, data: &[u8]) -> String {
+        encode_hex(hmac::sign(&self.0, data).as_ref())
+    }
+}
+
+/// Configuration for mirroring activity from local repositories
+#[derive(Debug, Clone)]
+pub struct MirrorConfig {
+    /// Paths inside the repositories to scan
+    pub sources: Vec<PathBuf>,
+    /// Only commits authored with one of these emails are mirrored
+    pub author_emails: Vec<String>,
+    /// Inclusive lower bound for author dates
+    pub since: Option<DateTime<Utc>>,
+    /// Inclusive upper bound for author dates
+    pub until: Option<DateTime<Utc>>,
+    pub message_mode: MessageMode,
+    /// Identity for mirrored commits; the source author is used when unset
+    pub identity: Option<GitIdentity>,
+    pub key: MirrorKey,
+}
+
+/// A commit found in a source repository that matches the mirror filters
+#[derive(Debug, Clone)]
+pub struct SourceCommit {
+    /// Name of the source repository (its working directory name)
+    pub repository: String,
+    /// Stable identifier that does not reveal the source commit id
+    pub mirror_id: String,
+    pub author: GitIdentity,
+    pub author_time: DateTime<Utc>,
+    /// Author timezone offset in minutes
+    pub offset_minutes: i32,
+    pub summary: String,
+}
+
+/// Result of a mirror run
+#[derive(Debug)]
+pub struct MirrorResult {
+    pub created: Vec<String>,
+    pub skipped: usize,
+}
+
+/// Scan all configured source repositories for matching commits, oldest first
+pub fn collect_source_commits(config: &MirrorConfig) -> Result<Vec<SourceCommit>> {
+    let emails: HashSet<String> = config.author_emails.iter()
+        .map(|email| email.trim().to_ascii_lowercase())
+        .collect();
+
+    let mut commits = Vec::new();
+    let mut seen_repos = HashSet::new();
+    for source in &config.sources {
+        let repo = Repository::discover(source).map_err(|e| TimeTravelError::repository(
+            crate::errors::RepoError::NotFound,
+            &source.display().to_string(),
+            &format!("No Git repository found: {}", e.message())
+        ))?;
+
+        // The same repository may be reached through several paths
+        if !seen_repos.insert(repo.path().to_path_buf()) {
+            continue;
+        }
+        commits.extend(scan_repository(&repo, &emails, config)?);
+    }
+
+    commits.sort_by_key(|commit| commit.author_time);
+    Ok(commits)
+}
+
+/// Collect matching commits reachable from the local branches of one repository
+fn scan_repository(repo: &Repository, emails: &HashSet<String>, config: &MirrorConfig) -> Result<Vec<SourceCommit>> {
+    let name = repository_name(repo);
+
+    let mut revwalk = repo.revwalk().context("Failed to create revision walker")?;
+    revwalk.set_sorting(Sort::TIME).context("Failed to set revision sorting")?;
+    if revwalk.push_glob("refs/heads/*").is_err() || repo.head().is_err() {
+        // Empty repositories have nothing to mirror
+        return Ok(Vec::new());
+    }
+    // Unlike the directory name, the root commit stays the same in every clone
+    let root = root_commit(repo)?;
+
+    let mut commits = Vec::new();
+    for oid in revwalk {
+        let oid = oid.context("Failed to walk repository history")?;
+        let commit = repo.find_commit(oid).context("Failed to find commit")?;
+        let author = commit.author();
+
+        let email = author.email().unwrap_or("").to_ascii_lowercase();
+        if !emails.is_empty() && !emails.contains(&email) {
+            continue;
+        }
+
+        let time = author.when();
+        let author_time = match Utc.timestamp_opt(time.seconds(), 0).single() {
+            Some(t) => t,
+            None => continue,
+        };
+        if config.since.is_some_and(|since| author_time < since)
+            || config.until.is_some_and(|until| author_time > until) {
+            continue;
+        }
+
+        commits.push(SourceCommit {
+            repository: name.clone(),
+            mirror_id: mirror_id(&config.key, &root, &oid),
+            author: GitIdentity {
+                name: author.name().unwrap_or("Unknown").to_string(),
+                email: author.email().unwrap_or("").to_string(),
+            },
+            author_time,
+            offset_minutes: time.offset_minutes(),
+            summary: commit.summary().unwrap_or("").to_string(),
+        });
+    }
+    Ok(commits)
+}
+
+/// Create one empty commit in the target repository per source commit that
+/// has not been mirrored before. Commits are added on top of HEAD.
+pub fn mirror_commits(target: &Repository, commits: &[SourceCommit], config: &MirrorConfig) -> Result<MirrorResult> {
+    let existing = existing_mirror_ids(target)?;
+
+    let mut parent = match target.head() {
+        Ok(head) => Some(head.peel_to_commit().context("Failed to resolve HEAD commit")?),
+        Err(_) => None,
+    };
+    let tree = match parent {
+        Some(ref commit) => commit.tree().context("Failed to read HEAD tree")?,
+        None => {
+            let tree_id = target.treebuilder(None)
+                .and_then(|builder| builder.write())
+                .context("Failed to create empty tree")?;
+            target.find_tree(tree_id).context("Failed to find empty tree")?
+        }
+    };
+
+    let mut result = MirrorResult { created: Vec::new(), skipped: 0 };
+    for commit in commits {
+        if existing.contains(&commit.mirror_id) {
+            result.skipped += 1;
+            continue;
+        }
+
+        let identity = config.identity.as_ref().unwrap_or(&commit.author);
+        let when = git2::Time::new(commit.author_time.timestamp(), commit.offset_minutes);
+        let signature = Signature::new(&identity.name, &identity.email, &when)
+            .context("Failed to create mirror signature")?;
+
+        let message = format!("{}\n\n{} {}\n", mirror_message(commit, config.message_mode, &config.key), MIRROR_TRAILER, commit.mirror_id);
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        let oid = target.commit(Some("HEAD"), &signature, &signature, &message, &tree, &parents)
+            .map_err(|e| TimeTravelError::git_operation("commit", &format!("Failed to create mirror commit: {}", e.message())))?;
+
+        result.created.push(oid.to_string());
+        parent = Some(target.find_commit(oid).context("Failed to find mirror commit")?);
+    }
+
+    Ok(result)
+}
+
+/// Message for a mirrored commit according to the chosen mode
+pub fn mirror_message(commit: &SourceCommit, mode: MessageMode, key: &MirrorKey) -> String {
+    match mode {
+        MessageMode::Keep if !commit.summary.is_empty() => commit.summary.clone(),
+        MessageMode::Keep | MessageMode::Redact => "Private contribution".to_string(),
+        MessageMode::Hash => format!("Contribution {}", &key.sign(commit.summary.as_bytes())[..12]),
+    }
+}
+
+/// Mirror ids already present in the target repository's history
+fn existing_mirror_ids(target: &Repository) -> Result<HashSet<String>> {
+    let mut ids = HashSet::new();
+    if target.head().is_err() {
+        return Ok(ids);
+    }
+
+    let mut revwalk = target.revwalk().context("Failed to create revision walker")?;
+    revwalk.push_head().context("Failed to walk target history")?;
+    for oid in revwalk {
+        let commit = target.find_commit(oid?).context("Failed to find commit")?;
+        let message = commit.message().unwrap_or("");
+        ids.extend(message.lines()
+            .filter_map(|line| line.strip_prefix(MIRROR_TRAILER))
+            .map(|id| id.trim().to_string()));
+    }
+    Ok(ids)
+}
+
+/// Keyed hash of the source repository's root commit and the commit id, so the mirror exposes neither
+fn mirror_id(key: &MirrorKey, root: &Oid, oid: &Oid) -> String {
+    key.sign(format!("{}:{}", root, oid).as_bytes())
+}
+
+/// First commit in the history of HEAD
+fn root_commit(repo: &Repository) -> Result<Oid> {
+    let mut revwalk = repo.revwalk().context("Failed to create revision walker")?;
+    revwalk.push_head().context("Failed to walk repository history")?;
+    revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE).context("Failed to set revision sorting")?;
+    revwalk.next()
+        .context("Repository has no commits")?
+        .context("Failed to walk repository history")
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim();
+    if text.len() != MIRROR_KEY_LEN * 2 {
+        return None;
+    }
+    (0..text.len()).step_by(2)
+        .map(|index| u8::from_str_radix(text.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+fn repository_name(repo: &Repository) -> String {
+    let path: &Path = repo.workdir().unwrap_or_else(|| repo.path());
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "repository".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn commit_as(repo: &Repository, email: &str, seconds: i64, message: &str) {
+        let sig = Signature::new("Dev", email, &git2::Time::new(seconds, 0)).unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap();
+    }
+
+    #[test]
+    fn test_mirror_filters_and_is_idempotent() {
+        let source_dir = TempDir::new().unwrap();
+        let source = Repository::init(source_dir.path()).unwrap();
+        commit_as(&source, "me@work.example", 1_000_000_000, "Secret feature");
+        commit_as(&source, "other@work.example", 1_100_000_000, "Someone else");
+        commit_as(&source, "ME@work.example", 1_200_000_000, "Later work");
+
+        let target_dir = TempDir::new().unwrap();
+        let target = Repository::init(target_dir.path()).unwrap();
+
+        let mut config = MirrorConfig {
+            sources: vec![source_dir.path().to_path_buf()],
+            author_emails: vec!["me@work.example".to_string()],
+            since: None,
+            until: Some(Utc.timestamp_opt(1_100_000_000, 0).unwrap()),
+            message_mode: MessageMode::Redact,
+            identity: None,
+            key: MirrorKey::load_or_create(&target, false).unwrap(),
+        };
+
+        let commits = collect_source_commits(&config).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].author_time.timestamp(), 1_000_000_000);
+
+        let result = mirror_commits(&target, &commits, &config).unwrap();
+        assert_eq!(result.created.len(), 1);
+
+        // Widening the range only adds the new commit
+        config.until = None;
+        let commits = collect_source_commits(&config).unwrap();
+        assert_eq!(commits.len(), 2);
+        let result = mirror_commits(&target, &commits, &config).unwrap();
+        assert_eq!(result.created.len(), 1);
+        assert_eq!(result.skipped, 1);
+
+        let head = target.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.author().when().seconds(), 1_200_000_000);
+        assert_eq!(head.summary(), Some("Private contribution"));
+        assert!(!head.message().unwrap().contains("Later work"));
+        assert_eq!(head.tree().unwrap().len(), 0);
+
+        // A fresh clone of the source keeps the mirror ids, since they follow its root commit
+        let clone_dir = TempDir::new().unwrap();
+        Repository::clone(source_dir.path().to_str().unwrap(), clone_dir.path().join("renamed")).unwrap();
+        config.sources = vec![clone_dir.path().join("renamed")];
+        config.key = MirrorKey::load_or_create(&target, false).unwrap();
+        let result = mirror_commits(&target, &collect_source_commits(&config).unwrap(), &config).unwrap();
+        assert!(result.created.is_empty());
+        assert_eq!(result.skipped, 2);
+    }
+
+    #[test]
+    fn test_fresh_target_clone_needs_the_key() {
+        let source_dir = TempDir::new().unwrap();
+        let source = Repository::init(source_dir.path()).unwrap();
+        commit_as(&source, "me@work.example", 1_000_000_000, "Secret feature");
+
+        let target_dir = TempDir::new().unwrap();
+        let target = Repository::init(target_dir.path()).unwrap();
+        let mut config = MirrorConfig {
+            sources: vec![source_dir.path().to_path_buf()],
+            author_emails: vec!["me@work.example".to_string()],
+            since: None,
+            until: None,
+            message_mode: MessageMode::Redact,
+            identity: None,
+            key: MirrorKey::load_or_create(&target, false).unwrap(),
+        };
+        mirror_commits(&target, &collect_source_commits(&config).unwrap(), &config).unwrap();
+
+        // A clone of the target has the history but not the key from .git/config
+        let clone_dir = TempDir::new().unwrap();
+        let clone = Repository::clone(target_dir.path().to_str().unwrap(), clone_dir.path()).unwrap();
+        let error = MirrorKey::load_or_create(&clone, false).unwrap_err();
+        assert_eq!(crate::errors::exit_code_for(&error), crate::errors::exit_codes::CONFIGURATION);
+        assert!(clone.config().unwrap().get_string(MIRROR_KEY_CONFIG).is_err());
+
+        // With the key copied over, re-mirroring adds nothing
+        let key = target.config().unwrap().get_string(MIRROR_KEY_CONFIG).unwrap();
+        clone.config().unwrap().set_str(MIRROR_KEY_CONFIG, &key).unwrap();
+        config.key = MirrorKey::load_or_create(&clone, false).unwrap();
+        let result = mirror_commits(&clone, &collect_source_commits(&config).unwrap(), &config).unwrap();
+        assert!(result.created.is_empty());
+        assert_eq!(result.skipped, 1);
+    }
+
+    #[test]
+    fn test_mirror_message_modes() {
+        let commit = SourceCommit {
+            repository: "internal".to_string(),
+            mirror_id: "abc".to_string(),
+            author: GitIdentity { name: "Dev".to_string(), email: "dev@example.com".to_string() },
+            author_time: Utc.timestamp_opt(0, 0).unwrap(),
+            offset_minutes: 0,
+            summary: "Add billing export".to_string(),
+        };
+
+        let target_dir = TempDir::new().unwrap();
+        let target = Repository::init(target_dir.path()).unwrap();
+        let key = MirrorKey::load_or_create(&target, false).unwrap();
+
+        assert_eq!(mirror_message(&commit, MessageMode::Keep, &key), "Add billing export");
+        assert_eq!(mirror_message(&commit, MessageMode::Redact, &key), "Private contribution");
+        let hashed = mirror_message(&commit, MessageMode::Hash, &key);
+        assert!(hashed.starts_with("Contribution "));
+        assert!(!hashed.contains("billing"));
+        assert_eq!(mirror_message(&commit, MessageMode::Hash, &MirrorKey::load_or_create(&target, false).unwrap()), hashed);
+
+        // Another mirror hashes the same message differently, and a dry run stores no key
+        let other_dir = TempDir::new().unwrap();
+        let other = Repository::init(other_dir.path()).unwrap();
+        assert_ne!(mirror_message(&commit, MessageMode::Hash, &MirrorKey::load_or_create(&other, true).unwrap()), hashed);
+        assert!(other.config().unwrap().get_string(MIRROR_KEY_CONFIG).is_err());
+        assert_eq!("HASH".parse::<MessageMode>().unwrap(), MessageMode::Hash);
+        assert!("other".parse::<MessageMode>().is_err());
+    }
+}
@@ -0,0 +1,259 @@
+use anyhow::{Context, Result};
+use std::sync::Arc;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// Step events a single year emits: token, repository, clone, content, commit, push
+pub const YEAR_STEPS: usize = 6;
+
+/// Step events a run of `years` years emits, each year on its own
+pub fn year_steps(years: usize) -> usize {
+    years * YEAR_STEPS
+}
+
+/// Step events an imported schedule emits for `commits` commits
+pub fn schedule_steps(commits: usize) -> usize {
+    // Token, repository and clone before the commits, the push after them
+    commits + 4
+}
+
+/// Something that happened while creating and pushing time travel commits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+    TokenValidated,
+    RepoChecked { repository: String, exists: bool },
+    RepoCreated { repository: String },
+    CloneStarted { url: String },
+    /// Transfer progress reported by git while cloning
+    CloneProgress { objects: usize, total_objects: usize, bytes: usize },
+    Cloned,
+    ContentWritten { path: String },
+    CommitCreated { sha: String },
+    PushStarted { branch: String },
+    /// Transfer progress reported by git while pushing
+    PushProgress { objects: usize, total_objects: usize, bytes: usize },
+    Pushed { branch: String },
+    YearDone { year: u32 },
+    ScheduleDone { commits: usize },
+}
+
+impl ProgressEvent {
+    /// Whether the event completes one of the counted steps
+    pub fn is_step(&self) -> bool {
+        matches!(
+            self,
+            Self::TokenValidated
+                | Self::RepoChecked { .. }
+                | Self::Cloned
+                | Self::ContentWritten { .. }
+                | Self::CommitCreated { .. }
+                | Self::Pushed { .. }
+        )
+    }
+
+    /// Whether the event ends a run
+    pub fn is_done(&self) -> bool {
+        matches!(self, Self::YearDone { .. } | Self::ScheduleDone { .. })
+    }
+
+    /// Short status line for progress displays
+    pub fn message(&self) -> String {
+        match self {
+            Self::TokenValidated => "GitHub token validated".to_string(),
+            Self::RepoChecked { repository, exists: true } => format!("Repository '{}' found", repository),
+            Self::RepoChecked { repository, exists: false } => format!("Repository '{}' not found", repository),
+            Self::RepoCreated { repository } => format!("Created repository '{}'", repository),
+            Self::CloneStarted { .. } => "Cloning repository...".to_string(),
+            Self::CloneProgress { objects, total_objects, bytes } => {
+                format!("Cloning: {}/{} objects ({})", objects, total_objects, format_bytes(*bytes))
+            }
+            Self::Cloned => "Repository cloned".to_string(),
+            Self::ContentWritten { path } => format!("Wrote {}", path),
+            Self::CommitCreated { sha } => format!("Created commit {}", &sha[..sha.len().min(7)]),
+            Self::PushStarted { branch } => format!("Pushing to '{}'...", branch),
+            Self::PushProgress { objects, total_objects, bytes } => {
+                format!("Pushing: {}/{} objects ({})", objects, total_objects, format_bytes(*bytes))
+            }
+            Self::Pushed { branch } => format!("Pushed to '{}'", branch),
+            Self::YearDone { .. } => "✅ Time travel complete!".to_string(),
+            Self::ScheduleDone { .. } => "✅ Schedule imported!".to_string(),
+        }
+    }
+}
+
+/// Receiver of progress events that can be shared with the git worker threads
+pub trait ProgressSink: Send + Sync {
+    fn emit(&self, event: ProgressEvent);
+}
+
+impl<F> ProgressSink for F
+where
+    F: Fn(ProgressEvent) + Send + Sync,
+{
+    fn emit(&self, event: ProgressEvent) {
+        self(event)
+    }
+}
+
+impl ProgressSink for UnboundedSender<ProgressEvent> {
+    fn emit(&self, event: ProgressEvent) {
+        // A consumer that stopped listening does not stop the run
+        let _ = self.send(event);
+    }
+}
+
+impl<S: ProgressSink + ?Sized> ProgressSink for Arc<S> {
+    fn emit(&self, event: ProgressEvent) {
+        (**self).emit(event)
+    }
+}
+
+/// Progress callback trait for reporting progress
+pub trait ProgressCallback {
+    fn set_message(&self, message: &str);
+    fn increment(&self);
+    fn finish(&self, message: &str);
+
+    /// Handle a pipeline event: steps advance the bar, the final event finishes it
+    fn on_event(&self, event: &ProgressEvent) {
+        if event.is_done() {
+            self.finish(&event.message());
+            return;
+        }
+        self.set_message(&event.message());
+        if event.is_step() {
+            self.increment();
+        }
+    }
+}
+
+/// Progress callback that forwards every event to a sink, e.g. a channel
+pub struct SinkProgress<S>(pub S);
+
+impl<S: ProgressSink> ProgressCallback for SinkProgress<S> {
+    fn set_message(&self, _message: &str) {}
+
+    fn increment(&self) {}
+
+    fn finish(&self, _message: &str) {}
+
+    fn on_event(&self, event: &ProgressEvent) {
+        self.0.emit(event.clone());
+    }
+}
+
+/// Delivers events from git worker threads to a callback on the async side
+pub(crate) struct ProgressRelay<'a> {
+    callback: Option<&'a dyn ProgressCallback>,
+    sender: UnboundedSender<ProgressEvent>,
+    receiver: UnboundedReceiver<ProgressEvent>,
+}
+
+impl<'a> ProgressRelay<'a> {
+    pub(crate) fn new(callback: Option<&'a dyn ProgressCallback>) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self { callback, sender, receiver }
+    }
+
+    /// Sink for worker threads; its events reach the callback while `run_blocking` waits
+    pub(crate) fn sink(&self) -> Arc<dyn ProgressSink> {
+        Arc::new(self.sender.clone())
+    }
+
+    pub(crate) fn emit(&self, event: ProgressEvent) {
+        if let Some(callback) = self.callback {
+            callback.on_event(&event);
+        }
+    }
+
+    /// Run synchronous git2 work on the blocking thread pool so it does not stall
+    /// the runtime, forwarding its events as they arrive
+    pub(crate) async fn run_blocking<T, F>(&mut self, work: F) -> Result<T>
+    where
+        F: FnOnce() -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let mut handle = tokio::task::spawn_blocking(work);
+        let result = loop {
+            tokio::select! {
+                biased;
+                Some(event) = self.receiver.recv() => self.emit(event),
+                result = &mut handle => break result,
+            }
+        };
+        while let Ok(event) = self.receiver.try_recv() {
+            self.emit(event);
+        }
+        result.context("Git worker thread failed")?
+    }
+}
+
+/// Human-readable byte count
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_events_and_sinks() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let collected = events.clone();
+        let sink: Arc<dyn ProgressSink> = Arc::new(move |event| collected.lock().unwrap().push(event));
+
+        sink.emit(ProgressEvent::CloneProgress { objects: 3, total_objects: 10, bytes: 1536 });
+        sink.emit(ProgressEvent::CommitCreated { sha: "0123456789abcdef".to_string() });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events[0].message(), "Cloning: 3/10 objects (1.5 KiB)");
+        assert!(!events[0].is_step());
+        assert_eq!(events[1].message(), "Created commit 0123456");
+        assert!(events[1].is_step());
+        assert_eq!(format_bytes(512), "512 B");
+    }
+
+    #[tokio::test]
+    async fn test_relay_forwards_worker_events() {
+        struct Recorder(std::cell::RefCell<Vec<ProgressEvent>>);
+
+        impl ProgressCallback for Recorder {
+            fn set_message(&self, _message: &str) {}
+            fn increment(&self) {}
+            fn finish(&self, _message: &str) {}
+
+            fn on_event(&self, event: &ProgressEvent) {
+                self.0.borrow_mut().push(event.clone());
+            }
+        }
+
+        let recorder = Recorder(Default::default());
+        let mut relay = ProgressRelay::new(Some(&recorder));
+        let sink = relay.sink();
+        let value = relay.run_blocking(move || {
+            sink.emit(ProgressEvent::Cloned);
+            sink.emit(ProgressEvent::Pushed { branch: "main".to_string() });
+            Ok(7)
+        }).await.unwrap();
+        relay.emit(ProgressEvent::YearDone { year: 1990 });
+
+        assert_eq!(value, 7);
+        assert_eq!(*recorder.0.borrow(), vec![
+            ProgressEvent::Cloned,
+            ProgressEvent::Pushed { branch: "main".to_string() },
+            ProgressEvent::YearDone { year: 1990 },
+        ]);
+    }
+}
@@ -0,0 +1,432 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::errors::{validation, TimeTravelError};
+use crate::git_context::GitIdentity;
+use crate::git_operations::TimeTravelCommitConfig;
+
+/// File that scheduled commits append to so every commit has a change
+pub const SCHEDULE_FILE: &str = "timetravel-schedule.md";
+
+/// Hour used when a schedule row only contains a date
+const DEFAULT_SCHEDULE_HOUR: u32 = 18;
+
+/// Upper bound for the `count` column of a single row
+const MAX_COMMITS_PER_ROW: u32 = 100;
+
+/// Supported schedule file formats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScheduleFormat {
+    Csv,
+    Json,
+}
+
+impl ScheduleFormat {
+    /// Detect the format from a file extension
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()).map(|ext| ext.to_ascii_lowercase()) {
+            Some(ext) if ext == "csv" => Ok(Self::Csv),
+            Some(ext) if ext == "json" => Ok(Self::Json),
+            _ => Err(TimeTravelError::invalid_input(
+                "schedule file",
+                &path.display().to_string(),
+                "unsupported file type",
+                "Use a .csv or .json file"
+            ).into()),
+        }
+    }
+}
+
+/// A single validated row of a commit schedule
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleEntry {
+    /// Line in the source file the row started on (1-based)
+    pub line: usize,
+    pub timestamp: DateTime<Utc>,
+    pub message: String,
+    pub count: u32,
+    pub author: Option<GitIdentity>,
+}
+
+/// A commit schedule imported from CSV or JSON
+#[derive(Debug, Clone)]
+pub struct CommitSchedule {
+    pub source: Option<PathBuf>,
+    pub entries: Vec<ScheduleEntry>,
+}
+
+/// Raw row as it appears in a JSON schedule
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonRow {
+    datetime: String,
+    message: String,
+    count: u32,
+    #[serde(default)]
+    author: Option<String>,
+}
+
+impl CommitSchedule {
+    /// Load a schedule from a .csv or .json file
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let format = ScheduleFormat::from_path(path)?;
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| TimeTravelError::file_system("read", &path.display().to_string(), &e.to_string()))?;
+
+        let mut schedule = Self::parse(&content, format)
+            .with_context(|| format!("Failed to import schedule from {}", path.display()))?;
+        schedule.source = Some(path.to_path_buf());
+        Ok(schedule)
+    }
+
+    /// Parse schedule content, reporting every invalid row with its line number
+    pub fn parse(content: &str, format: ScheduleFormat) -> Result<Self> {
+        let rows = match format {
+            ScheduleFormat::Csv => read_csv_rows(content)?,
+            ScheduleFormat::Json => read_json_rows(content)?,
+        };
+
+        let mut entries = Vec::new();
+        let mut row_errors = Vec::new();
+        for (line, row) in rows {
+            match row.and_then(|row| validate_row(line, row)) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => row_errors.push(format!("line {}: {}", line, e)),
+            }
+        }
+
+        if !row_errors.is_empty() {
+            return Err(TimeTravelError::invalid_input(
+                "schedule",
+                &format!("{} invalid row(s)", row_errors.len()),
+                &format!("\n  {}", row_errors.join("\n  ")),
+                "Fix the rows listed above and import the schedule again"
+            ).into());
+        }
+
+        if entries.is_empty() {
+            return Err(TimeTravelError::invalid_input(
+                "schedule",
+                "",
+                "contains no rows",
+                "Add at least one row of datetime, message, count[, author]"
+            ).into());
+        }
+
+        entries.sort_by_key(|entry| (entry.timestamp, entry.line));
+        Ok(Self { source: None, entries })
+    }
+
+    /// Total number of commits the schedule will create
+    pub fn total_commits(&self) -> usize {
+        self.entries.iter().map(|entry| entry.count as usize).sum()
+    }
+
+    /// Years covered by the schedule, in ascending order
+    pub fn years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = self.entries.iter().map(|entry| entry.timestamp.year() as u32).collect();
+        years.dedup();
+        years
+    }
+
+    /// Expand the schedule into one commit configuration per commit.
+    ///
+    /// Rows with a count above one are spaced a minute apart so they keep a
+    /// stable order in history.
+    pub fn to_commit_configs(&self, default_author: &GitIdentity) -> Vec<TimeTravelCommitConfig> {
+        let mut configs = Vec::with_capacity(self.total_commits());
+        for entry in &self.entries {
+            let author = entry.author.clone().unwrap_or_else(|| default_author.clone());
+            for i in 0..entry.count {
+                configs.push(TimeTravelCommitConfig {
+                    timestamp: entry.timestamp + Duration::minutes(i as i64),
+                    author: author.clone(),
+                    committer: author.clone(),
+                    message: entry.message.clone(),
+                    files_to_add: vec![PathBuf::from(SCHEDULE_FILE)],
+                });
+            }
+        }
+        configs
+    }
+}
+
+/// Unvalidated row fields: datetime, message, count, author
+type RawRow = (String, String, String, Option<String>);
+
+/// Read CSV rows with their line numbers. A leading header row is skipped.
+fn read_csv_rows(content: &str) -> Result<Vec<(usize, Result<RawRow>)>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .comment(Some(b'#'))
+        .from_reader(content.as_bytes());
+
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|p| p.line() as usize).unwrap_or(0);
+                rows.push((line, Err(anyhow!("unreadable CSV row: {}", e))));
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line() as usize).unwrap_or(index + 1);
+
+        if index == 0 && record.get(0).is_some_and(|field| field.eq_ignore_ascii_case("datetime")) {
+            continue;
+        }
+        if record.iter().all(|field| field.is_empty()) {
+            continue;
+        }
+
+        let row = if record.len() < 3 || record.len() > 4 {
+            Err(anyhow!("expected 3 or 4 columns (datetime, message, count[, author]), found {}", record.len()))
+        } else {
+            Ok((
+                record[0].to_string(),
+                record[1].to_string(),
+                record[2].to_string(),
+                record.get(3).filter(|author| !author.is_empty()).map(str::to_string),
+            ))
+        };
+        rows.push((line, row));
+    }
+    Ok(rows)
+}
+
+/// Read rows from a JSON array of objects with their line numbers
+fn read_json_rows(content: &str) -> Result<Vec<(usize, Result<RawRow>)>> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(content).map_err(|e| {
+        TimeTravelError::invalid_input(
+            "schedule",
+            &format!("line {}, column {}", e.line(), e.column()),
+            &e.to_string(),
+            "The JSON schedule must be an array of {\"datetime\", \"message\", \"count\", \"author\"} objects"
+        )
+    })?;
+
+    let lines = json_element_lines(content);
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let line = lines.get(index).copied().unwrap_or(0);
+            let row = serde_json::from_value::<JsonRow>(value)
+                .map(|row| (row.datetime, row.message, row.count.to_string(), row.author))
+                .map_err(|e| anyhow!("{}", e));
+            (line, row)
+        })
+        .collect())
+}
+
+/// Find the line each element of a top-level JSON array starts on
+pub(crate) fn json_element_lines(content: &str) -> Vec<usize> {
+    let mut lines = Vec::new();
+    let mut line = 1;
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut expecting_element = false;
+
+    for c in content.chars() {
+        if c == '\n' {
+            line += 1;
+        }
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        if expecting_element && !c.is_whitespace() && c != ']' {
+            lines.push(line);
+            expecting_element = false;
+        }
+        match c {
+            '"' => in_string = true,
+            '[' | '{' => {
+                depth += 1;
+                if depth == 1 {
+                    expecting_element = true;
+                }
+            }
+            ']' | '}' => depth -= 1,
+            ',' if depth == 1 => expecting_element = true,
+            _ => {}
+        }
+    }
+    lines
+}
+
+/// Validate a raw row and turn it into a schedule entry
+fn validate_row(line: usize, (datetime, message, count, author): RawRow) -> Result<ScheduleEntry> {
+    let timestamp = parse_datetime(&datetime)?;
+    validation::validate_year(timestamp.year() as u32)?;
+    validation::validate_month(timestamp.month())?;
+    validation::validate_day(timestamp.day())?;
+    validation::validate_hour(timestamp.hour())?;
+
+    let message = message.trim().to_string();
+    if message.is_empty() {
+        return Err(TimeTravelError::invalid_input(
+            "message",
+            "",
+            "cannot be empty",
+            "Provide a commit message for every row"
+        ).into());
+    }
+
+    let count: u32 = count.trim().parse().map_err(|_| TimeTravelError::invalid_input(
+        "count",
+        &count,
+        "must be a whole number",
+        "Use the number of commits to create, e.g. 3"
+    ))?;
+    if count == 0 || count > MAX_COMMITS_PER_ROW {
+        return Err(TimeTravelError::invalid_input(
+            "count",
+            &count.to_string(),
+            &format!("must be between 1 and {}", MAX_COMMITS_PER_ROW),
+            "Split large counts across several rows"
+        ).into());
+    }
+
+    let author = author.as_deref().map(parse_author).transpose()?;
+
+    Ok(ScheduleEntry { line, timestamp, message, count, author })
+}
+
+/// Parse a schedule datetime. Accepts RFC 3339, "YYYY-MM-DD HH:MM[:SS]" (UTC)
+/// and plain dates, which are placed at the default schedule hour.
+pub fn parse_datetime(input: &str) -> Result<DateTime<Utc>> {
+    let trimmed = input.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(trimmed, format) {
+            return Ok(Utc.from_utc_datetime(&datetime));
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        if let Some(datetime) = date.and_hms_opt(DEFAULT_SCHEDULE_HOUR, 0, 0) {
+            return Ok(Utc.from_utc_datetime(&datetime));
+        }
+    }
+
+    Err(TimeTravelError::invalid_input(
+        "datetime",
+        input,
+        "unrecognized date format",
+        "Use YYYY-MM-DD, YYYY-MM-DD HH:MM[:SS] or an RFC 3339 timestamp"
+    ).into())
+}
+
+/// Parse an author written as "Name <email>"
+pub(crate) fn parse_author(input: &str) -> Result<GitIdentity> {
+    let invalid = || TimeTravelError::invalid_input(
+        "author",
+        input,
+        "must look like 'Name <email>'",
+        "Write the author as e.g. Jane Doe <jane@example.com>"
+    );
+
+    let (name, rest) = input.split_once('<').ok_or_else(invalid)?;
+    let email = rest.strip_suffix('>').ok_or_else(invalid)?.trim();
+    let name = name.trim();
+    if name.is_empty() || !email.contains('@') {
+        return Err(invalid().into());
+    }
+
+    Ok(GitIdentity {
+        name: name.to_string(),
+        email: email.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_schedule() {
+        let content = "datetime,message,count,author\n\
+                       1995-06-01 09:30,Initial sketch,2,\n\
+                       1994-01-15,\"Fix parser, again\",1,Jane Doe <jane@example.com>\n";
+        let schedule = CommitSchedule::parse(content, ScheduleFormat::Csv).unwrap();
+
+        assert_eq!(schedule.entries.len(), 2);
+        assert_eq!(schedule.total_commits(), 3);
+        assert_eq!(schedule.years(), vec![1994, 1995]);
+
+        let first = &schedule.entries[0];
+        assert_eq!(first.line, 3);
+        assert_eq!(first.message, "Fix parser, again");
+        assert_eq!(first.timestamp.hour(), DEFAULT_SCHEDULE_HOUR);
+        assert_eq!(first.author.as_ref().unwrap().email, "jane@example.com");
+
+        let default_author = GitIdentity {
+            name: "Git Time Traveler".to_string(),
+            email: "timetraveler@example.com".to_string(),
+        };
+        let configs = schedule.to_commit_configs(&default_author);
+        assert_eq!(configs.len(), 3);
+        assert_eq!(configs[1].author, default_author);
+        assert_eq!(configs[2].timestamp - configs[1].timestamp, Duration::minutes(1));
+    }
+
+    #[test]
+    fn test_parse_json_schedule() {
+        let content = r#"[
+  {"datetime": "2001-03-04T05:06:07Z", "message": "First", "count": 1},
+  {
+    "datetime": "2001-03-05",
+    "message": "Second",
+    "count": 4,
+    "author": "Jane Doe <jane@example.com>"
+  }
+]"#;
+        let schedule = CommitSchedule::parse(content, ScheduleFormat::Json).unwrap();
+
+        assert_eq!(schedule.entries.len(), 2);
+        assert_eq!(schedule.entries[0].line, 2);
+        assert_eq!(schedule.entries[1].line, 3);
+        assert_eq!(schedule.total_commits(), 5);
+    }
+
+    #[test]
+    fn test_invalid_rows_report_line_numbers() {
+        let content = "1990-01-01,ok,1\n\
+                       1960-01-01,too early,1\n\
+                       1990-02-01,,1\n\
+                       1990-03-01,zero,0\n\
+                       1990-04-01,bad author,1,nobody\n";
+        let error = CommitSchedule::parse(content, ScheduleFormat::Csv).unwrap_err().to_string();
+
+        assert!(error.contains("4 invalid row(s)"));
+        assert!(error.contains("line 2: Invalid year"));
+        assert!(error.contains("line 3: Invalid message"));
+        assert!(error.contains("line 4: Invalid count"));
+        assert!(error.contains("line 5: Invalid author"));
+        assert!(!error.contains("line 1:"));
+
+        let json = "[\n  {\"datetime\": \"1990-01-01\", \"message\": \"x\"}\n]";
+        let error = CommitSchedule::parse(json, ScheduleFormat::Json).unwrap_err().to_string();
+        assert!(error.contains("line 2: missing field `count`"));
+    }
+
+    #[test]
+    fn test_schedule_format_detection() {
+        assert_eq!(ScheduleFormat::from_path(Path::new("plan.CSV")).unwrap(), ScheduleFormat::Csv);
+        assert_eq!(ScheduleFormat::from_path(Path::new("plan.json")).unwrap(), ScheduleFormat::Json);
+        assert!(ScheduleFormat::from_path(Path::new("plan.xlsx")).is_err());
+    }
+}
@@ -1,11 +1,121 @@
 use anyhow::{Context, Result};
 use bincode;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::errors::TimeTravelError;
+use crate::git_context::GitIdentity;
+use crate::t;
+
+/// Current session format version, stored in the file header and in `SessionMetadata`
+pub const SESSION_VERSION: u32 = 4;
+
+/// Environment variable selecting the session file format: `binary` (default) or `json`
+pub const SESSION_FORMAT_ENV: &str = "GIT_TIMETRAVELER_SESSION_FORMAT";
+
+/// Marks binary session files from version 2 on; version 1 files are bare bincode
+const MAGIC: &[u8; 4] = b"GTTS";
+
+/// Advisory lock file next to the session, held while loading and saving
+const LOCK_FILE: &str = "session.lock";
+
+const MAX_FAVORITE_YEARS: usize = 10;
+const MAX_FAVORITE_REPOSITORIES: usize = 5;
+const MAX_RECENT_CONTEXTS: usize = 10;
+
+/// Upgrades of the serialized data from version `index + 1` to `index + 2`.
+///
+/// Migrations work on the JSON representation, so they apply to both file formats.
+/// When bumping [`SESSION_VERSION`], append a migration here and freeze the previous
+/// `SessionData` layout in [`legacy`] so old binary files can still be decoded.
+const MIGRATIONS: &[fn(Value) -> Result<Value>] = &[
+    migrate_v1_to_v2,
+    migrate_v2_to_v3,
+    migrate_v3_to_v4,
+];
+
+/// Version 2 only introduced the versioned file header; the data is unchanged
+fn migrate_v1_to_v2(value: Value) -> Result<Value> {
+    Ok(value)
+}
+
+/// Version 3 added named profiles; existing preferences stay unscoped
+fn migrate_v2_to_v3(mut value: Value) -> Result<Value> {
+    value["profiles"] = Value::Object(Default::default());
+    Ok(value)
+}
+
+/// Version 4 records how suggestions were received, in the shared and every profile's preferences
+fn migrate_v3_to_v4(mut value: Value) -> Result<Value> {
+    value["user_preferences"]["suggestion_feedback"] = Value::Object(Default::default());
+    if let Some(profiles) = value["profiles"].as_object_mut() {
+        for profile in profiles.values_mut() {
+            profile["preferences"]["suggestion_feedback"] = Value::Object(Default::default());
+        }
+    }
+    Ok(value)
+}
+
+/// On-disk encoding of the session file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionFormat {
+    /// Compact bincode with a version header (`session.bin`)
+    Binary,
+    /// Human-readable, pretty-printed JSON (`session.json`)
+    Json,
+}
+
+impl SessionFormat {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "binary" => Ok(Self::Binary),
+            "json" => Ok(Self::Json),
+            _ => Err(TimeTravelError::invalid_input(
+                "session format",
+                name,
+                "Unknown session format",
+                "Use 'binary' or 'json'",
+            ).into()),
+        }
+    }
+
+    /// The format named by [`SESSION_FORMAT_ENV`], binary if unset
+    pub fn from_env() -> Result<Self> {
+        match std::env::var(SESSION_FORMAT_ENV) {
+            Ok(name) if !name.is_empty() => Self::from_name(&name),
+            _ => Ok(Self::Binary),
+        }
+    }
+
+    /// The format a session file is written in, judged by its extension
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::Json,
+            _ => Self::Binary,
+        }
+    }
+
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Binary => "session.bin",
+            Self::Json => "session.json",
+        }
+    }
+
+    fn other(self) -> Self {
+        match self {
+            Self::Binary => Self::Json,
+            Self::Json => Self::Binary,
+        }
+    }
+}
+
 /// Session data that persists across npx executions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionData {
@@ -15,10 +125,51 @@ pub struct SessionData {
     pub recent_contexts: Vec<RecentContext>,
     /// Session metadata
     pub metadata: SessionMetadata,
+    /// Named profiles, e.g. "work" and "personal"
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// A named identity with its own settings and learned preferences
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    /// GitHub username used with this profile
+    pub github_username: Option<String>,
+    /// Author identity for commits made with this profile
+    pub author: Option<GitIdentity>,
+    /// Where the GitHub token comes from
+    pub token_source: TokenSource,
+    /// Organization that owns the repositories by default
+    pub default_owner: Option<String>,
+    /// Preferred hours for commits, the first one being the default
+    pub preferred_hours: Vec<u32>,
+    /// Preferences learned while this profile was active
+    pub preferences: UserPreferences,
+    /// Recent contexts used with this profile
+    pub recent_contexts: Vec<RecentContext>,
+}
+
+/// Where a profile's GitHub token comes from (tokens are never stored in the session)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenSource {
+    /// Ask for the token on every run
+    #[default]
+    Prompt,
+    /// Read the token from the named environment variable
+    Env(String),
+}
+
+impl TokenSource {
+    /// The token, if this source provides one without asking
+    pub fn resolve(&self) -> Option<String> {
+        match self {
+            Self::Prompt => None,
+            Self::Env(variable) => std::env::var(variable).ok().filter(|token| !token.is_empty()),
+        }
+    }
 }
 
 /// User preferences that are learned and stored
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPreferences {
     /// Preferred author mode (current user, time traveler, etc.)
     pub preferred_author_mode: Option<String>,
@@ -32,10 +183,27 @@ pub struct UserPreferences {
     pub github_username: Option<String>,
     /// Preferred branch names
     pub preferred_branches: Vec<String>,
+    /// How often each kind of suggestion was accepted or rejected
+    pub suggestion_feedback: BTreeMap<String, SuggestionFeedback>,
+}
+
+/// Answers given to one kind of suggestion
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuggestionFeedback {
+    pub accepted: u32,
+    pub rejected: u32,
+}
+
+impl SuggestionFeedback {
+    /// Factor for the confidence of this kind of suggestion: 1.0 without answers,
+    /// up to 2.0 when it is always accepted and towards 0.0 when it keeps being rejected
+    pub fn weight(&self) -> f32 {
+        2.0 * (self.accepted as f32 + 1.0) / ((self.accepted + self.rejected) as f32 + 2.0)
+    }
 }
 
 /// Recent context information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RecentContext {
     /// Working directory path
     pub working_directory: PathBuf,
@@ -78,8 +246,9 @@ impl Default for SessionData {
                 total_executions: 0,
                 first_use: now,
                 last_use: now,
-                version: 1,
+                version: SESSION_VERSION,
             },
+            profiles: BTreeMap::new(),
         }
     }
 }
@@ -93,36 +262,105 @@ impl Default for UserPreferences {
             favorite_repositories: Vec::new(),
             github_username: None,
             preferred_branches: vec!["main".to_string(), "master".to_string()],
+            suggestion_feedback: BTreeMap::new(),
         }
     }
 }
 
-/// Session manager for handling persistence across npx executions
+/// Session manager for handling persistence across npx executions.
+///
+/// Several runs can share the session: saving merges this run's changes into
+/// whatever other runs saved since it was loaded, instead of overwriting them.
 pub struct SessionManager {
-    #[allow(dead_code)]
     pub session_dir: PathBuf,
     pub session_file: PathBuf,
     pub data: SessionData,
+    /// Profile that suggestions come from and learning goes to; `None` uses the shared preferences
+    pub active_profile: Option<String>,
+    /// The data as last loaded or saved, to tell this run's changes apart from other runs'
+    baseline: SessionData,
+    /// Version of a session file written by a newer release. It is left untouched,
+    /// so nothing is saved over it.
+    newer_version: Option<u32>,
+}
+
+/// A session file written by a newer release, which this one cannot read and must not overwrite
+#[derive(Debug, Clone, Copy)]
+pub struct NewerSessionVersion(pub u32);
+
+impl std::fmt::Display for NewerSessionVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Session file version {} is newer than this release supports ({})", self.0, SESSION_VERSION)
+    }
+}
+
+impl std::error::Error for NewerSessionVersion {}
+
+/// Advisory lock on the session directory, released when dropped
+struct SessionLock(fs::File);
+
+impl SessionLock {
+    /// Wait for the lock: shared for reading, exclusive for read-merge-write
+    fn acquire(session_dir: &Path, exclusive: bool) -> Result<Self> {
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(session_dir.join(LOCK_FILE))
+            .context("Failed to open session lock file")?;
+        if exclusive { file.lock() } else { file.lock_shared() }
+            .context("Failed to lock session file")?;
+        Ok(Self(file))
+    }
+}
+
+impl Drop for SessionLock {
+    fn drop(&mut self) {
+        let _ = self.0.unlock();
+    }
 }
 
 impl SessionManager {
     /// Create a new session manager
     pub fn new() -> Result<Self> {
-        let session_dir = Self::get_session_directory()?;
-        let session_file = session_dir.join("session.bin");
+        Self::open(Self::get_session_directory()?, SessionFormat::from_env()?)
+    }
+
+    /// Open the session kept in `session_dir`, stored in `format`
+    pub fn open(session_dir: PathBuf, format: SessionFormat) -> Result<Self> {
+        let session_file = session_dir.join(format.file_name());
         
         // Ensure session directory exists
         fs::create_dir_all(&session_dir)
             .context("Failed to create session directory")?;
         
-        // Load existing session data or create new
-        let data = Self::load_session_data(&session_file)
-            .unwrap_or_else(|_| SessionData::default());
+        // Load existing session data (converting from the other format if needed) or create new
+        let fallback = session_dir.join(format.other().file_name());
+        let loaded = {
+            let _lock = SessionLock::acquire(&session_dir, false)?;
+            Self::load_or_recover(&session_file, &fallback, false)
+        };
+        // Moving an unreadable file aside would pull it from under other readers, so that waits
+        // for the exclusive lock. Another run may have recovered it meanwhile, hence the second read.
+        let loaded = match loaded {
+            Ok(None) => {
+                let _lock = SessionLock::acquire(&session_dir, true)?;
+                Self::load_or_recover(&session_file, &fallback, true)
+            }
+            loaded => loaded,
+        };
+        let (data, newer_version) = match loaded {
+            Ok(data) => (data.unwrap_or_default(), None),
+            Err(NewerSessionVersion(version)) => (SessionData::default(), Some(version)),
+        };
         
         Ok(Self {
             session_dir,
             session_file,
+            baseline: data.clone(),
             data,
+            active_profile: None,
+            newer_version,
         })
     }
 
@@ -134,38 +372,163 @@ impl SessionManager {
         Ok(home_dir.join(".config").join("git-timetraveler"))
     }
 
-    /// Load session data from file
+    /// Load `session_file`, or `fallback` (the same session in the other format) if it does not exist.
+    ///
+    /// With `recover`, a file that cannot be read is moved aside to `<name>.corrupt-<timestamp>`
+    /// before starting over with defaults, so its history can still be recovered by hand; this
+    /// needs the exclusive lock. Without it, such a file is left alone and `None` is returned.
+    /// A file from a newer release is not touched at all.
+    fn load_or_recover(session_file: &Path, fallback: &Path, recover: bool) -> std::result::Result<Option<SessionData>, NewerSessionVersion> {
+        let source = if session_file.exists() {
+            session_file
+        } else if fallback.exists() {
+            fallback
+        } else {
+            return Ok(Some(SessionData::default()));
+        };
+
+        match Self::load_session_data(source) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) => {
+                if let Some(&newer) = e.downcast_ref::<NewerSessionVersion>() {
+                    Self::warn_newer_version(source, newer);
+                    return Err(newer);
+                }
+                if !recover {
+                    return Ok(None);
+                }
+                let backup = Self::back_up_unreadable(source);
+                tracing::warn!(target: "defaults", path = %source.display(), error = %format!("{:#}", e),
+                    backup = ?backup.as_ref().ok(), "Unreadable session file replaced with defaults");
+                match backup {
+                    Ok(backup) => eprintln!("{}", t!("session.unreadable_backed_up",
+                        path = source.display(), error = format!("{:#}", e), backup = backup.display())),
+                    Err(_) => eprintln!("{}", t!("session.unreadable", path = source.display(), error = format!("{:#}", e))),
+                }
+                Ok(Some(SessionData::default()))
+            }
+        }
+    }
+
+    fn warn_newer_version(path: &Path, NewerSessionVersion(version): NewerSessionVersion) {
+        tracing::warn!(target: "defaults", path = %path.display(), version, supported = SESSION_VERSION,
+            "Session file from a newer release left untouched");
+        eprintln!("{}", t!("session.newer_version", path = path.display(), version = version, supported = SESSION_VERSION));
+    }
+
+    /// Whether the session file comes from a newer release, in which case nothing is saved
+    pub fn is_read_only(&self) -> bool {
+        self.newer_version.is_some()
+    }
+
+    /// Move an unreadable session file out of the way, returning its new path
+    fn back_up_unreadable(path: &Path) -> Result<PathBuf> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".corrupt-{}", now));
+        let backup = path.with_file_name(name);
+        fs::rename(path, &backup)
+            .context("Failed to back up unreadable session file")?;
+        Ok(backup)
+    }
+
+    /// Load session data from file, migrating older versions
     fn load_session_data(file_path: &Path) -> Result<SessionData> {
         let data = fs::read(file_path)
             .context("Failed to read session file")?;
         
-        let session_data: SessionData = bincode::deserialize(&data)
-            .context("Failed to deserialize session data")?;
-        
-        Ok(session_data)
+        decode_session(&data)
     }
 
-    /// Save session data to file
+    /// The same session in the format this manager does not write
+    fn other_format_file(&self) -> PathBuf {
+        self.session_file.with_file_name(SessionFormat::from_path(&self.session_file).other().file_name())
+    }
+
+    /// The session as other runs left it on disk, in either format
+    fn read_saved(&self) -> std::result::Result<Option<SessionData>, NewerSessionVersion> {
+        let other = self.other_format_file();
+        let Some(path) = [&self.session_file, &other].into_iter().find(|path| path.exists()) else {
+            return Ok(None);
+        };
+        match Self::load_session_data(path) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) => {
+                if let Some(&newer) = e.downcast_ref::<NewerSessionVersion>() {
+                    Self::warn_newer_version(path, newer);
+                    return Err(newer);
+                }
+                tracing::warn!(target: "defaults", path = %path.display(), error = %format!("{:#}", e),
+                    "Unreadable session file overwritten on save");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Save session data to file, merged with what other runs saved in the meantime.
+    ///
+    /// Nothing is written while the session file comes from a newer release
+    /// (see [`is_read_only`](Self::is_read_only)); the user was warned when it was found.
     pub fn save(&mut self) -> Result<()> {
+        if self.is_read_only() {
+            tracing::debug!(target: "defaults", "Session from a newer release, not saving");
+            return Ok(());
+        }
+        let _lock = SessionLock::acquire(&self.session_dir, true)?;
+        let saved = match self.read_saved() {
+            Ok(saved) => saved.unwrap_or_else(|| self.baseline.clone()),
+            Err(NewerSessionVersion(version)) => {
+                self.newer_version = Some(version);
+                return Ok(());
+            }
+        };
+        let mut merged = merge_sessions(&self.data, &self.baseline, &saved);
+
         // Update metadata
-        self.data.metadata.last_use = SystemTime::now()
+        merged.metadata.last_use = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs();
-        self.data.metadata.total_executions += 1;
+        merged.metadata.total_executions += 1;
+        merged.metadata.version = SESSION_VERSION;
 
         // Serialize data
-        let serialized = bincode::serialize(&self.data)
-            .context("Failed to serialize session data")?;
+        let serialized = encode_session(&merged, SessionFormat::from_path(&self.session_file))?;
         
-        // Write to file atomically
+        // Write to a temp file and flush it to disk before replacing the session,
+        // so a crash leaves either the old or the new file intact
         let temp_file = self.session_file.with_extension("tmp");
-        fs::write(&temp_file, serialized)
+        let mut file = fs::File::create(&temp_file)
+            .context("Failed to create session temp file")?;
+        file.write_all(&serialized)
             .context("Failed to write session data to temp file")?;
+        file.sync_all()
+            .context("Failed to flush session data to disk")?;
+        drop(file);
         
         fs::rename(&temp_file, &self.session_file)
             .context("Failed to move temp file to session file")?;
         
+        // Persist the rename itself
+        #[cfg(unix)]
+        if let Some(dir) = self.session_file.parent() {
+            fs::File::open(dir)
+                .and_then(|dir| dir.sync_all())
+                .context("Failed to flush session directory")?;
+        }
+        
+        // The session now lives in this format only
+        let other = self.other_format_file();
+        if other.exists() {
+            fs::remove_file(&other)
+                .context("Failed to remove session file in the previous format")?;
+        }
+        
+        self.baseline = merged.clone();
+        self.data = merged;
         Ok(())
     }
 
@@ -179,48 +542,129 @@ impl SessionManager {
         &mut self.data
     }
 
-    /// Update user preferences based on recent choices
+    /// Names of all profiles, sorted
+    pub fn profile_names(&self) -> Vec<&str> {
+        self.data.profiles.keys().map(String::as_str).collect()
+    }
+
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.data.profiles.get(name)
+    }
+
+    /// Create or update a profile, keeping what it has learned so far
+    pub fn set_profile(&mut self, name: &str, profile: Profile) -> Result<()> {
+        validate_profile_name(name)?;
+        let entry = self.data.profiles.entry(name.to_string()).or_default();
+        *entry = Profile {
+            preferences: std::mem::take(&mut entry.preferences),
+            recent_contexts: std::mem::take(&mut entry.recent_contexts),
+            ..profile
+        };
+        Ok(())
+    }
+
+    /// Delete a profile, returning whether it existed
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        if self.active_profile.as_deref() == Some(name) {
+            self.active_profile = None;
+        }
+        self.data.profiles.remove(name).is_some()
+    }
+
+    /// Scope suggestions and learning to a profile, or to the shared preferences with `None`
+    pub fn activate_profile(&mut self, name: Option<&str>) -> Result<()> {
+        if let Some(name) = name {
+            if !self.data.profiles.contains_key(name) {
+                return Err(unknown_profile(name, &self.profile_names()).into());
+            }
+        }
+        self.active_profile = name.map(str::to_string);
+        Ok(())
+    }
+
+    /// The active profile with its name
+    pub fn active_profile(&self) -> Option<(&str, &Profile)> {
+        let name = self.active_profile.as_deref()?;
+        self.data.profiles.get(name).map(|profile| (name, profile))
+    }
+
+    fn preferences(&self) -> &UserPreferences {
+        match self.active_profile() {
+            Some((_, profile)) => &profile.preferences,
+            None => &self.data.user_preferences,
+        }
+    }
+
+    fn preferences_mut(&mut self) -> &mut UserPreferences {
+        match self.active_profile.as_deref().and_then(|name| self.data.profiles.get_mut(name)) {
+            Some(profile) => &mut profile.preferences,
+            None => &mut self.data.user_preferences,
+        }
+    }
+
+    fn contexts(&self) -> &Vec<RecentContext> {
+        match self.active_profile() {
+            Some((_, profile)) => &profile.recent_contexts,
+            None => &self.data.recent_contexts,
+        }
+    }
+
+    fn contexts_mut(&mut self) -> &mut Vec<RecentContext> {
+        match self.active_profile.as_deref().and_then(|name| self.data.profiles.get_mut(name)) {
+            Some(profile) => &mut profile.recent_contexts,
+            None => &mut self.data.recent_contexts,
+        }
+    }
+
+    /// Update user preferences based on recent choices, within the active profile
     pub fn learn_from_choice(&mut self, choice_type: &str, value: &str) {
+        let preferences = self.preferences_mut();
         match choice_type {
             "author_mode" => {
-                self.data.user_preferences.preferred_author_mode = Some(value.to_string());
+                preferences.preferred_author_mode = Some(value.to_string());
             }
             "year" => {
                 if let Ok(year) = value.parse::<u32>() {
-                    if !self.data.user_preferences.favorite_years.contains(&year) {
-                        self.data.user_preferences.favorite_years.push(year);
+                    if !preferences.favorite_years.contains(&year) {
+                        preferences.favorite_years.push(year);
                         // Keep only the most recent 10 years
-                        if self.data.user_preferences.favorite_years.len() > 10 {
-                            self.data.user_preferences.favorite_years.remove(0);
+                        if preferences.favorite_years.len() > MAX_FAVORITE_YEARS {
+                            preferences.favorite_years.remove(0);
                         }
                     }
                 }
             }
             "repository" => {
                 let repo = value.to_string();
-                if !self.data.user_preferences.favorite_repositories.contains(&repo) {
-                    self.data.user_preferences.favorite_repositories.push(repo);
+                if !preferences.favorite_repositories.contains(&repo) {
+                    preferences.favorite_repositories.push(repo);
                     // Keep only the most recent 5 repositories
-                    if self.data.user_preferences.favorite_repositories.len() > 5 {
-                        self.data.user_preferences.favorite_repositories.remove(0);
+                    if preferences.favorite_repositories.len() > MAX_FAVORITE_REPOSITORIES {
+                        preferences.favorite_repositories.remove(0);
                     }
                 }
             }
             "github_username" => {
-                self.data.user_preferences.github_username = Some(value.to_string());
+                preferences.github_username = Some(value.to_string());
             }
             "hour" => {
                 if let Ok(hour) = value.parse::<u32>() {
                     if hour <= 23 {
-                        self.data.user_preferences.preferred_hour = Some(hour);
+                        preferences.preferred_hour = Some(hour);
                     }
                 }
             }
+            "suggestion_accepted" => {
+                preferences.suggestion_feedback.entry(value.to_string()).or_default().accepted += 1;
+            }
+            "suggestion_rejected" => {
+                preferences.suggestion_feedback.entry(value.to_string()).or_default().rejected += 1;
+            }
             _ => {} // Unknown choice type
         }
     }
 
-    /// Add or update a recent context
+    /// Add or update a recent context of the active profile
     pub fn update_context(&mut self, working_dir: &Path, repo_name: Option<&str>, 
                          branch_name: Option<&str>, user_identity: Option<&str>, 
                          success: bool) {
@@ -229,9 +673,11 @@ impl SessionManager {
             .unwrap()
             .as_secs();
 
+        let contexts = self.contexts_mut();
+
         // Find existing context or create new one
         let mut found = false;
-        for context in &mut self.data.recent_contexts {
+        for context in contexts.iter_mut() {
             if context.working_directory == working_dir {
                 context.last_used = now;
                 if success {
@@ -261,15 +707,11 @@ impl SessionManager {
                 last_used: now,
                 success_count: if success { 1 } else { 0 },
             };
-            self.data.recent_contexts.push(new_context);
+            contexts.push(new_context);
         }
 
         // Keep only the most recent 10 contexts
-        if self.data.recent_contexts.len() > 10 {
-            // Sort by last_used and keep the most recent
-            self.data.recent_contexts.sort_by(|a, b| b.last_used.cmp(&a.last_used));
-            self.data.recent_contexts.truncate(10);
-        }
+        keep_recent_contexts(contexts);
     }
 
     /// Get suggestions based on current context and history
@@ -277,7 +719,7 @@ impl SessionManager {
         let mut suggestions = SessionSuggestions::default();
 
         // Find matching recent context
-        let matching_context = self.data.recent_contexts.iter()
+        let matching_context = self.contexts().iter()
             .find(|ctx| ctx.working_directory == current_dir);
 
         if let Some(context) = matching_context {
@@ -287,11 +729,28 @@ impl SessionManager {
         }
 
         // Add general preferences
-        suggestions.suggested_author_mode = self.data.user_preferences.preferred_author_mode.clone();
-        suggestions.suggested_hour = self.data.user_preferences.preferred_hour;
-        suggestions.suggested_years = self.data.user_preferences.favorite_years.clone();
-        suggestions.suggested_repositories = self.data.user_preferences.favorite_repositories.clone();
-        suggestions.github_username = self.data.user_preferences.github_username.clone();
+        let preferences = self.preferences();
+        suggestions.suggested_author_mode = preferences.preferred_author_mode.clone();
+        suggestions.suggested_hour = preferences.preferred_hour;
+        suggestions.suggested_years = preferences.favorite_years.clone();
+        suggestions.suggested_repositories = preferences.favorite_repositories.clone();
+        suggestions.github_username = preferences.github_username.clone();
+        suggestions.suggestion_feedback = preferences.suggestion_feedback.clone();
+
+        // Settings of the active profile win over anything learned
+        if let Some((name, profile)) = self.active_profile() {
+            suggestions.profile = Some(name.to_string());
+            suggestions.author_identity = profile.author.clone();
+            suggestions.default_owner = profile.default_owner.clone();
+            if profile.github_username.is_some() {
+                suggestions.github_username = profile.github_username.clone();
+            }
+            if !suggestions.suggested_hour.is_some_and(|hour| profile.preferred_hours.contains(&hour)) {
+                if let Some(&hour) = profile.preferred_hours.first() {
+                    suggestions.suggested_hour = Some(hour);
+                }
+            }
+        }
 
         suggestions
     }
@@ -305,16 +764,20 @@ impl SessionManager {
         
         // Remove contexts older than 30 days
         let thirty_days_ago = now - (30 * 24 * 60 * 60);
-        self.data.recent_contexts.retain(|ctx| ctx.last_used > thirty_days_ago);
+        let scopes = std::iter::once((&mut self.data.user_preferences, &mut self.data.recent_contexts))
+            .chain(self.data.profiles.values_mut().map(|profile| (&mut profile.preferences, &mut profile.recent_contexts)));
+        for (preferences, contexts) in scopes {
+            contexts.retain(|ctx| ctx.last_used > thirty_days_ago);
 
-        // Limit favorite years to reasonable number
-        if self.data.user_preferences.favorite_years.len() > 20 {
-            self.data.user_preferences.favorite_years.truncate(20);
-        }
+            // Limit favorite years to reasonable number
+            if preferences.favorite_years.len() > 20 {
+                preferences.favorite_years.truncate(20);
+            }
 
-        // Limit favorite repositories
-        if self.data.user_preferences.favorite_repositories.len() > 10 {
-            self.data.user_preferences.favorite_repositories.truncate(10);
+            // Limit favorite repositories
+            if preferences.favorite_repositories.len() > 10 {
+                preferences.favorite_repositories.truncate(10);
+            }
         }
 
         Ok(())
@@ -331,10 +794,320 @@ impl SessionManager {
                     .as_secs();
                 (now - self.data.metadata.first_use) / (24 * 60 * 60)
             },
-            recent_contexts_count: self.data.recent_contexts.len(),
-            favorite_years_count: self.data.user_preferences.favorite_years.len(),
+            recent_contexts_count: self.contexts().len(),
+            favorite_years_count: self.preferences().favorite_years.len(),
+        }
+    }
+}
+
+/// Sort by last_used and keep the most recent contexts
+fn keep_recent_contexts(contexts: &mut Vec<RecentContext>) {
+    if contexts.len() > MAX_RECENT_CONTEXTS {
+        contexts.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+        contexts.truncate(MAX_RECENT_CONTEXTS);
+    }
+}
+
+/// Three-way merge of this run's session (`ours`, loaded as `base`) into the saved one (`theirs`).
+///
+/// Counters add up the increments of both runs and recent contexts are combined by
+/// directory. For any other value, a change made by this run wins over the saved value.
+fn merge_sessions(ours: &SessionData, base: &SessionData, theirs: &SessionData) -> SessionData {
+    let names: BTreeSet<&String> = ours.profiles.keys().chain(theirs.profiles.keys()).collect();
+    let profiles = names.into_iter().filter_map(|name| {
+        let profile = match (ours.profiles.get(name), base.profiles.get(name), theirs.profiles.get(name)) {
+            (Some(ours), Some(base), Some(theirs)) => merge_profiles(ours, base, theirs),
+            (Some(ours), None, Some(theirs)) => merge_profiles(ours, &Profile::default(), theirs),
+            // Removed by another run: kept only if this run changed it
+            (Some(ours), Some(base), None) if ours == base => return None,
+            (Some(ours), _, None) => ours.clone(),
+            // Removed by this run
+            (None, Some(_), _) => return None,
+            (None, None, theirs) => theirs?.clone(),
+        };
+        Some((name.clone(), profile))
+    }).collect();
+
+    SessionData {
+        user_preferences: merge_preferences(&ours.user_preferences, &base.user_preferences, &theirs.user_preferences),
+        recent_contexts: merge_contexts(&ours.recent_contexts, &base.recent_contexts, &theirs.recent_contexts),
+        metadata: SessionMetadata {
+            total_executions: theirs.metadata.total_executions
+                + ours.metadata.total_executions.saturating_sub(base.metadata.total_executions),
+            first_use: ours.metadata.first_use.min(theirs.metadata.first_use),
+            last_use: ours.metadata.last_use.max(theirs.metadata.last_use),
+            version: SESSION_VERSION,
+        },
+        profiles,
+    }
+}
+
+fn merge_profiles(ours: &Profile, base: &Profile, theirs: &Profile) -> Profile {
+    Profile {
+        github_username: pick(&ours.github_username, &base.github_username, &theirs.github_username),
+        author: pick(&ours.author, &base.author, &theirs.author),
+        token_source: pick(&ours.token_source, &base.token_source, &theirs.token_source),
+        default_owner: pick(&ours.default_owner, &base.default_owner, &theirs.default_owner),
+        preferred_hours: pick(&ours.preferred_hours, &base.preferred_hours, &theirs.preferred_hours),
+        preferences: merge_preferences(&ours.preferences, &base.preferences, &theirs.preferences),
+        recent_contexts: merge_contexts(&ours.recent_contexts, &base.recent_contexts, &theirs.recent_contexts),
+    }
+}
+
+fn merge_preferences(ours: &UserPreferences, base: &UserPreferences, theirs: &UserPreferences) -> UserPreferences {
+    UserPreferences {
+        preferred_author_mode: pick(&ours.preferred_author_mode, &base.preferred_author_mode, &theirs.preferred_author_mode),
+        favorite_years: merge_list(&ours.favorite_years, &base.favorite_years, &theirs.favorite_years, MAX_FAVORITE_YEARS),
+        preferred_hour: pick(&ours.preferred_hour, &base.preferred_hour, &theirs.preferred_hour),
+        favorite_repositories: merge_list(&ours.favorite_repositories, &base.favorite_repositories,
+            &theirs.favorite_repositories, MAX_FAVORITE_REPOSITORIES),
+        github_username: pick(&ours.github_username, &base.github_username, &theirs.github_username),
+        preferred_branches: pick(&ours.preferred_branches, &base.preferred_branches, &theirs.preferred_branches),
+        suggestion_feedback: merge_feedback(&ours.suggestion_feedback, &base.suggestion_feedback, &theirs.suggestion_feedback),
+    }
+}
+
+/// Add the answers this run recorded to the saved ones
+fn merge_feedback(
+    ours: &BTreeMap<String, SuggestionFeedback>,
+    base: &BTreeMap<String, SuggestionFeedback>,
+    theirs: &BTreeMap<String, SuggestionFeedback>,
+) -> BTreeMap<String, SuggestionFeedback> {
+    let mut merged = theirs.clone();
+    for (kind, feedback) in ours {
+        let loaded = base.get(kind).copied().unwrap_or_default();
+        let saved = merged.entry(kind.clone()).or_default();
+        saved.accepted += feedback.accepted.saturating_sub(loaded.accepted);
+        saved.rejected += feedback.rejected.saturating_sub(loaded.rejected);
+    }
+    merged
+}
+
+/// The value if this run changed it, otherwise the saved one
+fn pick<T: Clone + PartialEq>(ours: &T, base: &T, theirs: &T) -> T {
+    if ours != base { ours.clone() } else { theirs.clone() }
+}
+
+/// The saved list with this run's removals and additions applied, keeping the newest `limit` items
+fn merge_list<T: Clone + PartialEq>(ours: &[T], base: &[T], theirs: &[T], limit: usize) -> Vec<T> {
+    let mut merged: Vec<T> = theirs.iter()
+        .filter(|item| ours.contains(item) || !base.contains(item))
+        .cloned()
+        .collect();
+    for item in ours {
+        if !base.contains(item) && !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+    let excess = merged.len().saturating_sub(limit);
+    merged.drain(..excess);
+    merged
+}
+
+/// Combine recent contexts by directory, adding up the successes of both runs
+fn merge_contexts(ours: &[RecentContext], base: &[RecentContext], theirs: &[RecentContext]) -> Vec<RecentContext> {
+    // Contexts this run dropped (cleanup or the size limit) stay dropped
+    let mut merged: Vec<RecentContext> = theirs.iter()
+        .filter(|saved| {
+            ours.iter().any(|context| context.working_directory == saved.working_directory)
+                || !base.iter().any(|context| context.working_directory == saved.working_directory)
+        })
+        .cloned()
+        .collect();
+
+    for context in ours {
+        let loaded = base.iter().find(|loaded| loaded.working_directory == context.working_directory);
+        if loaded == Some(context) {
+            continue;
+        }
+        match merged.iter_mut().find(|saved| saved.working_directory == context.working_directory) {
+            Some(saved) => {
+                saved.success_count += context.success_count.saturating_sub(loaded.map_or(0, |loaded| loaded.success_count));
+                // The newer run's details win, without dropping ones it did not record
+                if context.last_used >= saved.last_used {
+                    saved.last_used = context.last_used;
+                    saved.repository_name = context.repository_name.clone().or(saved.repository_name.take());
+                    saved.branch_name = context.branch_name.clone().or(saved.branch_name.take());
+                    saved.user_identity = context.user_identity.clone().or(saved.user_identity.take());
+                }
+            }
+            None => merged.push(context.clone()),
         }
     }
+
+    keep_recent_contexts(&mut merged);
+    merged
+}
+
+/// Serialize session data: binary files get a magic and version header
+fn encode_session(data: &SessionData, format: SessionFormat) -> Result<Vec<u8>> {
+    match format {
+        SessionFormat::Binary => {
+            let mut bytes = MAGIC.to_vec();
+            bytes.extend_from_slice(&SESSION_VERSION.to_le_bytes());
+            bytes.extend(bincode::serialize(data)
+                .context("Failed to serialize session data")?);
+            Ok(bytes)
+        }
+        SessionFormat::Json => serde_json::to_vec_pretty(data)
+            .context("Failed to serialize session data"),
+    }
+}
+
+/// Deserialize session data in any known format and version
+fn decode_session(bytes: &[u8]) -> Result<SessionData> {
+    if let Some(rest) = bytes.strip_prefix(MAGIC) {
+        let (version, payload) = rest.split_at_checked(4)
+            .context("Truncated session file header")?;
+        let version = u32::from_le_bytes(version.try_into()?);
+        return match version {
+            SESSION_VERSION => bincode::deserialize(payload)
+                .context("Failed to deserialize session data"),
+            2 => {
+                let legacy: legacy::SessionDataV1 = bincode::deserialize(payload)
+                    .context("Failed to deserialize session data")?;
+                migrate(serde_json::to_value(legacy)?, 2)
+            }
+            3 => {
+                let legacy: legacy::SessionDataV3 = bincode::deserialize(payload)
+                    .context("Failed to deserialize session data")?;
+                migrate(serde_json::to_value(legacy)?, 3)
+            }
+            _ => Err(unsupported_version(version)),
+        };
+    }
+
+    if bytes.first() == Some(&b'{') {
+        let value: Value = serde_json::from_slice(bytes)
+            .context("Failed to parse session JSON")?;
+        let version = value["metadata"]["version"].as_u64()
+            .context("Session JSON has no metadata.version")? as u32;
+        return migrate(value, version);
+    }
+
+    // Version 1 wrote bare bincode without a header
+    let legacy: legacy::SessionDataV1 = bincode::deserialize(bytes)
+        .context("Failed to deserialize session data")?;
+    migrate(serde_json::to_value(legacy)?, 1)
+}
+
+/// Bring serialized data of `version` up to [`SESSION_VERSION`]
+fn migrate(mut value: Value, version: u32) -> Result<SessionData> {
+    if version == 0 || version > SESSION_VERSION {
+        return Err(unsupported_version(version));
+    }
+    for from in version..SESSION_VERSION {
+        value = MIGRATIONS[(from - 1) as usize](value)
+            .with_context(|| format!("Failed to migrate session data from version {}", from))?;
+        value["metadata"]["version"] = Value::from(from + 1);
+    }
+    serde_json::from_value(value).context("Failed to deserialize migrated session data")
+}
+
+fn unsupported_version(version: u32) -> anyhow::Error {
+    if version > SESSION_VERSION {
+        NewerSessionVersion(version).into()
+    } else {
+        anyhow::anyhow!("Unknown session file version {}", version)
+    }
+}
+
+/// Profile names become file-system-safe identifiers and CLI arguments
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.len() > 40
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(TimeTravelError::invalid_input(
+            "profile",
+            name,
+            "Profile names must be 1-40 letters, digits, '-' or '_'",
+            "Choose a name like 'work' or 'personal'",
+        ).into());
+    }
+    Ok(())
+}
+
+fn unknown_profile(name: &str, known: &[&str]) -> TimeTravelError {
+    let suggestion = if known.is_empty() {
+        "Create one with 'git-timetraveler profile add <NAME>'".to_string()
+    } else {
+        format!("Known profiles: {}", known.join(", "))
+    };
+    TimeTravelError::invalid_input("profile", name, "Unknown profile", &suggestion)
+}
+
+/// Frozen layouts of earlier session versions, needed to decode their bincode files
+mod legacy {
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    /// Layout of versions 1 and 2
+    #[derive(Serialize, Deserialize)]
+    pub struct SessionDataV1 {
+        pub user_preferences: UserPreferencesV1,
+        pub recent_contexts: Vec<RecentContextV1>,
+        pub metadata: SessionMetadataV1,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct UserPreferencesV1 {
+        pub preferred_author_mode: Option<String>,
+        pub favorite_years: Vec<u32>,
+        pub preferred_hour: Option<u32>,
+        pub favorite_repositories: Vec<String>,
+        pub github_username: Option<String>,
+        pub preferred_branches: Vec<String>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct RecentContextV1 {
+        pub working_directory: PathBuf,
+        pub repository_name: Option<String>,
+        pub branch_name: Option<String>,
+        pub user_identity: Option<String>,
+        pub last_used: u64,
+        pub success_count: u32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct SessionMetadataV1 {
+        pub total_executions: u32,
+        pub first_use: u64,
+        pub last_use: u64,
+        pub version: u32,
+    }
+
+    /// Layout of version 3
+    #[derive(Serialize, Deserialize)]
+    pub struct SessionDataV3 {
+        pub user_preferences: UserPreferencesV1,
+        pub recent_contexts: Vec<RecentContextV1>,
+        pub metadata: SessionMetadataV1,
+        pub profiles: BTreeMap<String, ProfileV3>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct ProfileV3 {
+        pub github_username: Option<String>,
+        pub author: Option<GitIdentityV3>,
+        pub token_source: TokenSourceV3,
+        pub default_owner: Option<String>,
+        pub preferred_hours: Vec<u32>,
+        pub preferences: UserPreferencesV1,
+        pub recent_contexts: Vec<RecentContextV1>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct GitIdentityV3 {
+        pub name: String,
+        pub email: String,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub enum TokenSourceV3 {
+        Prompt,
+        Env(String),
+    }
 }
 
 /// Suggestions based on session history
@@ -348,6 +1121,14 @@ pub struct SessionSuggestions {
     pub suggested_years: Vec<u32>,
     pub suggested_repositories: Vec<String>,
     pub github_username: Option<String>,
+    /// How each kind of suggestion was received so far
+    pub suggestion_feedback: BTreeMap<String, SuggestionFeedback>,
+    /// Name of the profile the suggestions come from
+    pub profile: Option<String>,
+    /// Author identity configured in the active profile
+    pub author_identity: Option<GitIdentity>,
+    /// Default owner configured in the active profile
+    pub default_owner: Option<String>,
 }
 
 /// Session statistics
@@ -372,14 +1153,7 @@ mod tests {
         let session_dir = temp_dir.path().join(".config").join("git-timetraveler");
         fs::create_dir_all(&session_dir)?;
         
-        let session_file = session_dir.join("session.bin");
-        let data = SessionData::default();
-        
-        let manager = SessionManager {
-            session_dir,
-            session_file,
-            data,
-        };
+        let manager = SessionManager::open(session_dir, SessionFormat::Binary)?;
         
         Ok((temp_dir, manager))
     }
@@ -520,4 +1294,242 @@ mod tests {
         
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_legacy_and_json_sessions_are_migrated() -> Result<()> {
+        assert_eq!(MIGRATIONS.len() as u32, SESSION_VERSION - 1);
+
+        // Version 1: bare bincode, no header
+        let mut legacy_data = serde_json::to_value(SessionData::default())?;
+        legacy_data["metadata"]["version"] = Value::from(1);
+        legacy_data["user_preferences"]["favorite_years"] = serde_json::json!([1987]);
+        let legacy: legacy::SessionDataV1 = serde_json::from_value(legacy_data.clone())?;
+        let migrated = decode_session(&bincode::serialize(&legacy)?)?;
+        assert_eq!(migrated.metadata.version, SESSION_VERSION);
+        assert_eq!(migrated.user_preferences.favorite_years, vec![1987]);
+
+        // Version 2: same layout behind a header, gains an empty profile map
+        let mut version_2 = MAGIC.to_vec();
+        version_2.extend_from_slice(&2u32.to_le_bytes());
+        version_2.extend(bincode::serialize(&legacy)?);
+        let migrated = decode_session(&version_2)?;
+        assert_eq!(migrated.metadata.version, SESSION_VERSION);
+        assert_eq!(migrated.user_preferences.favorite_years, vec![1987]);
+        assert!(migrated.profiles.is_empty());
+
+        // Version 3: profiles gain an empty suggestion record
+        let mut version_3_data = serde_json::to_value(SessionData::default())?;
+        version_3_data["profiles"] = serde_json::json!({ "work": serde_json::to_value(Profile::default())? });
+        let version_3_data: legacy::SessionDataV3 = serde_json::from_value(version_3_data)?;
+        let mut version_3 = MAGIC.to_vec();
+        version_3.extend_from_slice(&3u32.to_le_bytes());
+        version_3.extend(bincode::serialize(&version_3_data)?);
+        let migrated = decode_session(&version_3)?;
+        assert_eq!(migrated.metadata.version, SESSION_VERSION);
+        assert!(migrated.profiles["work"].preferences.suggestion_feedback.is_empty());
+
+        // Old JSON goes through the same migrations
+        let migrated = decode_session(&serde_json::to_vec(&legacy_data)?)?;
+        assert_eq!(migrated.metadata.version, SESSION_VERSION);
+
+        // Files from a newer release are not guessed at
+        let mut newer = MAGIC.to_vec();
+        newer.extend_from_slice(&(SESSION_VERSION + 1).to_le_bytes());
+        assert!(decode_session(&newer).unwrap_err().to_string().contains("newer"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiles_scope_learning_and_suggestions() -> Result<()> {
+        let (temp_dir, mut manager) = create_test_session_manager()?;
+        manager.learn_from_choice("year", "1990");
+
+        let work = Profile {
+            github_username: Some("me-at-work".to_string()),
+            author: Some(GitIdentity { name: "Work Me".to_string(), email: "me@company.com".to_string() }),
+            token_source: TokenSource::Env("WORK_GITHUB_TOKEN".to_string()),
+            default_owner: Some("company".to_string()),
+            preferred_hours: vec![9, 14],
+            ..Profile::default()
+        };
+        manager.set_profile("work", work.clone())?;
+        assert!(manager.set_profile("not valid", Profile::default()).is_err());
+        assert!(manager.activate_profile(Some("personal")).is_err());
+
+        // Learning only touches the active profile
+        manager.activate_profile(Some("work"))?;
+        manager.learn_from_choice("year", "2001");
+        manager.learn_from_choice("hour", "14");
+        assert_eq!(manager.data.user_preferences.favorite_years, vec![1990]);
+
+        let suggestions = manager.get_suggestions(temp_dir.path());
+        assert_eq!(suggestions.profile.as_deref(), Some("work"));
+        assert_eq!(suggestions.suggested_years, vec![2001]);
+        assert_eq!(suggestions.suggested_hour, Some(14));
+        assert_eq!(suggestions.github_username.as_deref(), Some("me-at-work"));
+        assert_eq!(suggestions.default_owner.as_deref(), Some("company"));
+        assert_eq!(suggestions.author_identity, work.author);
+
+        // A learned hour outside the profile's hours falls back to its first one
+        manager.learn_from_choice("hour", "22");
+        assert_eq!(manager.get_suggestions(temp_dir.path()).suggested_hour, Some(9));
+
+        // Updating the settings keeps what the profile learned, and survives a save
+        manager.set_profile("work", Profile { preferred_hours: vec![8], ..work })?;
+        manager.save()?;
+        let loaded = SessionManager::load_session_data(&manager.session_file)?;
+        assert_eq!(loaded.profiles["work"].preferences.favorite_years, vec![2001]);
+        assert_eq!(loaded.profiles["work"].preferred_hours, vec![8]);
+
+        manager.activate_profile(None)?;
+        assert_eq!(manager.get_suggestions(temp_dir.path()).suggested_years, vec![1990]);
+        assert!(manager.remove_profile("work"));
+        assert!(manager.profile_names().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concurrent_runs_merge_on_save() -> Result<()> {
+        let (temp_dir, mut first) = create_test_session_manager()?;
+        let mut second = SessionManager::open(first.session_dir.clone(), SessionFormat::Binary)?;
+        let shared = temp_dir.path().join("shared");
+        let other = temp_dir.path().join("other");
+
+        first.learn_from_choice("year", "1990");
+        first.update_context(&shared, Some("first-repo"), None, None, true);
+        second.learn_from_choice("year", "2001");
+        second.learn_from_choice("repository", "second-repo");
+        second.update_context(&shared, None, None, None, true);
+        second.update_context(&other, None, None, None, true);
+        second.set_profile("work", Profile::default())?;
+        first.learn_from_choice("suggestion_accepted", "next_year");
+        second.learn_from_choice("suggestion_rejected", "next_year");
+
+        first.save()?;
+        second.save()?;
+
+        // Nothing learned by the first run is lost, and both runs are counted
+        let saved = SessionManager::load_session_data(&first.session_file)?;
+        assert_eq!(saved.user_preferences.favorite_years, vec![1990, 2001]);
+        assert_eq!(saved.user_preferences.favorite_repositories, vec!["second-repo".to_string()]);
+        assert_eq!(saved.metadata.total_executions, 2);
+        let shared_context = saved.recent_contexts.iter().find(|context| context.working_directory == shared).unwrap();
+        assert_eq!(shared_context.success_count, 2);
+        assert_eq!(shared_context.repository_name.as_deref(), Some("first-repo"));
+        assert_eq!(saved.recent_contexts.len(), 2);
+        assert!(saved.profiles.contains_key("work"));
+        assert_eq!(saved.user_preferences.suggestion_feedback["next_year"], SuggestionFeedback { accepted: 1, rejected: 1 });
+        assert_eq!(second.data.user_preferences.favorite_years, vec![1990, 2001]);
+
+        // Removals are merged too
+        first.data = SessionManager::load_session_data(&first.session_file)?;
+        first.baseline = first.data.clone();
+        first.remove_profile("work");
+        second.learn_from_choice("year", "2002");
+        first.save()?;
+        second.save()?;
+        let saved = SessionManager::load_session_data(&first.session_file)?;
+        assert!(saved.profiles.is_empty());
+        assert_eq!(saved.user_preferences.favorite_years, vec![1990, 2001, 2002]);
+        assert_eq!(saved.metadata.total_executions, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_saves_keep_every_run() -> Result<()> {
+        let (_temp_dir, manager) = create_test_session_manager()?;
+        let runs: Vec<_> = (0..8u32).map(|run| {
+            let session_dir = manager.session_dir.clone();
+            std::thread::spawn(move || -> Result<()> {
+                let mut session = SessionManager::open(session_dir, SessionFormat::Binary)?;
+                session.learn_from_choice("year", &(1990 + run).to_string());
+                session.save()
+            })
+        }).collect();
+        for run in runs {
+            run.join().unwrap()?;
+        }
+
+        let saved = SessionManager::load_session_data(&manager.session_file)?;
+        assert_eq!(saved.metadata.total_executions, 8);
+        assert_eq!(saved.user_preferences.favorite_years.len(), 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_session_round_trip() -> Result<()> {
+        let (_temp_dir, mut manager) = create_test_session_manager()?;
+        let binary_file = manager.session_file.clone();
+        manager.learn_from_choice("year", "1990");
+        manager.save()?;
+
+        // Switching formats converts the existing session and removes the old file
+        let json_file = binary_file.with_file_name(SessionFormat::Json.file_name());
+        manager.data = SessionManager::load_or_recover(&json_file, &binary_file, true)?.unwrap();
+        manager.session_file = json_file.clone();
+        manager.save()?;
+        assert!(!binary_file.exists());
+
+        let text = fs::read_to_string(&json_file)?;
+        assert!(text.contains("\"favorite_years\""));
+        let loaded = SessionManager::load_session_data(&json_file)?;
+        assert_eq!(loaded.user_preferences.favorite_years, vec![1990]);
+        assert_eq!(loaded.metadata.total_executions, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_newer_session_is_left_untouched() -> Result<()> {
+        let (_temp_dir, manager) = create_test_session_manager()?;
+        let mut newer = MAGIC.to_vec();
+        newer.extend_from_slice(&(SESSION_VERSION + 1).to_le_bytes());
+        newer.extend_from_slice(b"from the future");
+        fs::write(&manager.session_file, &newer)?;
+
+        let mut reopened = SessionManager::open(manager.session_dir.clone(), SessionFormat::Binary)?;
+        assert!(reopened.is_read_only());
+        reopened.learn_from_choice("year", "1990");
+        reopened.save()?;
+        assert_eq!(fs::read(&manager.session_file)?, newer);
+
+        // A newer release saving after this run loaded is not overwritten either
+        let (_other_dir, mut current) = create_test_session_manager()?;
+        fs::write(&current.session_file, &newer)?;
+        current.learn_from_choice("year", "1990");
+        current.save()?;
+        assert!(current.is_read_only());
+        assert_eq!(fs::read(&current.session_file)?, newer);
+        assert_eq!(fs::read_dir(&current.session_dir)?.count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unreadable_session_is_backed_up() -> Result<()> {
+        let (_temp_dir, manager) = create_test_session_manager()?;
+        fs::write(&manager.session_file, b"not a session")?;
+
+        let json_file = manager.session_dir.join("session.json");
+        // Reading under the shared lock leaves the file alone
+        assert!(SessionManager::load_or_recover(&manager.session_file, &json_file, false)?.is_none());
+        assert!(manager.session_file.exists());
+
+        let data = SessionManager::load_or_recover(&manager.session_file, &json_file, true)?.unwrap();
+        assert_eq!(data.metadata.total_executions, 0);
+        assert!(!manager.session_file.exists());
+
+        let backups: Vec<_> = fs::read_dir(&manager.session_dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with("session.bin.corrupt-"))
+            .collect();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(fs::read(backups[0].path())?, b"not a session");
+
+        Ok(())
+    }
+}
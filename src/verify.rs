@@ -0,0 +1,361 @@
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Duration;
+
+use crate::github_client::{GitHubClient, Repository};
+use crate::{TimeTravelCommitConfig, TimeTravelConfig};
+
+/// Reason given when nothing else explains a missing day; only these days can still show up
+const PROCESSING_REASON: &str = "GitHub may still be processing the push - contributions can take up to 24 hours to appear";
+
+/// First pause between calendar checks, doubled after every check up to `MAX_POLL_DELAY`
+const FIRST_POLL_DELAY: Duration = Duration::from_secs(5);
+const MAX_POLL_DELAY: Duration = Duration::from_secs(30);
+
+/// A day that should show a contribution after the run
+#[derive(Debug, Clone)]
+pub struct ExpectedContribution {
+    pub date: NaiveDate,
+    /// Author email of the commit made for this day
+    pub author_email: String,
+}
+
+/// What was pushed and where, used to explain missing contributions
+#[derive(Debug, Clone)]
+pub struct VerificationTarget {
+    /// Login whose profile is checked
+    pub login: String,
+    pub repository: String,
+    /// Branch the commits were pushed to
+    pub branch: String,
+    pub expected: Vec<ExpectedContribution>,
+}
+
+impl VerificationTarget {
+    /// One expected contribution per configured year
+    pub fn for_configs(configs: &[TimeTravelConfig], branch: &str) -> Option<Self> {
+        let first = configs.first()?;
+        let expected = configs.iter()
+            .filter_map(|config| Some(ExpectedContribution {
+                date: NaiveDate::from_ymd_opt(config.year as i32, config.month, config.day)?,
+                author_email: config.commit_author().email,
+            }))
+            .collect();
+
+        Some(Self {
+            login: first.username.clone(),
+            repository: first.repo_name(),
+            branch: branch.to_string(),
+            expected,
+        })
+    }
+
+    /// One expected contribution per scheduled commit
+    pub fn for_commits(config: &TimeTravelConfig, commits: &[TimeTravelCommitConfig]) -> Self {
+        Self {
+            login: config.username.clone(),
+            repository: config.repo_name(),
+            branch: config.branch.clone(),
+            expected: commits.iter()
+                .map(|commit| ExpectedContribution {
+                    date: commit.timestamp.date_naive(),
+                    author_email: commit.author.email.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Verification result for one day.
+/// The calendar only has totals, so contributions from other repositories on the same
+/// day count too; a day with contributions does not prove that this push was counted.
+#[derive(Debug, Clone)]
+pub struct DayVerification {
+    pub date: NaiveDate,
+    /// Contributions GitHub shows on that day (from any repository)
+    pub contributions: u32,
+    /// Likely reason when nothing shows up
+    pub reason: Option<String>,
+}
+
+impl DayVerification {
+    pub fn has_contributions(&self) -> bool {
+        self.contributions > 0
+    }
+}
+
+/// Per-day verification report
+#[derive(Debug, Clone, Default)]
+pub struct VerificationReport {
+    pub days: Vec<DayVerification>,
+}
+
+impl VerificationReport {
+    pub fn with_contributions(&self) -> usize {
+        self.days.iter().filter(|day| day.has_contributions()).count()
+    }
+
+    pub fn without_contributions(&self) -> usize {
+        self.days.len() - self.with_contributions()
+    }
+
+    /// Whether some missing day may still appear once GitHub has processed the push
+    pub fn is_pending(&self) -> bool {
+        self.days.iter().any(|day| day.reason.as_deref() == Some(PROCESSING_REASON))
+    }
+}
+
+/// Query the contribution calendar for every expected day and explain the gaps
+pub async fn verify_contributions(client: &GitHubClient, target: &VerificationTarget) -> Result<VerificationReport> {
+    let repository = client.get_repository(&target.repository).await
+        .context("Failed to read repository details for verification")?;
+    // Without the user:email scope the email check is skipped
+    let linked_emails = client.verified_emails().await.ok();
+
+    // contributionsCollection accepts at most one year per query
+    let mut by_year: BTreeMap<i32, BTreeSet<NaiveDate>> = BTreeMap::new();
+    for expected in &target.expected {
+        by_year.entry(expected.date.year()).or_default().insert(expected.date);
+    }
+
+    let mut counts = HashMap::new();
+    for dates in by_year.values() {
+        let (first, last) = match (dates.first(), dates.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => continue,
+        };
+        let days = client.contribution_calendar(
+            &target.login,
+            &format!("{}T00:00:00Z", first),
+            &format!("{}T23:59:59Z", last),
+        ).await?;
+        for day in days {
+            if let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") {
+                counts.insert(date, day.contribution_count);
+            }
+        }
+    }
+
+    Ok(build_report(target, &repository, linked_emails.as_deref(), &counts))
+}
+
+/// Check the calendar until every day shows up, GitHub has no pending days left or `wait` is used up.
+/// The pauses between checks back off from a few seconds to half a minute.
+pub async fn verify_contributions_within(client: &GitHubClient, target: &VerificationTarget, wait: Duration) -> Result<VerificationReport> {
+    let mut report = verify_contributions(client, target).await?;
+    for delay in poll_delays(wait) {
+        if !report.is_pending() {
+            break;
+        }
+        tracing::debug!(target: "github", missing = report.without_contributions(), delay_secs = delay.as_secs(), "Waiting for contributions to appear");
+        tokio::time::sleep(delay).await;
+        report = verify_contributions(client, target).await?;
+    }
+    Ok(report)
+}
+
+/// Pauses between calendar checks, adding up to at most `wait`
+fn poll_delays(wait: Duration) -> Vec<Duration> {
+    let mut delays = Vec::new();
+    let mut remaining = wait;
+    let mut delay = FIRST_POLL_DELAY;
+    while !remaining.is_zero() {
+        let pause = delay.min(remaining);
+        delays.push(pause);
+        remaining -= pause;
+        delay = (delay * 2).min(MAX_POLL_DELAY);
+    }
+    delays
+}
+
+/// Combine calendar counts with what was pushed into a per-day report
+pub fn build_report(
+    target: &VerificationTarget,
+    repository: &Repository,
+    linked_emails: Option<&[String]>,
+    counts: &HashMap<NaiveDate, u32>,
+) -> VerificationReport {
+    let mut seen = BTreeSet::new();
+    let mut days = Vec::new();
+    for expected in &target.expected {
+        if !seen.insert(expected.date) {
+            continue;
+        }
+        let contributions = counts.get(&expected.date).copied().unwrap_or(0);
+        let reason = (contributions == 0)
+            .then(|| likely_reason(target, repository, linked_emails, &expected.author_email));
+        days.push(DayVerification { date: expected.date, contributions, reason });
+    }
+    days.sort_by_key(|day| day.date);
+    VerificationReport { days }
+}
+
+/// Most likely reason a pushed commit does not count, checked in GitHub's own order
+fn likely_reason(
+    target: &VerificationTarget,
+    repository: &Repository,
+    linked_emails: Option<&[String]>,
+    author_email: &str,
+) -> String {
+    if repository.fork {
+        return "Commits in a fork only count after they are merged into the parent repository".to_string();
+    }
+    if target.branch != repository.default_branch {
+        return format!("Commits were pushed to '{}', but only the default branch '{}' counts",
+            target.branch, repository.default_branch);
+    }
+    if let Some(emails) = linked_emails {
+        if !is_linked_email(author_email, &target.login, emails) {
+            return format!("Author email '{}' is not a verified email of '{}' - add it in GitHub settings or use your noreply address",
+                author_email, target.login);
+        }
+    }
+    if repository.private {
+        return "Repository is private - enable 'Private contributions' on your profile to show them".to_string();
+    }
+    PROCESSING_REASON.to_string()
+}
+
+/// Verified emails and the account's noreply addresses are attributed to the user
+fn is_linked_email(email: &str, login: &str, linked_emails: &[String]) -> bool {
+    let email = email.to_ascii_lowercase();
+    let noreply = format!("{}@users.noreply.github.com", login.to_ascii_lowercase());
+    email == noreply
+        || email.ends_with(&format!("+{}", noreply))
+        || linked_emails.iter().any(|linked| linked.eq_ignore_ascii_case(&email))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    fn repository(default_branch: &str, fork: bool) -> Repository {
+        serde_json::from_value(serde_json::json!({
+            "id": 1, "name": "1990", "full_name": "octocat/1990",
+            "description": null, "private": false, "fork": fork,
+            "html_url": "", "clone_url": "", "ssh_url": "",
+            "default_branch": default_branch, "created_at": "", "updated_at": "",
+        })).unwrap()
+    }
+
+    fn target(dates: &[&str], email: &str) -> VerificationTarget {
+        VerificationTarget {
+            login: "octocat".to_string(),
+            repository: "1990".to_string(),
+            branch: "main".to_string(),
+            expected: dates.iter().map(|date| ExpectedContribution {
+                date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+                author_email: email.to_string(),
+            }).collect(),
+        }
+    }
+
+    #[test]
+    fn test_report_reasons() {
+        let counts = HashMap::from([(NaiveDate::from_ymd_opt(1990, 1, 1).unwrap(), 1)]);
+        let target = target(&["1990-01-01", "1991-01-01"], "someone@example.com");
+        let emails = vec!["octocat@example.org".to_string()];
+
+        let report = build_report(&target, &repository("main", false), Some(&emails), &counts);
+        assert_eq!(report.with_contributions(), 1);
+        assert_eq!(report.without_contributions(), 1);
+        assert!(report.days[1].reason.as_deref().unwrap().contains("not a verified email"));
+
+        let report = build_report(&target, &repository("trunk", false), Some(&emails), &counts);
+        assert!(report.days[1].reason.as_deref().unwrap().contains("default branch 'trunk'"));
+
+        let report = build_report(&target, &repository("main", true), None, &counts);
+        assert!(report.days[1].reason.as_deref().unwrap().contains("fork"));
+        assert!(!report.is_pending());
+
+        assert!(is_linked_email("123+OctoCat@users.noreply.github.com", "octocat", &[]));
+    }
+
+    #[test]
+    fn test_poll_delays_back_off_within_wait() {
+        let seconds = |wait| poll_delays(Duration::from_secs(wait)).iter().map(Duration::as_secs).collect::<Vec<_>>();
+        assert_eq!(seconds(0), Vec::<u64>::new());
+        assert_eq!(seconds(60), vec![5, 10, 20, 25]);
+        assert_eq!(seconds(120), vec![5, 10, 20, 30, 30, 25]);
+    }
+
+    /// Serve canned responses for each request path, standing in for the GitHub API
+    fn serve(routes: Vec<(&'static str, &'static str)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(routes.len()) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let path = request_line.split_whitespace().nth(1).unwrap_or("");
+                let (status, response) = routes.iter()
+                    .find(|(route, _)| path == *route)
+                    .map(|(_, response)| ("200 OK", *response))
+                    .unwrap_or(("404 Not Found", "{\"message\":\"Not Found\"}"));
+                write!(stream, "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, response.len(), response).unwrap();
+            }
+        });
+        address
+    }
+
+    #[tokio::test]
+    async fn test_verify_against_stand_in_endpoint() {
+        let api = serve(vec![
+            ("/repos/octocat/1990", r#"{"id":1,"name":"1990","full_name":"octocat/1990","description":null,
+                "private":false,"html_url":"","clone_url":"","ssh_url":"","default_branch":"main",
+                "created_at":"","updated_at":""}"#),
+            ("/user/emails", r#"[{"email":"octocat@example.org","verified":true}]"#),
+            ("/graphql", r#"{"data":{"user":{"contributionsCollection":{"contributionCalendar":{"weeks":[
+                {"contributionDays":[{"date":"1990-01-01","contributionCount":2},{"date":"1990-01-02","contributionCount":0}]}
+            ]}}}}}"#),
+        ]);
+        let client = GitHubClient::new("octocat".to_string(), "ghp_test".to_string()).unwrap()
+            .with_api_base(&api);
+
+        let report = verify_contributions(&client, &target(&["1990-01-01", "1990-01-02"], "octocat@example.org"))
+            .await
+            .unwrap();
+
+        assert!(report.days[0].has_contributions());
+        assert_eq!(report.days[0].contributions, 2);
+        assert!(!report.days[1].has_contributions());
+        assert!(report.days[1].reason.as_deref().unwrap().contains("24 hours"));
+        assert!(report.is_pending());
+    }
+
+    #[tokio::test]
+    async fn test_untyped_graphql_error_has_exit_code() {
+        let api = serve(vec![
+            ("/graphql", r#"{"errors":[{"message":"Something went wrong"}]}"#),
+        ]);
+        let client = GitHubClient::new("octocat".to_string(), "ghp_test".to_string()).unwrap()
+            .with_api_base(&api);
+
+        let error = client.contribution_calendar("octocat", "1990-01-01T00:00:00Z", "1990-01-02T23:59:59Z")
+            .await
+            .unwrap_err();
+
+        assert_eq!(crate::errors::exit_code_for(&error), crate::errors::exit_codes::NETWORK_INVALID_RESPONSE);
+        assert!(error.to_string().contains("Something went wrong"));
+    }
+}
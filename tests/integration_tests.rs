@@ -1,5 +1,4 @@
 use std::process::Command;
-use std::path::Path;
 
 #[test]
 fn test_help_command() {
@@ -164,29 +163,78 @@ fn test_year_list_parsing() {
     }
 }
 
+#[test]
+fn test_author_flags_win_over_author_pool() {
+    let home = tempfile::tempdir().expect("Failed to create home directory");
+    let pool = home.path().join("authors.csv");
+    std::fs::write(&pool, "from,until,author\n,2000-12-31,Pool Author <pool@example.com>\n")
+        .expect("Failed to write author pool");
+
+    let dry_run = |extra: &[&str]| {
+        let output = Command::new("cargo")
+            .args([
+                "run", "--",
+                "--dry-run",
+                "--no-menu",
+                "--years", "2000-2001",
+                "--username", "testuser",
+                "--repo", "testrepo",
+                "--token", "ghp_fake_token_for_testing",
+            ])
+            .arg("--author-pool").arg(&pool)
+            .args(extra)
+            .env("HOME", home.path())
+            .output()
+            .expect("Failed to execute command");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // The pool covers 2000 only; 2001 falls back to the Time Traveler
+    let stdout = dry_run(&[]);
+    assert!(stdout.contains("pool@example.com"));
+    assert!(stdout.contains("timetraveler@example.com"));
+
+    // Explicit flags apply to every year, including the ones the pool covers
+    let stdout = dry_run(&["--author-name", "Flag Author", "--author-email", "flag@example.com"]);
+    assert!(stdout.contains("flag@example.com"));
+    assert!(!stdout.contains("pool@example.com"));
+}
+
 #[cfg(test)]
 mod npm_integration_tests {
     use super::*;
     use std::fs;
 
-    #[test]
-    fn test_npm_wrapper_help() {
-        // Build the binary first
+    /// Lay out the npm package in a temporary directory, with a fresh release binary,
+    /// so the tests never overwrite the binary checked into the repository
+    fn npm_package_dir() -> tempfile::TempDir {
         let build_output = Command::new("cargo")
             .args(&["build", "--release"])
             .output()
             .expect("Failed to build binary");
-        
+
         assert!(build_output.status.success(), "Failed to build binary");
 
-        // Ensure binary directory exists and copy binary
-        fs::create_dir_all("binary").expect("Failed to create binary directory");
-        fs::copy("target/release/git-timetraveler", "binary/git-timetraveler")
+        let package = tempfile::tempdir().expect("Failed to create package directory");
+        for dir in ["bin", "binary"] {
+            fs::create_dir_all(package.path().join(dir)).expect("Failed to create package directory");
+        }
+        fs::copy("bin/git-timetraveler", package.path().join("bin/git-timetraveler"))
+            .expect("Failed to copy npm wrapper");
+        fs::copy("target/release/git-timetraveler", package.path().join("binary/git-timetraveler"))
             .expect("Failed to copy binary");
+        package
+    }
+
+    #[test]
+    fn test_npm_wrapper_help() {
+        let package = npm_package_dir();
 
         // Test npm wrapper
         let output = Command::new("node")
-            .args(&["bin/git-timetraveler", "--help"])
+            .arg(package.path().join("bin/git-timetraveler"))
+            .arg("--help")
             .output()
             .expect("Failed to execute npm wrapper");
 
@@ -198,21 +246,11 @@ mod npm_integration_tests {
 
     #[test]
     fn test_npm_wrapper_version() {
-        // Ensure binary exists
-        if !Path::new("binary/git-timetraveler").exists() {
-            let build_output = Command::new("cargo")
-                .args(&["build", "--release"])
-                .output()
-                .expect("Failed to build binary");
-            
-            assert!(build_output.status.success());
-            fs::create_dir_all("binary").expect("Failed to create binary directory");
-            fs::copy("target/release/git-timetraveler", "binary/git-timetraveler")
-                .expect("Failed to copy binary");
-        }
+        let package = npm_package_dir();
 
         let output = Command::new("node")
-            .args(&["bin/git-timetraveler", "--version"])
+            .arg(package.path().join("bin/git-timetraveler"))
+            .arg("--version")
             .output()
             .expect("Failed to execute npm wrapper");
 